@@ -21,6 +21,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // - etc.
     let mut config = QdrantConfig::default();
     config.merge_env()?;
+    // Sample documents use readable IDs like "doc_1", which Qdrant only accepts once hashed
+    config.hash_string_ids = true;
 
     println!("📝 Configuration:");
     println!("   URL: {}", config.url);
@@ -65,11 +67,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         DistanceMetric::Cosine,
     )?;
 
-    match adapter.create_collection(collection_config).await {
-        Ok(_) => println!("✅ Collection created successfully"),
-        Err(e) if e.to_string().contains("already exists") => {
-            println!("ℹ️  Collection already exists, continuing...");
-        }
+    match adapter
+        .create_collection_if_not_exists(collection_config)
+        .await
+    {
+        Ok(true) => println!("✅ Collection created successfully"),
+        Ok(false) => println!("ℹ️  Collection already exists, continuing..."),
         Err(e) => {
             eprintln!("❌ Failed to create collection: {}", e);
             return Ok(());
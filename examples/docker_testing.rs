@@ -36,7 +36,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Attempt to connect with QdrantAdapter
     println!("\n🔌 Attempting to connect to Qdrant...");
 
-    let config = QdrantConfig::default();
+    let config = QdrantConfig {
+        hash_string_ids: true,
+        ..QdrantConfig::default()
+    };
     match QdrantAdapter::connect(config).await {
         Ok(adapter) => {
             println!("✅ Successfully connected to Qdrant!");
@@ -0,0 +1,208 @@
+//! Buffered vector writer for high-ingest workloads
+//!
+//! Coalesces individual `store_vector` calls into batched upserts so streaming ingestion
+//! does not pay one network round trip per vector.
+
+use super::*;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+/// Buffers vectors for one collection and writes them in batches
+///
+/// A batch is written as soon as `max_batch_size` vectors are buffered, and a background task
+/// writes whatever is buffered every `flush_interval`. Writes are serialized, so batches reach
+/// the store in the order their vectors were buffered.
+///
+/// Vectors from a failed write are kept and retried ahead of newer vectors by the next write;
+/// the failure is reported by the next `store_vector` or `flush` call. Use
+/// [`take_failed`](Self::take_failed) to give up on vectors the store keeps rejecting.
+///
+/// Always `flush().await` before dropping the writer: dropping it only schedules a
+/// best-effort final write on the current Tokio runtime, which is not guaranteed to run and
+/// whose errors are lost.
+pub struct BufferedVectorWriter<S: VectorStore + Send + Sync + 'static> {
+    store: Arc<S>,
+    collection: String,
+    max_batch_size: usize,
+    state: Arc<WriterState>,
+    flush_task: JoinHandle<()>,
+}
+
+/// Buffer and failure state shared with the background flush task
+struct WriterState {
+    max_batch_size: usize,
+    buffer: Mutex<Vec<Vector>>,
+    /// Vectors whose write failed, retried first by the next write
+    failed: Mutex<Vec<Vector>>,
+    /// Error of a background write not yet reported to the caller
+    background_error: Mutex<Option<TylError>>,
+    /// Held for the whole of each write so batches cannot overtake each other
+    write_lock: tokio::sync::Mutex<()>,
+}
+
+impl<S: VectorStore + Send + Sync + 'static> BufferedVectorWriter<S> {
+    /// Create a writer for `collection`; must be called within a Tokio runtime
+    pub fn new(
+        store: Arc<S>,
+        collection: impl Into<String>,
+        max_batch_size: usize,
+        flush_interval: Duration,
+    ) -> VectorResult<Self> {
+        if max_batch_size == 0 {
+            return Err(TylError::validation(
+                "max_batch_size",
+                "Max batch size must be greater than 0",
+            ));
+        }
+        if flush_interval.is_zero() {
+            return Err(TylError::validation(
+                "flush_interval",
+                "Flush interval must be greater than 0",
+            ));
+        }
+
+        let collection = collection.into();
+        let state = Arc::new(WriterState {
+            max_batch_size,
+            buffer: Mutex::new(Vec::with_capacity(max_batch_size)),
+            failed: Mutex::new(Vec::new()),
+            background_error: Mutex::new(None),
+            write_lock: tokio::sync::Mutex::new(()),
+        });
+
+        let flush_task = tokio::spawn({
+            let store = Arc::clone(&store);
+            let collection = collection.clone();
+            let state = Arc::clone(&state);
+            async move {
+                let mut interval = tokio::time::interval(flush_interval);
+                interval.tick().await; // the first tick completes immediately
+                loop {
+                    interval.tick().await;
+                    if let Err(e) = state.write_buffered(store.as_ref(), &collection).await {
+                        *state.background_error.lock().unwrap() = Some(e);
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            store,
+            collection,
+            max_batch_size,
+            state,
+            flush_task,
+        })
+    }
+
+    /// Buffer a vector, writing the buffered batch once it reaches `max_batch_size`
+    ///
+    /// The vector is buffered even when an error is returned; errors concern earlier writes,
+    /// whose vectors are kept for retry.
+    pub async fn store_vector(&self, vector: Vector) -> TylResult<()> {
+        let full = {
+            let mut buffer = self.state.buffer.lock().unwrap();
+            buffer.push(vector);
+            buffer.len() >= self.max_batch_size
+        };
+        if full {
+            self.state
+                .write_buffered(self.store.as_ref(), &self.collection)
+                .await?;
+        }
+        self.take_background_error()
+    }
+
+    /// Write every buffered vector now, retrying vectors from earlier failed writes
+    ///
+    /// Succeeds only once everything buffered so far has been stored.
+    pub async fn flush(&self) -> TylResult<()> {
+        self.state
+            .write_buffered(self.store.as_ref(), &self.collection)
+            .await?;
+        // Anything a background write failed on has just been written
+        self.state.background_error.lock().unwrap().take();
+        Ok(())
+    }
+
+    /// Number of vectors waiting to be written, including those kept from failed writes
+    pub fn buffered_len(&self) -> usize {
+        self.state.buffer.lock().unwrap().len() + self.state.failed.lock().unwrap().len()
+    }
+
+    /// Remove and return the vectors kept from failed writes, so they are not retried
+    pub fn take_failed(&self) -> Vec<Vector> {
+        std::mem::take(&mut *self.state.failed.lock().unwrap())
+    }
+
+    fn take_background_error(&self) -> TylResult<()> {
+        match self.state.background_error.lock().unwrap().take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl WriterState {
+    /// Write previously failed vectors followed by the buffer in `max_batch_size` batches,
+    /// keeping whatever fails
+    async fn write_buffered<S: VectorStore>(&self, store: &S, collection: &str) -> TylResult<()> {
+        let _write = self.write_lock.lock().await;
+
+        let mut pending = std::mem::take(&mut *self.failed.lock().unwrap());
+        pending.append(&mut self.buffer.lock().unwrap());
+
+        let mut pending = pending.into_iter().peekable();
+        let mut first_error = None;
+        while pending.peek().is_some() {
+            let batch: Vec<Vector> = pending.by_ref().take(self.max_batch_size).collect();
+            let (failed, error) = write_batch(store, collection, batch).await;
+            self.failed.lock().unwrap().extend(failed);
+            first_error = first_error.or(error);
+        }
+        match first_error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<S: VectorStore + Send + Sync + 'static> Drop for BufferedVectorWriter<S> {
+    fn drop(&mut self) {
+        self.flush_task.abort();
+
+        if self.buffered_len() == 0 {
+            return;
+        }
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let store = Arc::clone(&self.store);
+            let collection = std::mem::take(&mut self.collection);
+            let state = Arc::clone(&self.state);
+            runtime.spawn(async move {
+                let _ = state.write_buffered(store.as_ref(), &collection).await;
+            });
+        }
+    }
+}
+
+/// Upsert one batch, returning the vectors that were not stored and the first error
+async fn write_batch<S: VectorStore>(
+    store: &S,
+    collection: &str,
+    batch: Vec<Vector>,
+) -> (Vec<Vector>, Option<TylError>) {
+    match store.store_vectors_batch(collection, batch.clone()).await {
+        Err(e) => (batch, Some(e)),
+        Ok(results) => {
+            let mut failed = Vec::new();
+            let mut first_error = None;
+            for (vector, result) in batch.into_iter().zip(results) {
+                if let Err(e) = result {
+                    first_error.get_or_insert(e);
+                    failed.push(vector);
+                }
+            }
+            (failed, first_error)
+        }
+    }
+}
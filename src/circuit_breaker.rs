@@ -0,0 +1,188 @@
+//! Circuit breaker that fails operations fast while Qdrant is unavailable
+//!
+//! After `failure_threshold` consecutive operations failed to reach Qdrant the breaker opens
+//! and operations are rejected without a network call. Once `cooldown` has passed it
+//! half-opens and admits a single probe operation: its success closes the breaker, a failure
+//! re-opens it.
+//!
+//! Only transport failures count: Qdrant unreachable or not answering in time. Errors that
+//! show the server answered, or that were raised before any request (validation, missing
+//! collections, tenant violations), leave the breaker alone, so bad input cannot open it for
+//! everyone. Each adapter operation counts once, however many requests it makes internally.
+
+use super::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// gRPC status codes that mean a request never got an answer from Qdrant
+///
+/// Compared by value so the adapter does not depend on qdrant-client's `tonic` version.
+const TRANSPORT_FAILURE_CODES: [i32; 3] = [
+    1,  // Cancelled, returned when the client timeout expires
+    4,  // DeadlineExceeded
+    14, // Unavailable, returned when an established connection breaks
+];
+
+/// gRPC `Internal` status, which qdrant-client also uses when it cannot connect at all
+const INTERNAL_CODE: i32 = 13;
+
+tokio::task_local! {
+    /// Set while an admitted operation runs; whether one of its requests failed to reach Qdrant
+    static TRANSPORT_FAILED: Arc<AtomicBool>;
+}
+
+/// State of the adapter's circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Operations run normally
+    Closed,
+    /// Operations are rejected until the cooldown has passed
+    Open,
+    /// Cooldown has passed; the next probe operation closes or re-opens the breaker
+    HalfOpen,
+}
+
+/// Consecutive-failure circuit breaker; time is passed in so transitions are testable
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: Mutex<BreakerState>,
+}
+
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+}
+
+impl CircuitBreaker {
+    /// Breaker configured from `QdrantConfig`, or `None` when it is disabled
+    pub(crate) fn from_config(config: &QdrantConfig) -> Option<Self> {
+        (config.circuit_breaker_threshold > 0).then(|| {
+            Self::new(
+                config.circuit_breaker_threshold,
+                Duration::from_millis(config.circuit_breaker_cooldown_ms),
+            )
+        })
+    }
+
+    pub(crate) fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold,
+            cooldown,
+            state: Mutex::new(BreakerState::default()),
+        }
+    }
+
+    /// Current state at `now`
+    pub(crate) fn state(&self, now: Instant) -> CircuitState {
+        match self.state.lock().unwrap().opened_at {
+            None => CircuitState::Closed,
+            Some(opened_at) if now.duration_since(opened_at) < self.cooldown => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Admit an operation at `now`, or `None` to reject it
+    ///
+    /// While half-open only one probe is admitted at a time; the probe slot is released when
+    /// its permit is dropped.
+    pub(crate) fn try_admit(&self, now: Instant) -> Option<BreakerPermit<'_>> {
+        let mut state = self.state.lock().unwrap();
+        let probe = match state.opened_at {
+            None => false,
+            Some(opened_at) if now.duration_since(opened_at) < self.cooldown => return None,
+            Some(_) if state.probe_in_flight => return None,
+            Some(_) => true,
+        };
+        state.probe_in_flight |= probe;
+        Some(BreakerPermit {
+            breaker: self,
+            probe,
+        })
+    }
+
+    /// Record a successful operation, closing the breaker
+    pub(crate) fn record_success(&self) {
+        *self.state.lock().unwrap() = BreakerState::default();
+    }
+
+    /// Record an operation that failed to reach Qdrant at `now`, opening the breaker at the
+    /// threshold
+    pub(crate) fn record_failure(&self, now: Instant) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        // A failure while half-open (or already open) restarts the cooldown
+        if state.opened_at.is_some() || state.consecutive_failures >= self.failure_threshold {
+            state.opened_at = Some(now);
+        }
+        state.probe_in_flight = false;
+    }
+}
+
+/// An admitted operation; report its outcome with [`finish`](Self::finish)
+pub(crate) struct BreakerPermit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+}
+
+impl BreakerPermit<'_> {
+    /// Record the outcome of the admitted operation
+    ///
+    /// A transport failure counts as a failure and a success closes the breaker; any other
+    /// error says nothing about Qdrant's availability and is not recorded.
+    pub(crate) fn finish(self, succeeded: bool, transport_failed: bool) {
+        if transport_failed {
+            self.breaker.record_failure(Instant::now());
+        } else if succeeded {
+            self.breaker.record_success();
+        }
+    }
+}
+
+impl Drop for BreakerPermit<'_> {
+    fn drop(&mut self) {
+        if self.probe {
+            self.breaker.state.lock().unwrap().probe_in_flight = false;
+        }
+    }
+}
+
+/// Whether an admitted operation is already running in this task
+///
+/// Operations called from within another operation are not admitted or recorded again.
+pub(crate) fn in_operation() -> bool {
+    TRANSPORT_FAILED.try_with(|_| ()).is_ok()
+}
+
+/// Run `operation`, returning its output and whether any of its requests failed to reach
+/// Qdrant (as reported through [`observe_error`])
+pub(crate) async fn track_transport_failures<F: std::future::Future>(
+    operation: F,
+) -> (F::Output, bool) {
+    let failed = Arc::new(AtomicBool::new(false));
+    let output = TRANSPORT_FAILED.scope(Arc::clone(&failed), operation).await;
+    (output, failed.load(Ordering::SeqCst))
+}
+
+/// Report a client error to the running operation if it shows Qdrant was unreachable
+pub(crate) fn observe_error<E: 'static>(error: &E) {
+    if is_transport_failure(error) {
+        let _ = TRANSPORT_FAILED.try_with(|failed| failed.store(true, Ordering::SeqCst));
+    }
+}
+
+/// Whether `error` is a qdrant-client error from a request that got no answer from Qdrant
+pub(crate) fn is_transport_failure<E: 'static>(error: &E) -> bool {
+    match (error as &dyn std::any::Any).downcast_ref::<qdrant_client::QdrantError>() {
+        Some(qdrant_client::QdrantError::ResponseError { status, .. }) => {
+            let code = status.code() as i32;
+            TRANSPORT_FAILURE_CODES.contains(&code)
+                || (code == INTERNAL_CODE && status.message().starts_with("Failed to connect"))
+        }
+        Some(qdrant_client::QdrantError::Io(_)) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,119 @@
+//! Background health monitoring for long-lived services
+//!
+//! Polls a store's health at a fixed interval so callers can read the latest status without a
+//! network round trip, e.g. to open an upstream circuit breaker while Qdrant is down.
+
+use super::*;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+/// Latest health observation of a monitored store
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HealthSnapshot {
+    /// Result of the most recent check; `false` until the first check completes
+    pub healthy: bool,
+    /// When the most recent check completed
+    pub last_checked_at: Option<Instant>,
+    /// When a check last reported the store healthy
+    pub last_healthy_at: Option<Instant>,
+}
+
+/// Cloneable read handle on a [`HealthMonitor`]'s latest status
+#[derive(Debug, Clone)]
+pub struct HealthHandle {
+    state: Arc<Mutex<HealthSnapshot>>,
+}
+
+impl HealthHandle {
+    /// The latest health observation
+    pub fn status(&self) -> HealthSnapshot {
+        *self.state.lock().unwrap()
+    }
+
+    /// Whether the most recent check reported the store healthy
+    pub fn is_healthy(&self) -> bool {
+        self.status().healthy
+    }
+}
+
+/// Polls `is_healthy` in the background and tracks the latest status
+///
+/// A failed check (an error from `is_healthy`) counts as unhealthy. `on_transition` is called
+/// with the new status whenever it differs from the previous check; the first check always
+/// counts as a transition. The task runs until [`stop`](Self::stop) is called or the monitor
+/// is dropped.
+pub struct HealthMonitor {
+    handle: HealthHandle,
+    task: JoinHandle<()>,
+}
+
+impl HealthMonitor {
+    /// Start monitoring `store` every `interval`; must be called within a Tokio runtime
+    pub fn start<S>(
+        store: Arc<S>,
+        interval: Duration,
+        on_transition: impl Fn(bool) + Send + Sync + 'static,
+    ) -> VectorResult<Self>
+    where
+        S: VectorStoreHealth + Send + Sync + 'static,
+    {
+        if interval.is_zero() {
+            return Err(TylError::validation(
+                "interval",
+                "Health check interval must be greater than 0",
+            ));
+        }
+
+        let handle = HealthHandle {
+            state: Arc::new(Mutex::new(HealthSnapshot::default())),
+        };
+
+        let task = tokio::spawn({
+            let state = Arc::clone(&handle.state);
+            async move {
+                let mut interval = tokio::time::interval(interval);
+                let mut previous = None;
+                loop {
+                    interval.tick().await;
+                    let healthy = store.is_healthy().await.unwrap_or(false);
+                    let now = Instant::now();
+                    {
+                        let mut state = state.lock().unwrap();
+                        state.healthy = healthy;
+                        state.last_checked_at = Some(now);
+                        if healthy {
+                            state.last_healthy_at = Some(now);
+                        }
+                    }
+                    if previous != Some(healthy) {
+                        on_transition(healthy);
+                        previous = Some(healthy);
+                    }
+                }
+            }
+        });
+
+        Ok(Self { handle, task })
+    }
+
+    /// A cloneable handle for reading the status from other tasks
+    pub fn handle(&self) -> HealthHandle {
+        self.handle.clone()
+    }
+
+    /// The latest health observation
+    pub fn status(&self) -> HealthSnapshot {
+        self.handle.status()
+    }
+
+    /// Stop polling; handles keep returning the last observed status
+    pub fn stop(&self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for HealthMonitor {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
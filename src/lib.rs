@@ -88,23 +88,90 @@ pub use tyl_embeddings_port::{
 use async_trait::async_trait;
 use qdrant_client::{
     qdrant::{
-        vectors_output, CreateCollection, DeletePoints, Distance, Filter, GetPoints, PointId,
-        PointStruct, PointsIdsList, PointsSelector, UpsertPoints, VectorParams, VectorsConfig,
-        WithPayloadSelector, WithVectorsSelector,
+        vectors_output, CollectionStatus, CreateCollection, DeletePoints, Distance, Filter,
+        GetPoints, OptimizersConfigDiff, PointId, PointStruct, PointsIdsList, PointsSelector,
+        UpdateCollection, UpsertPoints, VectorParams, VectorsConfig, WithPayloadSelector,
+        WithVectorsSelector,
     },
     Payload, Qdrant,
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
-use tyl_logging::{JsonLogger, LogLevel, LogRecord, Logger};
+#[cfg(feature = "logging")]
+use tyl_logging::{ConsoleLogger, JsonLogger, LogLevel, LogRecord, Logger};
+#[cfg(feature = "tracing")]
 use tyl_tracing::{SimpleTracer, TraceConfig, TracingManager};
 
+/// Default `QdrantConfig::reserved_payload_prefix` for adapter-internal payload keys
+pub const RESERVED_PAYLOAD_PREFIX: &str = "__tyl_";
+
+/// Payload key under which the caller's original ID is kept when it was hashed into a UUID
+///
+/// Spelled with the default prefix; see [`QdrantConfig::original_id_key`].
+pub const ORIGINAL_ID_PAYLOAD_KEY: &str = "__tyl_original_id";
+
+/// Payload key recording the `ContentType` an embedding was generated for
+///
+/// Spelled with the default prefix; see [`QdrantConfig::content_type_key`].
+pub const CONTENT_TYPE_PAYLOAD_KEY: &str = "__tyl_content_type";
+
+/// Payload key recording the model an embedding was generated with
+///
+/// Spelled with the default prefix; see [`QdrantConfig::embedding_model_key`].
+pub const EMBEDDING_MODEL_PAYLOAD_KEY: &str = "__tyl_embedding_model";
+
+/// Reserved-key names, without prefix, of the internal keys above
+const ORIGINAL_ID_KEY_NAME: &str = "original_id";
+const CONTENT_TYPE_KEY_NAME: &str = "content_type";
+const EMBEDDING_MODEL_KEY_NAME: &str = "embedding_model";
+
+/// Indexing threshold (in KB) applied by `optimize_collection` so every segment gets an HNSW index
+pub const FORCE_INDEXING_THRESHOLD_KB: u64 = 1;
+
+/// Interval between collection status checks in `wait_until_ready`
+pub const READINESS_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Operators recognized in filter objects such as `{"$gte": 10}`
+const FILTER_OPERATORS: &[&str] = &[
+    "$gte",
+    "$lte",
+    "$gt",
+    "$lt",
+    "$in",
+    "$ne",
+    "$is_null",
+    "$exists",
+    "$is_empty",
+    "$has_id",
+    "$text",
+];
+
+/// Filter key under which `search_within_ids` adds its `$has_id` allowlist
+const ALLOWED_IDS_FILTER_KEY: &str = "__tyl_allowed_ids";
+
+/// gRPC `NotFound` status code, compared by value so the adapter does not depend on `tonic`
+const NOT_FOUND_CODE: i32 = 5;
+
+/// Maximum concurrent stats requests issued by `collections_with_stats`
+pub const COLLECTION_STATS_CONCURRENCY: usize = 8;
+
+/// Namespace used to derive UUIDv5 point IDs from arbitrary string IDs
+///
+/// Changing this value would make previously stored points unreachable by their string ID.
+const STRING_ID_NAMESPACE: uuid::Uuid =
+    uuid::Uuid::from_u128(0x6f3c_2a1e_8d4b_5f07_9e21_c4a8_3b7d_0e56);
+
 /// Qdrant-specific configuration following TYL config patterns
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Debug` output redacts `api_key` so configs can be logged safely.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct QdrantConfig {
     /// Qdrant server URL
     pub url: String,
+    /// Cluster node URLs tried in order for client-side failover; takes precedence over `url`
+    #[serde(default)]
+    pub urls: Vec<String>,
     /// API key for authentication (optional for local instances)
     pub api_key: Option<String>,
     /// Connection timeout in seconds
@@ -113,20 +180,139 @@ pub struct QdrantConfig {
     pub max_batch_size: usize,
     /// Enable gRPC compression
     pub enable_compression: bool,
-    /// Retry attempts for failed operations
+    /// Retry attempts for failed operations; not used yet, the adapter does not retry
     pub retry_attempts: u32,
-    /// Delay between retries in milliseconds
+    /// Delay between retries in milliseconds; not used yet
     pub retry_delay_ms: u64,
     /// Default collection shard number
     pub default_shard_number: u32,
     /// Default replication factor
     pub default_replication_factor: u32,
+    /// Hash IDs that are neither UUIDs nor integers into deterministic UUIDv5 point IDs
+    #[serde(default)]
+    pub hash_string_ids: bool,
+    /// Keep point payloads on disk instead of in RAM for new collections
+    ///
+    /// `None` leaves the choice to the Qdrant server default.
+    #[serde(default)]
+    pub default_on_disk_payload: Option<bool>,
+    /// Minimum level of adapter log records; lower levels are dropped
+    #[serde(default)]
+    pub log_level: QdrantLogLevel,
+    /// Output format of adapter log records
+    #[serde(default)]
+    pub log_format: QdrantLogFormat,
+    /// Consecutive failed operations that open the circuit breaker (0 disables it)
+    #[serde(default)]
+    pub circuit_breaker_threshold: u32,
+    /// Milliseconds an open circuit breaker rejects operations before half-opening
+    #[serde(default = "default_circuit_breaker_cooldown_ms")]
+    pub circuit_breaker_cooldown_ms: u64,
+    /// Prefix prepended to every collection name on the server, e.g. `"billing_"`
+    #[serde(default)]
+    pub collection_prefix: Option<String>,
+    /// How searches handle filters on payload fields without a payload index
+    #[serde(default)]
+    pub auto_index_filter_fields: UnindexedFilterPolicy,
+    /// Payload index type created for each filter field under `auto_index_filter_fields =
+    /// create`; unlisted fields are only warned about
+    #[serde(default)]
+    pub filter_index_types: HashMap<String, PayloadIndexType>,
+    /// Result limit used by `search_with_defaults`
+    #[serde(default = "default_search_limit")]
+    pub default_search_limit: usize,
+    /// Minimum score used by `search_with_defaults`; `None` keeps every match
+    #[serde(default)]
+    pub default_search_threshold: Option<f32>,
+    /// Reject searches whose filters have unknown operators or entries that cannot be applied
+    #[serde(default)]
+    pub strict_filters: bool,
+    /// REST API base URL used for snapshot downloads; derived from `url` when unset
+    #[serde(default)]
+    pub rest_url: Option<String>,
+    /// Payload key prefix reserved for adapter bookkeeping; user keys with it are rejected
+    ///
+    /// Points stored under one prefix keep their internal keys under it, so changing this on
+    /// an existing collection leaves hashed IDs and embedding provenance unreadable.
+    #[serde(default = "default_reserved_payload_prefix")]
+    pub reserved_payload_prefix: String,
+}
+
+impl std::fmt::Debug for QdrantConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Destructure so new fields cannot be left out of the output by accident
+        let Self {
+            url,
+            urls,
+            api_key,
+            timeout_seconds,
+            max_batch_size,
+            enable_compression,
+            retry_attempts,
+            retry_delay_ms,
+            default_shard_number,
+            default_replication_factor,
+            hash_string_ids,
+            default_on_disk_payload,
+            log_level,
+            log_format,
+            circuit_breaker_threshold,
+            circuit_breaker_cooldown_ms,
+            collection_prefix,
+            auto_index_filter_fields,
+            filter_index_types,
+            default_search_limit,
+            default_search_threshold,
+            strict_filters,
+            rest_url,
+            reserved_payload_prefix,
+        } = self;
+        f.debug_struct("QdrantConfig")
+            .field("url", url)
+            .field("urls", urls)
+            .field("api_key", &api_key.as_ref().map(|_| "***"))
+            .field("timeout_seconds", timeout_seconds)
+            .field("max_batch_size", max_batch_size)
+            .field("enable_compression", enable_compression)
+            .field("retry_attempts", retry_attempts)
+            .field("retry_delay_ms", retry_delay_ms)
+            .field("default_shard_number", default_shard_number)
+            .field("default_replication_factor", default_replication_factor)
+            .field("hash_string_ids", hash_string_ids)
+            .field("default_on_disk_payload", default_on_disk_payload)
+            .field("log_level", log_level)
+            .field("log_format", log_format)
+            .field("circuit_breaker_threshold", circuit_breaker_threshold)
+            .field("circuit_breaker_cooldown_ms", circuit_breaker_cooldown_ms)
+            .field("collection_prefix", collection_prefix)
+            .field("auto_index_filter_fields", auto_index_filter_fields)
+            .field("filter_index_types", filter_index_types)
+            .field("default_search_limit", default_search_limit)
+            .field("default_search_threshold", default_search_threshold)
+            .field("strict_filters", strict_filters)
+            .field("rest_url", rest_url)
+            .field("reserved_payload_prefix", reserved_payload_prefix)
+            .finish()
+    }
+}
+
+fn default_circuit_breaker_cooldown_ms() -> u64 {
+    30_000
+}
+
+fn default_search_limit() -> usize {
+    10
+}
+
+fn default_reserved_payload_prefix() -> String {
+    RESERVED_PAYLOAD_PREFIX.to_string()
 }
 
 impl Default for QdrantConfig {
     fn default() -> Self {
         Self {
             url: "http://localhost:6333".to_string(),
+            urls: Vec::new(),
             api_key: None,
             timeout_seconds: 30,
             max_batch_size: 100,
@@ -135,6 +321,20 @@ impl Default for QdrantConfig {
             retry_delay_ms: 1000,
             default_shard_number: 1,
             default_replication_factor: 1,
+            hash_string_ids: false,
+            default_on_disk_payload: None,
+            log_level: QdrantLogLevel::default(),
+            log_format: QdrantLogFormat::default(),
+            circuit_breaker_threshold: 0,
+            circuit_breaker_cooldown_ms: default_circuit_breaker_cooldown_ms(),
+            collection_prefix: None,
+            auto_index_filter_fields: UnindexedFilterPolicy::default(),
+            filter_index_types: HashMap::new(),
+            default_search_limit: default_search_limit(),
+            default_search_threshold: None,
+            strict_filters: false,
+            rest_url: None,
+            reserved_payload_prefix: default_reserved_payload_prefix(),
         }
     }
 }
@@ -149,9 +349,15 @@ impl ConfigPlugin for QdrantConfig {
     }
 
     fn validate(&self) -> ConfigResult<()> {
-        if self.url.is_empty() {
+        if self.urls.is_empty() && self.url.is_empty() {
             return Err(TylError::validation("url", "Qdrant URL cannot be empty"));
         }
+        if self.urls.iter().any(|url| url.is_empty()) {
+            return Err(TylError::validation(
+                "urls",
+                "Qdrant URLs cannot contain empty entries",
+            ));
+        }
         if self.timeout_seconds == 0 {
             return Err(TylError::validation(
                 "timeout_seconds",
@@ -176,6 +382,46 @@ impl ConfigPlugin for QdrantConfig {
                 "Replication factor must be greater than 0",
             ));
         }
+        if self.collection_prefix.as_deref() == Some("") {
+            return Err(TylError::validation(
+                "collection_prefix",
+                "Collection prefix cannot be empty; leave it unset instead",
+            ));
+        }
+        if self.reserved_payload_prefix.is_empty() {
+            return Err(TylError::validation(
+                "reserved_payload_prefix",
+                "Reserved payload prefix cannot be empty",
+            ));
+        }
+        if self.reserved_payload_prefix.contains('.') {
+            // Filters read dots as nested paths, so content type filters would miss
+            return Err(TylError::validation(
+                "reserved_payload_prefix",
+                "Reserved payload prefix cannot contain '.'",
+            ));
+        }
+        if self.default_search_limit == 0 {
+            return Err(TylError::validation(
+                "default_search_limit",
+                "Default search limit must be greater than 0",
+            ));
+        }
+        if self
+            .default_search_threshold
+            .is_some_and(|threshold| !threshold.is_finite())
+        {
+            return Err(TylError::validation(
+                "default_search_threshold",
+                "Default search threshold must be a finite number",
+            ));
+        }
+        if self.circuit_breaker_threshold > 0 && self.circuit_breaker_cooldown_ms == 0 {
+            return Err(TylError::validation(
+                "circuit_breaker_cooldown_ms",
+                "Circuit breaker cooldown must be greater than 0",
+            ));
+        }
         Ok(())
     }
 
@@ -193,6 +439,15 @@ impl ConfigPlugin for QdrantConfig {
             self.url = url;
         }
 
+        // Failover endpoints: comma-separated list
+        if let Ok(urls) = std::env::var("TYL_QDRANT_URLS") {
+            self.urls = urls
+                .split(',')
+                .map(|url| url.trim().to_string())
+                .filter(|url| !url.is_empty())
+                .collect();
+        }
+
         // API Key: TYL_QDRANT_API_KEY > QDRANT_API_KEY > default
         if let Ok(key) = std::env::var("TYL_QDRANT_API_KEY") {
             self.api_key = Some(key);
@@ -234,1249 +489,9597 @@ impl ConfigPlugin for QdrantConfig {
                 .map_err(|_| TylError::configuration("Invalid TYL_QDRANT_RETRY_DELAY_MS"))?;
         }
 
-        Ok(())
-    }
-}
-
-/// Qdrant adapter implementation
-pub struct QdrantAdapter {
-    client: Qdrant,
-    config: QdrantConfig,
-    logger: JsonLogger,
-    tracer: SimpleTracer,
-}
+        // String ID hashing
+        if let Ok(hash_ids) = std::env::var("TYL_QDRANT_HASH_STRING_IDS") {
+            self.hash_string_ids = hash_ids
+                .parse()
+                .map_err(|_| TylError::configuration("Invalid TYL_QDRANT_HASH_STRING_IDS"))?;
+        }
 
-impl QdrantAdapter {
-    /// Helper macro for error mapping to reduce duplication
-    fn map_qdrant_error<T, E: std::fmt::Display>(
-        result: Result<T, E>,
-        context: &str,
-    ) -> VectorResult<T> {
-        result.map_err(|e| vector_errors::storage_failed(format!("{context}: {e}")))
-    }
+        // Collection defaults
+        if let Ok(on_disk) = std::env::var("TYL_QDRANT_DEFAULT_ON_DISK_PAYLOAD") {
+            self.default_on_disk_payload = Some(on_disk.parse().map_err(|_| {
+                TylError::configuration("Invalid TYL_QDRANT_DEFAULT_ON_DISK_PAYLOAD")
+            })?);
+        }
 
-    /// Helper for common telemetry (logging + tracing) operations
-    async fn with_telemetry<F, T>(
-        &self,
-        operation: &str,
-        context: &str,
-        operation_fn: F,
-    ) -> TylResult<T>
-    where
-        F: std::future::Future<Output = TylResult<T>>,
-    {
-        let span_id = Self::map_qdrant_error(
-            self.tracer.start_span(operation, None),
-            "Failed to start trace",
-        )?;
+        // Logging
+        if let Ok(level) = std::env::var("TYL_QDRANT_LOG_LEVEL") {
+            self.log_level = level
+                .parse()
+                .map_err(|_| TylError::configuration("Invalid TYL_QDRANT_LOG_LEVEL"))?;
+        }
 
-        let start_time = Instant::now();
-        let record = LogRecord::new(LogLevel::Info, format!("{operation} - {context}"));
-        self.logger.log(&record);
+        if let Ok(format) = std::env::var("TYL_QDRANT_LOG_FORMAT") {
+            self.log_format = format
+                .parse()
+                .map_err(|_| TylError::configuration("Invalid TYL_QDRANT_LOG_FORMAT"))?;
+        }
 
-        let result = operation_fn.await;
+        // Collection namespace
+        if let Ok(prefix) = std::env::var("TYL_QDRANT_COLLECTION_PREFIX") {
+            self.collection_prefix = Some(prefix).filter(|prefix| !prefix.is_empty());
+        }
 
-        let duration = start_time.elapsed();
-        match &result {
-            Ok(_) => {
-                let success_record = LogRecord::new(
-                    LogLevel::Info,
-                    format!("Completed {operation} in {duration:?} - {context}"),
-                );
-                self.logger.log(&success_record);
-            }
-            Err(e) => {
-                let error_record = LogRecord::new(
-                    LogLevel::Error,
-                    format!("Failed {operation} in {duration:?} - {context}: {e}"),
-                );
-                self.logger.log(&error_record);
-            }
+        // Unindexed filter fields
+        if let Ok(policy) = std::env::var("TYL_QDRANT_AUTO_INDEX_FILTER_FIELDS") {
+            self.auto_index_filter_fields = policy.parse().map_err(|_| {
+                TylError::configuration("Invalid TYL_QDRANT_AUTO_INDEX_FILTER_FIELDS")
+            })?;
         }
 
-        Self::map_qdrant_error(self.tracer.end_span(span_id), "Failed to end trace")?;
+        // Filter field index types: comma-separated `field:type` pairs
+        if let Ok(types) = std::env::var("TYL_QDRANT_FILTER_INDEX_TYPES") {
+            self.filter_index_types = types
+                .split(',')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .map(|entry| {
+                    let (field, index_type) = entry.split_once(':')?;
+                    Some((field.trim().to_string(), index_type.parse().ok()?))
+                })
+                .collect::<Option<_>>()
+                .ok_or_else(|| TylError::configuration("Invalid TYL_QDRANT_FILTER_INDEX_TYPES"))?;
+        }
 
-        result
-    }
+        // REST API endpoint
+        if let Ok(rest_url) = std::env::var("TYL_QDRANT_REST_URL") {
+            self.rest_url = Some(rest_url).filter(|url| !url.is_empty());
+        }
 
-    /// Create a new QdrantAdapter from configuration
-    async fn new(config: QdrantConfig) -> VectorResult<Self> {
-        config.validate()?;
+        // Reserved payload namespace
+        if let Ok(prefix) = std::env::var("TYL_QDRANT_RESERVED_PAYLOAD_PREFIX") {
+            self.reserved_payload_prefix = prefix;
+        }
 
-        // Create Qdrant client using new API
-        let mut client_builder =
-            Qdrant::from_url(&config.url).timeout(Duration::from_secs(config.timeout_seconds));
+        // Filter strictness
+        if let Ok(strict) = std::env::var("TYL_QDRANT_STRICT_FILTERS") {
+            self.strict_filters = strict
+                .parse()
+                .map_err(|_| TylError::configuration("Invalid TYL_QDRANT_STRICT_FILTERS"))?;
+        }
 
-        if let Some(api_key) = &config.api_key {
-            client_builder = client_builder.api_key(api_key.clone());
+        // Search defaults
+        if let Ok(limit) = std::env::var("TYL_QDRANT_DEFAULT_SEARCH_LIMIT") {
+            self.default_search_limit = limit
+                .parse()
+                .map_err(|_| TylError::configuration("Invalid TYL_QDRANT_DEFAULT_SEARCH_LIMIT"))?;
         }
 
-        let client = client_builder.build().map_err(|e| {
-            vector_errors::connection_failed(format!("Failed to create Qdrant client: {e}"))
-        })?;
+        if let Ok(threshold) = std::env::var("TYL_QDRANT_DEFAULT_SEARCH_THRESHOLD") {
+            self.default_search_threshold = Some(threshold.parse().map_err(|_| {
+                TylError::configuration("Invalid TYL_QDRANT_DEFAULT_SEARCH_THRESHOLD")
+            })?);
+        }
 
-        let logger = JsonLogger::new();
-        let tracer = SimpleTracer::new(TraceConfig::new("tyl-qdrant-adapter"));
+        // Circuit breaker
+        if let Ok(threshold) = std::env::var("TYL_QDRANT_CIRCUIT_BREAKER_THRESHOLD") {
+            self.circuit_breaker_threshold = threshold.parse().map_err(|_| {
+                TylError::configuration("Invalid TYL_QDRANT_CIRCUIT_BREAKER_THRESHOLD")
+            })?;
+        }
 
-        let adapter = Self {
-            client,
-            config,
-            logger,
-            tracer,
-        };
+        if let Ok(cooldown) = std::env::var("TYL_QDRANT_CIRCUIT_BREAKER_COOLDOWN_MS") {
+            self.circuit_breaker_cooldown_ms = cooldown.parse().map_err(|_| {
+                TylError::configuration("Invalid TYL_QDRANT_CIRCUIT_BREAKER_COOLDOWN_MS")
+            })?;
+        }
 
-        // Test connection
-        adapter.test_connection().await?;
-        Ok(adapter)
+        Ok(())
     }
+}
 
-    /// Test Qdrant connection
-    async fn test_connection(&self) -> VectorResult<()> {
-        // Try health check, but don't fail immediately on version incompatibility
-        match self.client.health_check().await {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                let error_str = e.to_string();
-                // If it's just a compatibility check warning, try to continue
-                if error_str.contains("check client-server compatibility")
-                    || error_str.contains("Set check_compatibility=false")
-                {
-                    println!("⚠️  Version compatibility warning: {error_str}");
-                    // Don't fail on compatibility warnings, just log them
-                    Ok(())
-                } else {
-                    Err(vector_errors::connection_failed(format!(
-                        "Qdrant health check failed: {e}"
-                    )))
+impl QdrantConfig {
+    /// REST API base URL: `rest_url` if set, otherwise `url` with the gRPC port 6334
+    /// replaced by the REST port 6333
+    pub fn rest_api_url(&self) -> String {
+        match &self.rest_url {
+            Some(rest_url) => rest_url.trim_end_matches('/').to_string(),
+            None => {
+                let url = self.url.trim_end_matches('/');
+                match url.strip_suffix(":6334") {
+                    Some(host) => format!("{host}:6333"),
+                    None => url.to_string(),
                 }
             }
         }
     }
 
-    /// Convert TYL DistanceMetric to Qdrant Distance (necessary for adapter pattern)
-    fn distance_metric_to_qdrant(metric: &DistanceMetric) -> Distance {
-        match metric {
-            DistanceMetric::Cosine => Distance::Cosine,
-            DistanceMetric::Euclidean => Distance::Euclid,
-            DistanceMetric::DotProduct => Distance::Dot,
-            DistanceMetric::Manhattan => Distance::Manhattan,
+    /// Endpoints to connect to, in failover order: `urls` if set, otherwise `url`
+    pub fn endpoints(&self) -> Vec<&str> {
+        if self.urls.is_empty() {
+            vec![self.url.as_str()]
+        } else {
+            self.urls.iter().map(String::as_str).collect()
         }
     }
 
-    /// Convert JSON value to Qdrant value - helper for metadata conversion
-    fn json_to_qdrant_value(value: serde_json::Value) -> Option<qdrant_client::qdrant::Value> {
-        let kind = match value {
-            serde_json::Value::String(s) => qdrant_client::qdrant::value::Kind::StringValue(s),
-            serde_json::Value::Number(n) if n.is_i64() => {
-                qdrant_client::qdrant::value::Kind::IntegerValue(n.as_i64()?)
-            }
-            serde_json::Value::Number(n) if n.is_f64() => {
-                qdrant_client::qdrant::value::Kind::DoubleValue(n.as_f64()?)
-            }
-            serde_json::Value::Bool(b) => qdrant_client::qdrant::value::Kind::BoolValue(b),
-            _ => return None, // Skip unsupported types
-        };
-
-        Some(qdrant_client::qdrant::Value { kind: Some(kind) })
+    /// Server-side name of a collection: `collection` with `collection_prefix` prepended
+    pub fn qualified_collection_name(&self, collection: &str) -> String {
+        match &self.collection_prefix {
+            Some(prefix) => format!("{prefix}{collection}"),
+            None => collection.to_string(),
+        }
     }
 
-    /// Convert TYL Vector to Qdrant PointStruct (necessary for adapter pattern)
-    fn vector_to_point_struct(vector: Vector) -> PointStruct {
-        let mut payload = Payload::new();
-
-        for (key, value) in vector.metadata {
-            if let Some(qdrant_value) = Self::json_to_qdrant_value(value) {
-                payload.insert(key, qdrant_value);
-            }
-        }
+    /// Payload key for the adapter-internal field `name`, e.g. `__tyl_checksum`
+    pub fn reserved_key(&self, name: &str) -> String {
+        format!("{}{name}", self.reserved_payload_prefix)
+    }
 
-        PointStruct::new(vector.id, vector.embedding, payload)
+    /// Whether `key` is in the adapter's reserved payload namespace
+    pub fn is_reserved_key(&self, key: &str) -> bool {
+        key.starts_with(&self.reserved_payload_prefix)
     }
 
-    /// Extract point ID from Qdrant point - helper for point conversion
-    fn extract_point_id(point_id: Option<qdrant_client::qdrant::PointId>) -> VectorResult<String> {
-        let point_id =
-            point_id.ok_or_else(|| vector_errors::vector_not_found("missing point ID"))?;
+    /// Payload key under which a hashed point keeps the caller's original ID
+    pub fn original_id_key(&self) -> String {
+        self.reserved_key(ORIGINAL_ID_KEY_NAME)
+    }
 
-        match point_id.point_id_options {
-            Some(qdrant_client::qdrant::point_id::PointIdOptions::Uuid(uuid)) => Ok(uuid),
-            Some(qdrant_client::qdrant::point_id::PointIdOptions::Num(num)) => Ok(num.to_string()),
-            None => Err(vector_errors::vector_not_found("missing point ID")),
-        }
+    /// Payload key recording the `ContentType` an embedding was generated for
+    pub fn content_type_key(&self) -> String {
+        self.reserved_key(CONTENT_TYPE_KEY_NAME)
     }
 
-    /// Extract vector data from Qdrant vectors - helper for point conversion
-    fn extract_vector_data(
-        vectors: Option<qdrant_client::qdrant::VectorsOutput>,
-    ) -> VectorResult<Vec<f32>> {
-        let vectors =
-            vectors.ok_or_else(|| vector_errors::storage_failed("Missing vector data"))?;
+    /// Payload key recording the model an embedding was generated with
+    pub fn embedding_model_key(&self) -> String {
+        self.reserved_key(EMBEDDING_MODEL_KEY_NAME)
+    }
 
-        match vectors.vectors_options {
-            Some(vectors_output::VectorsOptions::Vector(vector_data)) => Ok(vector_data.data),
-            _ => Err(vector_errors::storage_failed("Invalid vector format")),
+    /// Search parameters built from `default_search_limit` and `default_search_threshold`
+    pub fn default_search_params(&self) -> SearchParams {
+        let params = SearchParams::with_limit(self.default_search_limit);
+        match self.default_search_threshold {
+            Some(threshold) => params.with_threshold(threshold),
+            None => params,
         }
     }
 
-    /// Convert Qdrant value to JSON value - helper for metadata conversion
-    fn qdrant_to_json_value(value: qdrant_client::qdrant::Value) -> Option<serde_json::Value> {
-        match value.kind? {
-            qdrant_client::qdrant::value::Kind::StringValue(s) => {
-                Some(serde_json::Value::String(s))
-            }
-            qdrant_client::qdrant::value::Kind::IntegerValue(i) => {
-                Some(serde_json::Value::Number(serde_json::Number::from(i)))
-            }
-            qdrant_client::qdrant::value::Kind::DoubleValue(d) => {
-                serde_json::Number::from_f64(d).map(serde_json::Value::Number)
-            }
-            qdrant_client::qdrant::value::Kind::BoolValue(b) => Some(serde_json::Value::Bool(b)),
-            _ => None, // Skip unsupported types
+    /// Logical name of a server-side collection, or `None` if it lies outside the prefix
+    pub fn logical_collection_name<'a>(&self, server_name: &'a str) -> Option<&'a str> {
+        match &self.collection_prefix {
+            Some(prefix) => server_name.strip_prefix(prefix.as_str()),
+            None => Some(server_name),
         }
     }
+}
 
-    /// Convert Qdrant ScoredPoint to TYL Vector (necessary for adapter pattern)
-    fn point_to_vector(point: qdrant_client::qdrant::ScoredPoint) -> VectorResult<Vector> {
-        let id = Self::extract_point_id(point.id)?;
-        let embedding = Self::extract_vector_data(point.vectors)?;
+/// Minimum severity of the log records the adapter emits
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QdrantLogLevel {
+    /// Per-operation detail such as result counts and scores
+    Debug,
+    /// Operation start and completion (default)
+    #[default]
+    Info,
+    /// Recoverable problems
+    Warn,
+    /// Failed operations only
+    Error,
+}
 
-        let mut metadata = HashMap::new();
-        for (key, value) in point.payload {
-            if let Some(json_value) = Self::qdrant_to_json_value(value) {
-                metadata.insert(key, json_value);
-            }
-        }
+impl QdrantLogLevel {
+    /// Whether a record at `level` passes this minimum level
+    pub fn enables(self, level: QdrantLogLevel) -> bool {
+        level >= self
+    }
+}
 
-        Ok(Vector {
-            id,
-            embedding,
-            metadata,
-        })
+impl std::str::FromStr for QdrantLogLevel {
+    type Err = TylError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "debug" => Ok(Self::Debug),
+            "info" => Ok(Self::Info),
+            "warn" | "warning" => Ok(Self::Warn),
+            "error" => Ok(Self::Error),
+            other => Err(TylError::validation(
+                "log_level",
+                format!("Unknown log level '{other}'; expected debug, info, warn or error"),
+            )),
+        }
     }
+}
 
-    /// Build range condition from filter object (e.g. {"$gte": 10, "$lte": 20})
-    fn build_range_condition(
-        field: &str,
-        obj: &serde_json::Map<String, serde_json::Value>,
-    ) -> VectorResult<qdrant_client::qdrant::Condition> {
-        use qdrant_client::qdrant::{Condition, FieldCondition, Range};
+#[cfg(feature = "logging")]
+impl From<QdrantLogLevel> for LogLevel {
+    fn from(level: QdrantLogLevel) -> Self {
+        match level {
+            QdrantLogLevel::Debug => LogLevel::Debug,
+            QdrantLogLevel::Info => LogLevel::Info,
+            QdrantLogLevel::Warn => LogLevel::Warn,
+            QdrantLogLevel::Error => LogLevel::Error,
+        }
+    }
+}
 
-        let mut gte = None;
-        let mut lte = None;
-        let mut gt = None;
-        let mut lt = None;
+/// Output format of the adapter's log records
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QdrantLogFormat {
+    /// Structured JSON records for log aggregation (default)
+    #[default]
+    Json,
+    /// Plain human-readable lines for local development
+    Text,
+}
 
-        for (op, value) in obj {
-            let num_val = value.as_f64().ok_or_else(|| {
-                vector_errors::invalid_dimension(0, 0) // Using placeholder error, could be improved
-            })?;
+impl std::str::FromStr for QdrantLogFormat {
+    type Err = TylError;
 
-            match op.as_str() {
-                "$gte" => gte = Some(num_val),
-                "$lte" => lte = Some(num_val),
-                "$gt" => gt = Some(num_val),
-                "$lt" => lt = Some(num_val),
-                _ => continue,
-            }
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "text" => Ok(Self::Text),
+            other => Err(TylError::validation(
+                "log_format",
+                format!("Unknown log format '{other}'; expected json or text"),
+            )),
         }
+    }
+}
 
-        let range = Range { gte, lte, gt, lt };
+/// What filtered operations do when a filter references a payload field without a payload
+/// index
+///
+/// Applies to searches, grouped searches, `find_by_filter`, `count_matching` and
+/// `delete_by_filter`. Filtering on an unindexed field scans every candidate point, which is
+/// slow on large collections. Checks cost one collection info request per newly seen filter
+/// field; a failed check is logged as a warning and never fails the operation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnindexedFilterPolicy {
+    /// Filter without checking for payload indexes (default)
+    #[default]
+    Ignore,
+    /// Log a warning naming each unindexed filter field
+    Warn,
+    /// Request a payload index for each unindexed filter field listed in
+    /// `filter_index_types`, and warn about the others
+    ///
+    /// Indexes are built in the background: the operation that triggers the request does not
+    /// wait for it, so it and the next few may still scan.
+    Create,
+}
 
-        Ok(Condition {
-            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
-                FieldCondition {
-                    key: field.to_string(),
-                    r#match: None,
-                    range: Some(range),
-                    geo_bounding_box: None,
-                    geo_radius: None,
-                    geo_polygon: None,
-                    values_count: None,
-                    is_empty: None,
-                    is_null: None,
-                    datetime_range: None,
-                },
+impl std::str::FromStr for UnindexedFilterPolicy {
+    type Err = TylError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "ignore" | "off" => Ok(Self::Ignore),
+            "warn" => Ok(Self::Warn),
+            "create" => Ok(Self::Create),
+            other => Err(TylError::validation(
+                "auto_index_filter_fields",
+                format!(
+                    "Unknown unindexed filter policy '{other}'; expected ignore, warn or create"
+                ),
             )),
-        })
+        }
     }
+}
 
-    /// Build IN condition from filter object (e.g. {"$in": ["value1", "value2"]})
-    fn build_in_condition(
-        field: &str,
-        obj: &serde_json::Map<String, serde_json::Value>,
-    ) -> VectorResult<qdrant_client::qdrant::Condition> {
-        use qdrant_client::qdrant::{Condition, FieldCondition, Match};
-
-        if let Some(serde_json::Value::Array(values)) = obj.get("$in") {
-            // For arrays, we'll create multiple OR conditions
-            // This is a simplification - ideally we'd use ValuesCount but it's more complex
-            if let Some(first_val) = values.first() {
-                let match_value = match first_val {
-                    serde_json::Value::String(s) => Some(
-                        qdrant_client::qdrant::r#match::MatchValue::Keyword(s.clone()),
-                    ),
-                    serde_json::Value::Number(n) if n.is_i64() => Some(
-                        qdrant_client::qdrant::r#match::MatchValue::Integer(n.as_i64().unwrap()),
-                    ),
-                    serde_json::Value::Number(n) if n.is_f64() => {
-                        // Convert float to integer for compatibility with Qdrant
-                        let int_val = n.as_f64().unwrap() as i64;
-                        Some(qdrant_client::qdrant::r#match::MatchValue::Integer(int_val))
-                    }
-                    serde_json::Value::Bool(b) => {
-                        Some(qdrant_client::qdrant::r#match::MatchValue::Boolean(*b))
-                    }
-                    _ => None,
-                };
+/// Type of a payload index created for a filter field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PayloadIndexType {
+    /// Exact string matches
+    Keyword,
+    /// Integer matches and ranges
+    Integer,
+    /// Floating point ranges
+    Float,
+    /// `true` / `false` matches
+    Bool,
+    /// Full-text (`$text`) matches
+    Text,
+    /// RFC 3339 timestamp ranges
+    Datetime,
+}
 
-                if let Some(mv) = match_value {
-                    return Ok(Condition {
-                        condition_one_of: Some(
-                            qdrant_client::qdrant::condition::ConditionOneOf::Field(
-                                FieldCondition {
-                                    key: field.to_string(),
-                                    r#match: Some(Match {
-                                        match_value: Some(mv),
-                                    }),
-                                    range: None,
-                                    geo_bounding_box: None,
-                                    geo_radius: None,
-                                    geo_polygon: None,
-                                    values_count: None,
-                                    is_empty: None,
-                                    is_null: None,
-                                    datetime_range: None,
-                                },
-                            ),
-                        ),
-                    });
-                }
-            }
+impl std::str::FromStr for PayloadIndexType {
+    type Err = TylError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "keyword" => Ok(Self::Keyword),
+            "integer" => Ok(Self::Integer),
+            "float" => Ok(Self::Float),
+            "bool" => Ok(Self::Bool),
+            "text" => Ok(Self::Text),
+            "datetime" => Ok(Self::Datetime),
+            other => Err(TylError::validation(
+                "filter_index_types",
+                format!(
+                    "Unknown payload index type '{other}'; expected keyword, integer, float, \
+                     bool, text or datetime"
+                ),
+            )),
         }
+    }
+}
+
+/// Qdrant filter helpers for the port's `SearchParams`
+pub trait SearchParamsExt {
+    /// Only match vectors whose `key` equals any of `values` (OR within the key)
+    ///
+    /// Other filters still apply, so `status in [published, review] AND lang = en` is
+    /// `with_any_of("status", ["published", "review"]).with_filter("lang", json!("en"))`.
+    fn with_any_of<K, I, V>(self, key: K, values: I) -> Self
+    where
+        K: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<serde_json::Value>;
+
+    /// Only match vectors embedded as `content_type` by `store_text` or `reembed_collection`
+    ///
+    /// Filters on [`CONTENT_TYPE_PAYLOAD_KEY`]; adapters with a custom
+    /// `reserved_payload_prefix` use [`SearchParamsExt::with_content_type_key`].
+    fn with_content_type(self, content_type: &ContentType) -> Self;
+
+    /// Like [`SearchParamsExt::with_content_type`], filtering on `config.content_type_key()`
+    fn with_content_type_key(self, content_type: &ContentType, config: &QdrantConfig) -> Self;
+}
 
-        Err(vector_errors::invalid_dimension(0, 0)) // Placeholder error
+impl SearchParamsExt for SearchParams {
+    fn with_any_of<K, I, V>(self, key: K, values: I) -> Self
+    where
+        K: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<serde_json::Value>,
+    {
+        let key: String = key.into();
+        let values = values.into_iter().map(Into::into).collect();
+        self.with_filter(&key, serde_json::Value::Array(values))
     }
 
-    /// Build NOT EQUALS condition from filter object (e.g. {"$ne": "value"})
-    fn build_not_equals_condition(
-        _field: &str,
-        _obj: &serde_json::Map<String, serde_json::Value>,
-    ) -> VectorResult<qdrant_client::qdrant::Condition> {
-        // For now, return an error as NOT EQUALS is complex in Qdrant
-        // Would need to be implemented using must_not in the filter
-        Err(vector_errors::storage_failed(
-            "$ne operator not yet implemented",
-        ))
+    fn with_content_type(self, content_type: &ContentType) -> Self {
+        self.with_content_type_key(content_type, &QdrantConfig::default())
     }
 
-    /// Build EXISTS condition from filter object (e.g. {"$exists": true})  
-    fn build_exists_condition(
-        field: &str,
-        obj: &serde_json::Map<String, serde_json::Value>,
-    ) -> VectorResult<qdrant_client::qdrant::Condition> {
-        use qdrant_client::qdrant::{Condition, FieldCondition};
+    fn with_content_type_key(self, content_type: &ContentType, config: &QdrantConfig) -> Self {
+        self.with_filter(
+            &config.content_type_key(),
+            serde_json::json!(content_type_label(content_type)),
+        )
+    }
+}
 
-        let exists = obj.get("$exists").and_then(|v| v.as_bool()).unwrap_or(true);
+/// Payload value stored under [`CONTENT_TYPE_PAYLOAD_KEY`] for [`ContentType::Text`]
+pub const CONTENT_TYPE_TEXT_LABEL: &str = "text";
+
+/// Payload value stored under [`CONTENT_TYPE_PAYLOAD_KEY`] for [`ContentType::Code`]
+pub const CONTENT_TYPE_CODE_LABEL: &str = "code";
+
+/// Payload value stored under [`CONTENT_TYPE_PAYLOAD_KEY`], e.g. `"code"`
+///
+/// Labels are stored in payloads and filtered on, so they are spelled out here rather than
+/// derived from `Debug` and must not change. Content types without a constant fall back to
+/// their lowercased `Debug` name.
+fn content_type_label(content_type: &ContentType) -> String {
+    match content_type {
+        ContentType::Text => CONTENT_TYPE_TEXT_LABEL.to_string(),
+        ContentType::Code => CONTENT_TYPE_CODE_LABEL.to_string(),
+        #[allow(unreachable_patterns)]
+        other => format!("{other:?}").to_lowercase(),
+    }
+}
 
-        Ok(Condition {
-            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
-                FieldCondition {
-                    key: field.to_string(),
-                    r#match: None,
-                    range: None,
-                    geo_bounding_box: None,
-                    geo_radius: None,
-                    geo_polygon: None,
-                    values_count: None,
-                    is_empty: Some(!exists),
-                    is_null: Some(!exists),
-                    datetime_range: None,
-                },
-            )),
-        })
+/// Whether higher scores mean more similar vectors under `metric`
+///
+/// Cosine and dot product scores are similarities, so higher is better. Euclidean and
+/// Manhattan scores are distances, so lower is better: Qdrant returns them as-is, nearest
+/// first, and treats a score threshold as a maximum distance.
+pub fn is_higher_better(metric: &DistanceMetric) -> bool {
+    match metric {
+        DistanceMetric::Cosine | DistanceMetric::DotProduct => true,
+        DistanceMetric::Euclidean | DistanceMetric::Manhattan => false,
     }
+}
 
-    /// Build Qdrant filter from search parameters with sophisticated filtering
-    fn build_filter(params: &SearchParams) -> Option<Filter> {
-        use qdrant_client::qdrant::{Condition, FieldCondition, Filter, Match};
+/// Whether `score` clears `threshold` under `metric`, as Qdrant applies `score_threshold`
+///
+/// A minimum similarity for cosine and dot product, a maximum distance for Euclidean and
+/// Manhattan.
+pub fn passes_score_threshold(metric: &DistanceMetric, score: f32, threshold: f32) -> bool {
+    if is_higher_better(metric) {
+        score >= threshold
+    } else {
+        score <= threshold
+    }
+}
 
-        if params.filters.is_empty() {
-            return None;
-        }
+/// Build a vector from an embedding-service result, ready to store
+///
+/// The embedding's model and content type are recorded under `config.embedding_model_key()`
+/// and `config.content_type_key()` next to `metadata`, so stored vectors can later be
+/// traced to the model that produced them or searched per modality.
+pub fn vector_from_embedding(
+    id: impl Into<String>,
+    embedding: Embedding,
+    metadata: HashMap<String, serde_json::Value>,
+    config: &QdrantConfig,
+) -> Vector {
+    let mut vector = Vector::new(id.into(), Vec::new());
+    vector.metadata = metadata;
+    apply_embedding(&mut vector, embedding, config);
+    vector
+}
 
-        let mut must_conditions = Vec::new();
+/// Replace a vector's embedding, recording the embedding's model and content type
+fn apply_embedding(vector: &mut Vector, embedding: Embedding, config: &QdrantConfig) {
+    vector.embedding = embedding.vector;
+    vector.add_metadata(
+        config.embedding_model_key(),
+        serde_json::json!(embedding.model),
+    );
+    vector.add_metadata(
+        config.content_type_key(),
+        serde_json::json!(content_type_label(&embedding.content_type)),
+    );
+}
 
-        for (field, value) in &params.filters {
-            let condition = match value {
-                // Support for special filter objects with operators
-                serde_json::Value::Object(obj)
-                    if obj.contains_key("$gte")
-                        || obj.contains_key("$lte")
-                        || obj.contains_key("$gt")
-                        || obj.contains_key("$lt") =>
-                {
-                    match Self::build_range_condition(field, obj) {
-                        Ok(cond) => cond,
-                        Err(_) => continue, // Skip invalid range conditions
-                    }
-                }
-                serde_json::Value::Object(obj) if obj.contains_key("$in") => {
-                    match Self::build_in_condition(field, obj) {
-                        Ok(cond) => cond,
-                        Err(_) => continue, // Skip invalid in conditions
-                    }
-                }
-                serde_json::Value::Object(obj) if obj.contains_key("$ne") => {
-                    match Self::build_not_equals_condition(field, obj) {
-                        Ok(cond) => cond,
-                        Err(_) => continue, // Skip unsupported $ne conditions
-                    }
-                }
-                serde_json::Value::Object(obj) if obj.contains_key("$exists") => {
-                    match Self::build_exists_condition(field, obj) {
-                        Ok(cond) => cond,
-                        Err(_) => continue, // Skip invalid exists conditions
-                    }
-                }
-                serde_json::Value::String(s) => {
-                    let match_value = Match {
-                        match_value: Some(qdrant_client::qdrant::r#match::MatchValue::Keyword(
-                            s.clone(),
-                        )),
-                    };
-                    Condition {
-                        condition_one_of: Some(
-                            qdrant_client::qdrant::condition::ConditionOneOf::Field(
-                                FieldCondition {
-                                    key: field.clone(),
-                                    r#match: Some(match_value),
-                                    range: None,
-                                    geo_bounding_box: None,
-                                    geo_radius: None,
-                                    geo_polygon: None,
-                                    values_count: None,
-                                    is_empty: None,
-                                    is_null: None,
-                                    datetime_range: None,
-                                },
-                            ),
-                        ),
-                    }
-                }
-                serde_json::Value::Number(n) => {
-                    if let Some(int_val) = n.as_i64() {
-                        let match_value = Match {
-                            match_value: Some(qdrant_client::qdrant::r#match::MatchValue::Integer(
-                                int_val,
-                            )),
-                        };
-                        Condition {
-                            condition_one_of: Some(
-                                qdrant_client::qdrant::condition::ConditionOneOf::Field(
-                                    FieldCondition {
-                                        key: field.clone(),
-                                        r#match: Some(match_value),
-                                        range: None,
-                                        geo_bounding_box: None,
-                                        geo_radius: None,
-                                        geo_polygon: None,
-                                        values_count: None,
-                                        is_empty: None,
-                                        is_null: None,
-                                        datetime_range: None,
-                                    },
-                                ),
-                            ),
-                        }
-                    } else if let Some(float_val) = n.as_f64() {
-                        // Convert float to integer for Qdrant compatibility
-                        // Note: For exact float matching, range filters should be used instead
-                        let match_value = Match {
-                            match_value: Some(qdrant_client::qdrant::r#match::MatchValue::Integer(
-                                float_val as i64,
-                            )),
-                        };
-                        Condition {
-                            condition_one_of: Some(
-                                qdrant_client::qdrant::condition::ConditionOneOf::Field(
-                                    FieldCondition {
-                                        key: field.clone(),
-                                        r#match: Some(match_value),
-                                        range: None,
-                                        geo_bounding_box: None,
-                                        geo_radius: None,
-                                        geo_polygon: None,
-                                        values_count: None,
-                                        is_empty: None,
-                                        is_null: None,
-                                        datetime_range: None,
-                                    },
-                                ),
-                            ),
-                        }
-                    } else {
-                        continue; // Skip unsupported number types
-                    }
-                }
-                serde_json::Value::Bool(b) => {
-                    let match_value = Match {
-                        match_value: Some(qdrant_client::qdrant::r#match::MatchValue::Boolean(*b)),
-                    };
-                    Condition {
-                        condition_one_of: Some(
-                            qdrant_client::qdrant::condition::ConditionOneOf::Field(
-                                FieldCondition {
-                                    key: field.clone(),
-                                    r#match: Some(match_value),
-                                    range: None,
-                                    geo_bounding_box: None,
-                                    geo_radius: None,
-                                    geo_polygon: None,
-                                    values_count: None,
-                                    is_empty: None,
-                                    is_null: None,
-                                    datetime_range: None,
-                                },
-                            ),
-                        ),
-                    }
-                }
-                _ => continue, // Skip unsupported value types
-            };
+/// Qdrant-specific search options complementing the port's `SearchParams`
+#[derive(Debug, Clone)]
+pub struct QdrantSearchOptions {
+    /// Return point payloads as result metadata (default: true)
+    pub include_payload: bool,
+    /// Restrict returned payloads to these keys (default: all keys)
+    pub payload_fields: Option<Vec<String>>,
+    /// Fail the search unless the collection uses this distance metric (default: unchecked)
+    pub expected_metric: Option<DistanceMetric>,
+    /// HNSW `ef` at query time; higher trades latency for recall (default: collection setting)
+    pub hnsw_ef: Option<u64>,
+    /// Bypass the HNSW index and run an exact brute-force search (default: false)
+    pub exact: bool,
+    /// Keep only the best result per distinct value of this payload key (default: no dedup)
+    pub dedup_by: Option<String>,
+    /// Only search the shards under this shard key (default: all shards)
+    pub shard_key: Option<String>,
+}
 
-            must_conditions.push(condition);
+impl Default for QdrantSearchOptions {
+    fn default() -> Self {
+        Self {
+            include_payload: true,
+            payload_fields: None,
+            expected_metric: None,
+            hnsw_ef: None,
+            exact: false,
+            dedup_by: None,
+            shard_key: None,
         }
+    }
+}
 
-        if must_conditions.is_empty() {
-            return None;
-        }
+impl QdrantSearchOptions {
+    /// Create search options with default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        Some(Filter {
-            should: Vec::new(),
-            must: must_conditions,
-            must_not: Vec::new(),
-            min_should: None,
-        })
+    /// Set whether payloads are returned, e.g. `false` for lightweight ID/score queries
+    pub fn include_payload(mut self, include: bool) -> Self {
+        self.include_payload = include;
+        self
     }
 
-    /// Build range filter for numeric fields
-    pub fn build_range_filter(field: &str, min: Option<f64>, max: Option<f64>) -> Option<Filter> {
-        use qdrant_client::qdrant::{Condition, FieldCondition, Filter, Range};
+    /// Only return the given payload keys, e.g. to save bandwidth on large payloads
+    pub fn with_payload_fields<I, S>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.payload_fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
 
-        if min.is_none() && max.is_none() {
-            return None;
-        }
+    /// Require the collection to use `metric`, catching query vectors built for another metric
+    pub fn expect_metric(mut self, metric: DistanceMetric) -> Self {
+        self.expected_metric = Some(metric);
+        self
+    }
 
-        let range = Range {
-            lt: max,
-            gt: min,
-            gte: None,
-            lte: None,
-        };
+    /// Set the HNSW `ef` used for this query, e.g. a larger value for recall-critical searches
+    pub fn with_hnsw_ef(mut self, ef: u64) -> Self {
+        self.hnsw_ef = Some(ef);
+        self
+    }
 
-        let condition = Condition {
-            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
-                FieldCondition {
-                    key: field.to_string(),
-                    r#match: None,
-                    range: Some(range),
-                    geo_bounding_box: None,
-                    geo_radius: None,
-                    geo_polygon: None,
-                    values_count: None,
-                    is_empty: None,
-                    is_null: None,
-                    datetime_range: None,
-                },
-            )),
-        };
+    /// Run an exact brute-force search instead of an approximate HNSW search
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
 
-        Some(Filter {
-            should: Vec::new(),
-            must: vec![condition],
-            must_not: Vec::new(),
-            min_should: None,
-        })
+    /// Keep only the best result per value of `key`, e.g. one chunk per `doc_id`
+    ///
+    /// "Best" follows the collection's metric: the highest similarity, or the smallest
+    /// distance on Euclidean and Manhattan collections.
+    ///
+    /// Deduplication runs client-side on the `limit` results Qdrant returns, so fewer than
+    /// `limit` results may come back. Results without `key` in their payload are all kept;
+    /// the key is fetched even when `payload_fields` leaves it out, but not when payloads
+    /// are disabled.
+    pub fn with_dedup_by(mut self, key: impl Into<String>) -> Self {
+        self.dedup_by = Some(key.into());
+        self
     }
 
-    /// Build complex filter combining multiple conditions with logical operators
-    pub fn build_complex_filter(
-        must_conditions: Vec<(String, serde_json::Value)>,
-        should_conditions: Vec<(String, serde_json::Value)>,
-        must_not_conditions: Vec<(String, serde_json::Value)>,
-    ) -> Option<Filter> {
-        use qdrant_client::qdrant::{Condition, FieldCondition, Filter, Match};
+    /// Route the search to the shards under `key`, e.g. a tenant's dedicated shard
+    ///
+    /// The collection must use custom sharding with `key` created.
+    pub fn with_shard_key(mut self, key: impl Into<String>) -> Self {
+        self.shard_key = Some(key.into());
+        self
+    }
+}
 
-        let build_condition_list = |conditions: &[(String, serde_json::Value)]| -> Vec<Condition> {
-            conditions
-                .iter()
-                .filter_map(|(field, value)| {
-                    let match_value = match value {
-                        serde_json::Value::String(s) => Some(
-                            qdrant_client::qdrant::r#match::MatchValue::Keyword(s.clone()),
-                        ),
-                        serde_json::Value::Number(n) if n.is_i64() => Some(
-                            qdrant_client::qdrant::r#match::MatchValue::Integer(n.as_i64()?),
-                        ),
-                        serde_json::Value::Bool(b) => {
-                            Some(qdrant_client::qdrant::r#match::MatchValue::Boolean(*b))
-                        }
-                        _ => None,
-                    }?;
+/// Column layout of a CSV file loaded with `import_csv`
+#[cfg(feature = "import")]
+#[derive(Debug, Clone, Default)]
+pub struct CsvImportOptions {
+    /// Column holding the vector ID
+    pub id_column: String,
+    /// Columns holding the embedding, one float per dimension in order
+    pub embedding_columns: Vec<String>,
+    /// Columns stored as string payload fields (default: none)
+    pub metadata_columns: Vec<String>,
+}
 
-                    Some(Condition {
-                        condition_one_of: Some(
-                            qdrant_client::qdrant::condition::ConditionOneOf::Field(
-                                FieldCondition {
-                                    key: field.clone(),
-                                    r#match: Some(Match {
-                                        match_value: Some(match_value),
-                                    }),
-                                    range: None,
-                                    geo_bounding_box: None,
-                                    geo_radius: None,
-                                    geo_polygon: None,
-                                    values_count: None,
-                                    is_empty: None,
-                                    is_null: None,
-                                    datetime_range: None,
-                                },
-                            ),
-                        ),
-                    })
-                })
-                .collect()
-        };
+#[cfg(feature = "import")]
+impl CsvImportOptions {
+    /// Read IDs from `id_column` and embeddings from `embedding_columns`
+    pub fn new<I, S>(id_column: impl Into<String>, embedding_columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            id_column: id_column.into(),
+            embedding_columns: embedding_columns.into_iter().map(Into::into).collect(),
+            metadata_columns: Vec::new(),
+        }
+    }
 
-        let must = build_condition_list(&must_conditions);
-        let should = build_condition_list(&should_conditions);
-        let must_not = build_condition_list(&must_not_conditions);
+    /// Also store these columns as payload fields, e.g. titles or source URLs
+    pub fn with_metadata_columns<I, S>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.metadata_columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+}
 
-        if must.is_empty() && should.is_empty() && must_not.is_empty() {
-            return None;
-        }
+/// Qdrant-specific collection settings complementing the port's `CollectionConfig`
+///
+/// Unset fields fall back to the `QdrantConfig` defaults.
+#[derive(Debug, Clone, Default)]
+pub struct QdrantCollectionOptions {
+    /// Shard count override (default: `QdrantConfig::default_shard_number`)
+    pub shard_number: Option<u32>,
+    /// Replication factor override (default: `QdrantConfig::default_replication_factor`)
+    pub replication_factor: Option<u32>,
+    /// Write-ahead log settings (default: Qdrant server defaults)
+    pub wal: Option<QdrantWalConfig>,
+    /// Keep payloads on disk (default: `QdrantConfig::default_on_disk_payload`)
+    pub on_disk_payload: Option<bool>,
+    /// Storage type of vector components (default: [`VectorDatatype::Float32`])
+    pub datatype: VectorDatatype,
+    /// Store several vectors per point compared with this comparator (default: one vector)
+    pub multivector: Option<MultiVectorComparator>,
+    /// HNSW index settings (default: Qdrant server defaults)
+    pub hnsw: Option<QdrantHnswConfig>,
+}
 
-        Some(Filter {
-            must,
-            should,
-            must_not,
-            min_should: None, // TODO: Determine correct MinShould type
-        })
+impl QdrantCollectionOptions {
+    /// Create collection options using the adapter defaults
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the shard count, e.g. more shards for a hot collection
+    pub fn with_shard_number(mut self, shard_number: u32) -> Self {
+        self.shard_number = Some(shard_number);
+        self
+    }
+
+    /// Override the replication factor
+    pub fn with_replication_factor(mut self, replication_factor: u32) -> Self {
+        self.replication_factor = Some(replication_factor);
+        self
+    }
+
+    /// Set the write-ahead log settings for durability tuning
+    pub fn with_wal(mut self, wal: QdrantWalConfig) -> Self {
+        self.wal = Some(wal);
+        self
+    }
+
+    /// Keep payloads on disk to save RAM, e.g. for collections with large text payloads
+    pub fn with_on_disk_payload(mut self, on_disk: bool) -> Self {
+        self.on_disk_payload = Some(on_disk);
+        self
+    }
+
+    /// Store vector components as `datatype`, trading precision for memory
+    pub fn with_datatype(mut self, datatype: VectorDatatype) -> Self {
+        self.datatype = datatype;
+        self
+    }
+
+    /// Make this a multivector collection, e.g. for late-interaction (ColBERT) models
+    pub fn with_multivector(mut self, comparator: MultiVectorComparator) -> Self {
+        self.multivector = Some(comparator);
+        self
+    }
+
+    /// Set HNSW index settings, trading build time and memory for recall
+    pub fn with_hnsw(mut self, hnsw: QdrantHnswConfig) -> Self {
+        self.hnsw = Some(hnsw);
+        self
+    }
+
+    /// Validate the overrides
+    pub fn validate(&self) -> VectorResult<()> {
+        if self.shard_number == Some(0) {
+            return Err(qdrant_errors::invalid_collection_options(
+                "shard_number must be at least 1",
+            ));
+        }
+        if self.replication_factor == Some(0) {
+            return Err(qdrant_errors::invalid_collection_options(
+                "replication_factor must be at least 1",
+            ));
+        }
+        if self.wal.as_ref().is_some_and(|wal| wal.capacity_mb == 0) {
+            return Err(qdrant_errors::invalid_collection_options(
+                "wal capacity_mb must be at least 1",
+            ));
+        }
+        if self
+            .hnsw
+            .as_ref()
+            .is_some_and(|hnsw| hnsw.ef_construct.is_some_and(|ef| ef < 4))
+        {
+            return Err(qdrant_errors::invalid_collection_options(
+                "hnsw ef_construct must be at least 4",
+            ));
+        }
+        Ok(())
     }
 }
 
-#[async_trait]
-impl VectorStore for QdrantAdapter {
-    /// Store a single vector in Qdrant
-    async fn store_vector(&self, collection: &str, vector: Vector) -> TylResult<()> {
-        let vector_id = vector.id.clone();
-        let context = format!("Storing vector '{vector_id}' in collection '{collection}'");
+/// Storage type of the vector components in a collection
+///
+/// Smaller types cut vector memory at the cost of precision: `Float16` halves it with
+/// little recall loss for most embeddings, while `Uint8` quarters it but only suits vectors
+/// whose components are already integers in `0..=255` (e.g. quantized embeddings), as other
+/// values are rounded or clamped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VectorDatatype {
+    /// 32-bit floats, full precision (default)
+    #[default]
+    Float32,
+    /// 16-bit floats, half the memory of `Float32`
+    Float16,
+    /// Unsigned bytes, a quarter of the memory of `Float32`
+    Uint8,
+}
+
+/// How the rows of two multivectors are compared in a multivector collection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MultiVectorComparator {
+    /// Sum over query rows of the best-matching stored row (late interaction)
+    #[default]
+    MaxSim,
+}
+
+/// Write-ahead log settings for a collection
+#[derive(Debug, Clone)]
+pub struct QdrantWalConfig {
+    /// Size of a single WAL segment in megabytes (Qdrant default: 32)
+    pub capacity_mb: u64,
+    /// Number of WAL segments created ahead of time (Qdrant default: 0)
+    pub segments_ahead: u64,
+}
+
+impl Default for QdrantWalConfig {
+    fn default() -> Self {
+        Self {
+            capacity_mb: 32,
+            segments_ahead: 0,
+        }
+    }
+}
+
+/// HNSW index settings for a collection; unset fields keep Qdrant's defaults
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QdrantHnswConfig {
+    /// Edges per node in the index graph (Qdrant default: 16)
+    pub m: Option<u64>,
+    /// Neighbours considered while building the index (Qdrant default: 100)
+    pub ef_construct: Option<u64>,
+    /// Size in KB below which searches skip the index (Qdrant default: 10000)
+    pub full_scan_threshold: Option<u64>,
+    /// Keep the index on disk instead of in RAM (Qdrant default: false)
+    pub on_disk: Option<bool>,
+}
+
+/// JSON type a payload field must have under a collection's payload schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExpectedJsonType {
+    /// Any JSON string
+    String,
+    /// Any JSON number, integer or floating point
+    Number,
+    /// A JSON number without a fractional part
+    Integer,
+    /// `true` or `false`
+    Bool,
+    /// A JSON array
+    Array,
+    /// A JSON object
+    Object,
+}
+
+impl ExpectedJsonType {
+    /// Whether `value` has this type
+    pub fn matches(&self, value: &serde_json::Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Number => value.is_number(),
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::Bool => value.is_boolean(),
+            Self::Array => value.is_array(),
+            Self::Object => value.is_object(),
+        }
+    }
+
+    /// Lowercase type name for error messages
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Integer => "integer",
+            Self::Bool => "bool",
+            Self::Array => "array",
+            Self::Object => "object",
+        }
+    }
+}
+
+/// Group of search hits sharing the same value of a payload field
+#[derive(Debug, Clone)]
+pub struct VectorGroup {
+    /// Value of the `group_by` payload field shared by every hit in the group
+    pub group_id: serde_json::Value,
+    /// Best-scoring hits within the group
+    pub hits: Vec<VectorSearchResult>,
+}
+
+/// Running per-value counts behind `distinct_values`
+#[derive(Default)]
+struct FacetCounts {
+    /// JSON values are not hashable, so their serialized form keys the counts
+    counts: HashMap<String, (serde_json::Value, u64)>,
+}
+
+impl FacetCounts {
+    /// Count one payload value; elements of arrays count individually and nulls are ignored
+    fn add(&mut self, value: &serde_json::Value) {
+        match value {
+            serde_json::Value::Null => {}
+            serde_json::Value::Array(elements) => elements
+                .iter()
+                .filter(|element| !element.is_null())
+                .for_each(|element| self.count(element)),
+            value => self.count(value),
+        }
+    }
+
+    fn count(&mut self, value: &serde_json::Value) {
+        self.counts
+            .entry(value.to_string())
+            .or_insert_with(|| (value.clone(), 0))
+            .1 += 1;
+    }
+
+    /// The `limit` most frequent values, ties broken by their serialized form
+    fn top(self, limit: usize) -> Vec<(serde_json::Value, u64)> {
+        let mut facets: Vec<(String, (serde_json::Value, u64))> = self.counts.into_iter().collect();
+        facets.sort_by(|(a_key, (_, a)), (b_key, (_, b))| b.cmp(a).then_with(|| a_key.cmp(b_key)));
+        facets
+            .into_iter()
+            .take(limit)
+            .map(|(_, facet)| facet)
+            .collect()
+    }
+}
+
+/// One line of a JSONL collection export
+#[derive(Serialize, Deserialize)]
+struct ExportedVector {
+    id: String,
+    embedding: Vec<f32>,
+    #[serde(default)]
+    metadata: HashMap<String, serde_json::Value>,
+}
+
+/// Connection details for troubleshooting, richer than `connection_info`
+#[derive(Debug, Clone)]
+pub struct ConnectionDiagnostics {
+    /// Qdrant server URL
+    pub url: String,
+    /// Server version reported by the health check, if reachable
+    pub server_version: Option<String>,
+    /// Whether the server answered the health check
+    pub healthy: bool,
+    /// Number of collections on the server, if they could be listed
+    pub collection_count: Option<usize>,
+    /// Client request timeout
+    pub timeout: Duration,
+}
+
+/// Cluster state reported by Qdrant's `/cluster` endpoint
+///
+/// Single-node deployments without distributed mode report `enabled: false` and leave the
+/// remaining fields empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClusterInfo {
+    /// Whether distributed mode is enabled
+    pub enabled: bool,
+    /// ID of the peer that answered
+    pub peer_id: Option<u64>,
+    /// Every known peer, ordered by ID
+    pub peers: Vec<ClusterPeer>,
+    /// Current Raft term
+    pub raft_term: Option<u64>,
+    /// Index of the last committed Raft entry
+    pub raft_commit: Option<u64>,
+    /// Raft operations not yet applied on the answering peer
+    pub pending_operations: Option<u64>,
+    /// ID of the current Raft leader; `None` while no leader is elected
+    pub leader: Option<u64>,
+    /// Raft role of the answering peer, e.g. `"Leader"` or `"Follower"`
+    pub role: Option<String>,
+    /// State of the consensus thread, e.g. `"working"` or `"stopped"`
+    pub consensus_status: Option<String>,
+    /// Disk space used by the answering peer's collection segments, if the server reports it
+    pub disk_usage_bytes: Option<u64>,
+}
+
+/// One peer of a Qdrant cluster
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterPeer {
+    /// Peer ID
+    pub id: u64,
+    /// URI the peer is reachable at for internal communication
+    pub uri: String,
+}
+
+/// Details of a completed store operation, for metrics at the call site
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoreOutcome {
+    /// ID the vector was stored under
+    pub id: String,
+    /// Collection the vector was stored in
+    pub collection: String,
+    /// Time taken by the store, including validation and the upsert round trip
+    pub duration: Duration,
+}
+
+/// Outcome of `ensure_collection`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnsureOutcome {
+    /// The collection did not exist and was created
+    Created,
+    /// The collection already exists with the requested dimension and metric
+    AlreadyMatches,
+    /// The collection already exists with a different configuration and was left unchanged
+    ConfigMismatch {
+        /// Each difference, e.g. `"dimension is 384, expected 768"`
+        differences: Vec<String>,
+    },
+}
+
+/// Dimension and distance metric of one vector in a collection
+#[derive(Debug, Clone)]
+pub struct NamedVectorConfig {
+    /// Number of components
+    pub dimension: usize,
+    /// Distance metric used to compare this vector
+    pub distance_metric: DistanceMetric,
+}
+
+/// Collection configuration including every named vector, from `get_collection_details`
+#[derive(Debug, Clone)]
+pub struct DetailedCollectionConfig {
+    /// The port's view of the collection, as returned by `get_collection_info`
+    ///
+    /// For named-vector collections this describes the first vector by name.
+    pub config: CollectionConfig,
+    /// Parameters per vector name; a collection with a single unnamed vector has it under `""`
+    pub vectors: std::collections::BTreeMap<String, NamedVectorConfig>,
+}
+
+/// Results of a completed search plus diagnostics, for query analysis at the call site
+#[derive(Debug, Clone)]
+pub struct SearchOutcome {
+    /// Matching vectors, best first
+    pub results: Vec<VectorSearchResult>,
+    /// Time taken by the search, including validation and the query round trip
+    pub elapsed: Duration,
+    /// Number of results returned
+    pub returned_count: usize,
+    /// Whether a score threshold was applied, i.e. the request's `threshold` was set
+    ///
+    /// Qdrant does not report how many candidates the threshold dropped; a `returned_count`
+    /// below the requested limit with this set suggests it cut results.
+    pub threshold_applied: bool,
+    /// Whether higher scores are better in the searched collection; see [`is_higher_better`]
+    pub higher_is_better: bool,
+}
+
+/// Qdrant adapter implementation
+///
+/// `Debug` and `Display` show the endpoint in use but never the API key. They do not show
+/// a collection count, since formatting cannot make the server round trip it needs.
+pub struct QdrantAdapter {
+    client: Qdrant,
+    config: QdrantConfig,
+    #[cfg(feature = "logging")]
+    logger: Box<dyn Logger + Send + Sync>,
+    #[cfg(feature = "tracing")]
+    tracer: Box<dyn TracingManager + Send + Sync>,
+    collection_defaults: std::sync::RwLock<HashMap<String, HashMap<String, serde_json::Value>>>,
+    payload_schemas: std::sync::RwLock<HashMap<String, HashMap<String, ExpectedJsonType>>>,
+    /// Filter fields per collection already checked for a payload index
+    checked_filter_fields: std::sync::RwLock<HashMap<String, std::collections::HashSet<String>>>,
+    /// Distance metric per collection, refreshed whenever this adapter creates, deletes or
+    /// reads the collection's info, since another client may have recreated it
+    collection_metrics: std::sync::RwLock<HashMap<String, DistanceMetric>>,
+    circuit_breaker: Option<CircuitBreaker>,
+}
+
+impl std::fmt::Debug for QdrantAdapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QdrantAdapter")
+            .field("url", &self.config.url)
+            .field("circuit_state", &self.circuit_state())
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl std::fmt::Display for QdrantAdapter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "QdrantAdapter({})", self.config.url)
+    }
+}
+
+impl QdrantAdapter {
+    /// Helper macro for error mapping to reduce duplication
+    ///
+    /// Client errors are also reported to the circuit breaker.
+    fn map_qdrant_error<T, E: std::fmt::Display + 'static>(
+        result: Result<T, E>,
+        context: &str,
+    ) -> VectorResult<T> {
+        result.map_err(|e| {
+            circuit_breaker::observe_error(&e);
+            vector_errors::storage_failed(format!("{context}: {e}"))
+        })
+    }
+
+    /// Map an error of an operation on `collection`, reporting a missing collection as such
+    ///
+    /// Only a `NotFound` status naming a collection counts; missing points or shard keys stay
+    /// `storage_failed`.
+    fn map_point_error<T, E: std::fmt::Display + 'static>(
+        result: Result<T, E>,
+        collection: &str,
+        context: &str,
+    ) -> VectorResult<T> {
+        result.map_err(|e| {
+            circuit_breaker::observe_error(&e);
+            if Self::missing_collection(&e).is_some() {
+                vector_errors::collection_not_found(collection)
+            } else {
+                vector_errors::storage_failed(format!("{context}: {e}"))
+            }
+        })
+    }
+
+    /// Server-side name of the collection a qdrant-client error reports as missing
+    fn missing_collection<E: 'static>(error: &E) -> Option<&str> {
+        match (error as &dyn std::any::Any).downcast_ref::<qdrant_client::QdrantError>() {
+            Some(qdrant_client::QdrantError::ResponseError { status }) => {
+                Self::missing_collection_in_status(status.code() as i32, status.message())
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a qdrant-client error reports a missing point rather than a missing collection
+    fn missing_point<E: 'static>(error: &E) -> bool {
+        match (error as &dyn std::any::Any).downcast_ref::<qdrant_client::QdrantError>() {
+            Some(qdrant_client::QdrantError::ResponseError { status }) => {
+                status.code() as i32 == NOT_FOUND_CODE
+                    && Self::missing_collection_in_status(NOT_FOUND_CODE, status.message())
+                        .is_none()
+            }
+            _ => false,
+        }
+    }
+
+    /// Collection named by a `NotFound` status such as "Collection `docs` doesn't exist!"
+    fn missing_collection_in_status(code: i32, message: &str) -> Option<&str> {
+        if code != NOT_FOUND_CODE {
+            return None;
+        }
+        let (_, rest) = message.split_once("Collection `")?;
+        let (name, rest) = rest.split_once('`')?;
+        rest.contains("doesn't exist").then_some(name)
+    }
+
+    /// Server-side name of a logical collection name
+    fn server_collection(&self, collection: &str) -> String {
+        self.config.qualified_collection_name(collection)
+    }
+
+    /// Helper for common telemetry (logging + tracing) operations
+    ///
+    /// Operations are admitted by the circuit breaker and their outcome recorded by it.
+    async fn with_telemetry<F, T>(
+        &self,
+        operation: &str,
+        context: &str,
+        operation_fn: F,
+    ) -> TylResult<T>
+    where
+        F: std::future::Future<Output = TylResult<T>>,
+    {
+        // Operations nested in another one were admitted with it and are recorded by it
+        let permit = match &self.circuit_breaker {
+            Some(breaker) if !circuit_breaker::in_operation() => {
+                match breaker.try_admit(Instant::now()) {
+                    Some(permit) => Some(permit),
+                    None => {
+                        self.log(QdrantLogLevel::Warn, || {
+                            format!("Circuit breaker open, rejected {operation} - {context}")
+                        });
+                        return Err(vector_errors::connection_failed(format!(
+                            "Circuit breaker open: Qdrant failed repeatedly, {operation} not \
+                             attempted"
+                        )));
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        self.with_telemetry_outside_breaker(operation, context, async {
+            match permit {
+                Some(permit) => {
+                    let (result, transport_failed) =
+                        circuit_breaker::track_transport_failures(operation_fn).await;
+                    permit.finish(result.is_ok(), transport_failed);
+                    result
+                }
+                None => operation_fn.await,
+            }
+        })
+        .await
+    }
+
+    /// Log and trace an operation without circuit breaker admission or recording
+    ///
+    /// For operations that must keep working, and must not close the breaker, while Qdrant
+    /// is unreachable.
+    #[cfg_attr(not(feature = "logging"), allow(unused_variables))]
+    async fn with_telemetry_outside_breaker<F, T>(
+        &self,
+        operation: &str,
+        context: &str,
+        operation_fn: F,
+    ) -> TylResult<T>
+    where
+        F: std::future::Future<Output = TylResult<T>>,
+    {
+        // Spans join the caller's trace when run within `with_parent_span`
+        #[cfg(feature = "tracing")]
+        let span_id = Self::map_qdrant_error(
+            self.tracer
+                .start_span(operation, trace_context::current_parent_span()),
+            "Failed to start trace",
+        )?;
+
+        #[cfg(feature = "logging")]
+        let start_time = Instant::now();
+        self.log(QdrantLogLevel::Info, || format!("{operation} - {context}"));
+
+        let result = operation_fn.await;
+
+        #[cfg(feature = "logging")]
+        self.log_outcome(operation, context, start_time.elapsed(), &result);
+
+        #[cfg(feature = "tracing")]
+        Self::map_qdrant_error(self.tracer.end_span(span_id), "Failed to end trace")?;
+
+        result
+    }
+
+    /// Log how a telemetry-wrapped operation finished
+    #[cfg(feature = "logging")]
+    fn log_outcome<T>(
+        &self,
+        operation: &str,
+        context: &str,
+        duration: Duration,
+        result: &TylResult<T>,
+    ) {
+        match result {
+            Ok(_) => self.log(QdrantLogLevel::Info, || {
+                format!("Completed {operation} in {duration:?} - {context}")
+            }),
+            Err(e) => self.log(QdrantLogLevel::Error, || {
+                format!("Failed {operation} in {duration:?} - {context}: {e}")
+            }),
+        }
+    }
+
+    /// Log a record unless it is below the configured `log_level`
+    ///
+    /// The message is only built once the level check passes.
+    #[cfg_attr(not(feature = "logging"), allow(unused_variables))]
+    fn log(&self, level: QdrantLogLevel, message: impl FnOnce() -> String) {
+        #[cfg(feature = "logging")]
+        if self.config.log_level.enables(level) {
+            self.logger.log(&LogRecord::new(level.into(), message()));
+        }
+    }
+
+    /// Log operation detail at debug level; info-level telemetry stays unchanged
+    fn log_debug(&self, message: impl FnOnce() -> String) {
+        self.log(QdrantLogLevel::Debug, message);
+    }
+
+    /// Create the logger for the configured output format
+    #[cfg(feature = "logging")]
+    fn build_logger(format: QdrantLogFormat) -> Box<dyn Logger + Send + Sync> {
+        match format {
+            QdrantLogFormat::Json => Box::new(JsonLogger::new()),
+            QdrantLogFormat::Text => Box::new(ConsoleLogger::new()),
+        }
+    }
+
+    /// Debug summary of a search: result count and score range
+    fn search_summary(collection: &str, results: &[VectorSearchResult]) -> String {
+        let scores = results.iter().map(|result| result.score);
+        let max_score = scores.clone().fold(f32::NEG_INFINITY, f32::max);
+        let min_score = scores.fold(f32::INFINITY, f32::min);
+        match results.len() {
+            0 => format!("Search in collection '{collection}' returned 0 results"),
+            count => format!(
+                "Search in collection '{collection}' returned {count} results \
+                 (max score {max_score:.4}, min score {min_score:.4})"
+            ),
+        }
+    }
+
+    /// Debug summary of a batch store: number of points written
+    fn batch_store_summary(collection: &str, point_count: usize) -> String {
+        format!("Wrote {point_count} points to collection '{collection}'")
+    }
+
+    /// Create a new QdrantAdapter from configuration
+    ///
+    /// Endpoints are tried in order until one passes the connection test; the adapter's
+    /// `url` is then set to the endpoint in use.
+    async fn new(config: QdrantConfig) -> VectorResult<Self> {
+        config.validate()?;
+
+        let endpoints: Vec<String> = config.endpoints().into_iter().map(String::from).collect();
+        let (url, client) = Self::connect_with_failover(&endpoints, |url| {
+            let client = Self::build_client(&url, &config);
+            async move {
+                let client = client?;
+                Self::test_connection(&client).await?;
+                Ok(client)
+            }
+        })
+        .await?;
+
+        let mut config = config;
+        config.url = url;
+        Ok(Self::from_client(client, config))
+    }
+
+    /// Assemble an adapter around an already connected client
+    fn from_client(client: Qdrant, config: QdrantConfig) -> Self {
+        #[cfg(feature = "logging")]
+        let logger = Self::build_logger(config.log_format);
+        let circuit_breaker = CircuitBreaker::from_config(&config);
+        #[cfg(feature = "tracing")]
+        let tracer = Box::new(SimpleTracer::new(TraceConfig::new("tyl-qdrant-adapter")));
+
+        Self {
+            client,
+            config,
+            #[cfg(feature = "logging")]
+            logger,
+            #[cfg(feature = "tracing")]
+            tracer,
+            collection_defaults: std::sync::RwLock::new(HashMap::new()),
+            payload_schemas: std::sync::RwLock::new(HashMap::new()),
+            checked_filter_fields: std::sync::RwLock::new(HashMap::new()),
+            collection_metrics: std::sync::RwLock::new(HashMap::new()),
+            circuit_breaker,
+        }
+    }
+
+    /// Create a Qdrant client for one endpoint using new API
+    fn build_client(url: &str, config: &QdrantConfig) -> VectorResult<Qdrant> {
+        let mut client_builder =
+            Qdrant::from_url(url).timeout(Duration::from_secs(config.timeout_seconds));
+
+        if let Some(api_key) = &config.api_key {
+            client_builder = client_builder.api_key(api_key.clone());
+        }
+
+        client_builder.build().map_err(|e| {
+            vector_errors::connection_failed(format!("Failed to create Qdrant client: {e}"))
+        })
+    }
+
+    /// Try each endpoint in order, returning the first one that connects
+    async fn connect_with_failover<T, F, Fut>(
+        endpoints: &[String],
+        mut connect: F,
+    ) -> VectorResult<(String, T)>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: std::future::Future<Output = VectorResult<T>>,
+    {
+        let mut failures = Vec::with_capacity(endpoints.len());
+        for endpoint in endpoints {
+            match connect(endpoint.clone()).await {
+                Ok(connection) => return Ok((endpoint.clone(), connection)),
+                Err(e) => failures.push(format!("{endpoint}: {e}")),
+            }
+        }
+        Err(qdrant_errors::connection_failed(format!(
+            "No reachable endpoint ({})",
+            failures.join("; ")
+        )))
+    }
+
+    /// Test Qdrant connection
+    async fn test_connection(client: &Qdrant) -> VectorResult<()> {
+        // Try health check, but don't fail immediately on version incompatibility
+        match client.health_check().await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                let error_str = e.to_string();
+                // If it's just a compatibility check warning, try to continue
+                if error_str.contains("check client-server compatibility")
+                    || error_str.contains("Set check_compatibility=false")
+                {
+                    println!("⚠️  Version compatibility warning: {error_str}");
+                    // Don't fail on compatibility warnings, just log them
+                    Ok(())
+                } else {
+                    Err(vector_errors::connection_failed(format!(
+                        "Qdrant health check failed: {e}"
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Swap in a client using a rotated API key, e.g. after a credentials rotation
+    ///
+    /// The new client must pass the connection test against the endpoint in use before it
+    /// replaces the current one; on failure the adapter keeps its previous client and key.
+    /// Config, logger, tracer, caches and circuit breaker state are preserved.
+    pub async fn reconnect_with_api_key(&mut self, api_key: Option<String>) -> VectorResult<()> {
+        let mut config = self.config.clone();
+        config.api_key = api_key;
+
+        let client = Self::build_client(&config.url, &config)?;
+        Self::test_connection(&client).await?;
+
+        self.client = client;
+        self.config = config;
+        self.log(QdrantLogLevel::Info, || {
+            format!("Reconnected to {} with a new API key", self.config.url)
+        });
+        Ok(())
+    }
+
+    /// Configuration the adapter is running with; `url` is the endpoint in use
+    pub fn config(&self) -> &QdrantConfig {
+        &self.config
+    }
+
+    /// Parse a distance metric from text such as config files or migration parameters
+    ///
+    /// Case-insensitive; accepts "cosine", "euclidean", "dot"/"dotproduct" and "manhattan".
+    pub fn distance_metric_from_str(value: &str) -> VectorResult<DistanceMetric> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "cosine" => Ok(DistanceMetric::Cosine),
+            "euclidean" => Ok(DistanceMetric::Euclidean),
+            "dot" | "dotproduct" => Ok(DistanceMetric::DotProduct),
+            "manhattan" => Ok(DistanceMetric::Manhattan),
+            _ => Err(qdrant_errors::invalid_distance_metric(value)),
+        }
+    }
+
+    /// Convert TYL DistanceMetric to Qdrant Distance (necessary for adapter pattern)
+    fn distance_metric_to_qdrant(metric: &DistanceMetric) -> Distance {
+        match metric {
+            DistanceMetric::Cosine => Distance::Cosine,
+            DistanceMetric::Euclidean => Distance::Euclid,
+            DistanceMetric::DotProduct => Distance::Dot,
+            DistanceMetric::Manhattan => Distance::Manhattan,
+        }
+    }
+
+    /// Convert JSON value to Qdrant value - helper for metadata conversion
+    ///
+    /// Qdrant integers are signed 64-bit, so unsigned values above `i64::MAX` are stored as
+    /// doubles. They stay numeric for range filters but lose precision beyond 2^53.
+    fn json_to_qdrant_value(value: serde_json::Value) -> Option<qdrant_client::qdrant::Value> {
+        let kind = match value {
+            serde_json::Value::Null => qdrant_client::qdrant::value::Kind::NullValue(
+                qdrant_client::qdrant::NullValue::NullValue as i32,
+            ),
+            serde_json::Value::String(s) => qdrant_client::qdrant::value::Kind::StringValue(s),
+            serde_json::Value::Number(n) if n.is_i64() => {
+                qdrant_client::qdrant::value::Kind::IntegerValue(n.as_i64()?)
+            }
+            serde_json::Value::Number(n) if n.is_u64() => {
+                qdrant_client::qdrant::value::Kind::DoubleValue(n.as_u64()? as f64)
+            }
+            serde_json::Value::Number(n) if n.is_f64() => {
+                qdrant_client::qdrant::value::Kind::DoubleValue(n.as_f64()?)
+            }
+            serde_json::Value::Bool(b) => qdrant_client::qdrant::value::Kind::BoolValue(b),
+            serde_json::Value::Array(values) => {
+                qdrant_client::qdrant::value::Kind::ListValue(qdrant_client::qdrant::ListValue {
+                    values: values
+                        .into_iter()
+                        .filter_map(Self::json_to_qdrant_value)
+                        .collect(),
+                })
+            }
+            serde_json::Value::Object(map) => {
+                qdrant_client::qdrant::value::Kind::StructValue(qdrant_client::qdrant::Struct {
+                    fields: map
+                        .into_iter()
+                        .filter_map(|(key, value)| Some((key, Self::json_to_qdrant_value(value)?)))
+                        .collect(),
+                })
+            }
+            _ => return None, // Skip unsupported types
+        };
+
+        Some(qdrant_client::qdrant::Value { kind: Some(kind) })
+    }
+
+    /// Convert a TYL vector ID to a Qdrant PointId, rejecting IDs Qdrant cannot accept
+    ///
+    /// Qdrant only accepts UUIDs or unsigned integers as point IDs, so anything else is
+    /// rejected locally instead of surfacing as an opaque server error. Integer IDs are sent
+    /// as numeric point IDs and UUIDs in their canonical lowercase hyphenated form.
+    fn parse_point_id(id: &str) -> VectorResult<PointId> {
+        if let Ok(num) = id.parse::<u64>() {
+            return Ok(PointId::from(num));
+        }
+        if let Ok(uuid) = uuid::Uuid::parse_str(id) {
+            return Ok(PointId::from(uuid.to_string()));
+        }
+        Err(qdrant_errors::invalid_point_id(
+            id,
+            "Qdrant point IDs must be a UUID or an unsigned integer",
+        ))
+    }
+
+    /// Derive a deterministic UUIDv5 from an arbitrary string ID
+    fn hash_string_id(id: &str) -> uuid::Uuid {
+        uuid::Uuid::new_v5(&STRING_ID_NAMESPACE, id.as_bytes())
+    }
+
+    /// Resolve a TYL vector ID to a Qdrant PointId, hashing string IDs when enabled
+    fn resolve_point_id(id: &str, config: &QdrantConfig) -> VectorResult<PointId> {
+        match Self::parse_point_id(id) {
+            Ok(point_id) => Ok(point_id),
+            Err(_) if config.hash_string_ids => {
+                Ok(PointId::from(Self::hash_string_id(id).to_string()))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Convert TYL Vector to Qdrant PointStruct (necessary for adapter pattern)
+    fn vector_to_point_struct(vector: Vector, config: &QdrantConfig) -> VectorResult<PointStruct> {
+        let point_id = Self::resolve_point_id(&vector.id, config)?;
+        let mut payload = Payload::new();
+
+        for (key, value) in vector.metadata {
+            if let Some(qdrant_value) = Self::json_to_qdrant_value(value) {
+                payload.insert(key, qdrant_value);
+            }
+        }
+
+        // Keep the caller's ID whenever Qdrant would hand back a different spelling (hashed
+        // IDs, "042", upper-case UUIDs) so it can be restored when the point is read back
+        let canonical_id = Self::extract_point_id(Some(point_id.clone())).ok();
+        if canonical_id.as_deref() != Some(vector.id.as_str()) {
+            payload.insert(config.original_id_key(), vector.id);
+        }
+
+        Ok(PointStruct::new(point_id, vector.embedding, payload))
+    }
+
+    /// Extract point ID from Qdrant point - helper for point conversion
+    fn extract_point_id(point_id: Option<qdrant_client::qdrant::PointId>) -> VectorResult<String> {
+        let point_id =
+            point_id.ok_or_else(|| vector_errors::vector_not_found("missing point ID"))?;
+
+        match point_id.point_id_options {
+            Some(qdrant_client::qdrant::point_id::PointIdOptions::Uuid(uuid)) => Ok(uuid),
+            Some(qdrant_client::qdrant::point_id::PointIdOptions::Num(num)) => Ok(num.to_string()),
+            None => Err(vector_errors::vector_not_found("missing point ID")),
+        }
+    }
+
+    /// Extract vector data from Qdrant vectors - helper for point conversion
+    ///
+    /// Points fetched without vectors yield an empty embedding, mirroring how points fetched
+    /// without payload yield empty metadata.
+    fn extract_vector_data(
+        vectors: Option<qdrant_client::qdrant::VectorsOutput>,
+    ) -> VectorResult<Vec<f32>> {
+        let Some(vectors) = vectors else {
+            return Ok(Vec::new());
+        };
+
+        match vectors.vectors_options {
+            Some(vectors_output::VectorsOptions::Vector(vector_data)) => Ok(vector_data.data),
+            _ => Err(vector_errors::storage_failed("Invalid vector format")),
+        }
+    }
+
+    /// Convert Qdrant value to JSON value - helper for metadata conversion
+    fn qdrant_to_json_value(value: qdrant_client::qdrant::Value) -> Option<serde_json::Value> {
+        match value.kind? {
+            qdrant_client::qdrant::value::Kind::NullValue(_) => Some(serde_json::Value::Null),
+            qdrant_client::qdrant::value::Kind::StringValue(s) => {
+                Some(serde_json::Value::String(s))
+            }
+            qdrant_client::qdrant::value::Kind::IntegerValue(i) => {
+                Some(serde_json::Value::Number(serde_json::Number::from(i)))
+            }
+            qdrant_client::qdrant::value::Kind::DoubleValue(d) => {
+                serde_json::Number::from_f64(d).map(serde_json::Value::Number)
+            }
+            qdrant_client::qdrant::value::Kind::BoolValue(b) => Some(serde_json::Value::Bool(b)),
+            qdrant_client::qdrant::value::Kind::ListValue(list) => Some(serde_json::Value::Array(
+                list.values
+                    .into_iter()
+                    .filter_map(Self::qdrant_to_json_value)
+                    .collect(),
+            )),
+            qdrant_client::qdrant::value::Kind::StructValue(object) => {
+                Some(serde_json::Value::Object(
+                    object
+                        .fields
+                        .into_iter()
+                        .filter_map(|(key, value)| Some((key, Self::qdrant_to_json_value(value)?)))
+                        .collect(),
+                ))
+            }
+        }
+    }
+
+    /// Convert Qdrant ScoredPoint to TYL Vector (necessary for adapter pattern)
+    fn point_to_vector(
+        point: qdrant_client::qdrant::ScoredPoint,
+        config: &QdrantConfig,
+    ) -> VectorResult<Vector> {
+        let mut id = Self::extract_point_id(point.id)?;
+        let embedding = Self::extract_vector_data(point.vectors)?;
+        let mut metadata = Self::payload_to_metadata(point.payload);
+
+        // Restore the caller's ID for points stored under a hashed UUID
+        if let Some(serde_json::Value::String(original_id)) =
+            metadata.remove(&config.original_id_key())
+        {
+            id = original_id;
+        }
+
+        Ok(Vector {
+            id,
+            embedding,
+            metadata,
+        })
+    }
+
+    /// Convert a Qdrant payload to metadata, skipping values without a JSON equivalent
+    fn payload_to_metadata(
+        payload: HashMap<String, qdrant_client::qdrant::Value>,
+    ) -> HashMap<String, serde_json::Value> {
+        payload
+            .into_iter()
+            .filter_map(|(key, value)| Some((key, Self::qdrant_to_json_value(value)?)))
+            .collect()
+    }
+
+    /// Build a payload selector - helper for request construction
+    fn payload_selector(enable: bool) -> WithPayloadSelector {
+        WithPayloadSelector {
+            selector_options: Some(
+                qdrant_client::qdrant::with_payload_selector::SelectorOptions::Enable(enable),
+            ),
+        }
+    }
+
+    /// Build the payload selector for a search from its options
+    fn search_payload_selector(
+        options: &QdrantSearchOptions,
+        config: &QdrantConfig,
+    ) -> WithPayloadSelector {
+        match &options.payload_fields {
+            Some(fields) if options.include_payload => {
+                let mut fields = fields.clone();
+                // Hashed IDs can only be restored if the original ID comes back too
+                fields.push(config.original_id_key());
+                fields.extend(options.dedup_by.clone());
+                WithPayloadSelector {
+                    selector_options: Some(
+                        qdrant_client::qdrant::with_payload_selector::SelectorOptions::Include(
+                            qdrant_client::qdrant::PayloadIncludeSelector { fields },
+                        ),
+                    ),
+                }
+            }
+            _ => Self::payload_selector(options.include_payload),
+        }
+    }
+
+    /// Build a vectors selector - helper for request construction
+    fn vectors_selector(enable: bool) -> WithVectorsSelector {
+        WithVectorsSelector {
+            selector_options: Some(
+                qdrant_client::qdrant::with_vectors_selector::SelectorOptions::Enable(enable),
+            ),
+        }
+    }
+
+    /// Convert Qdrant GroupId to JSON value - helper for group conversion
+    fn group_id_to_json(group_id: Option<qdrant_client::qdrant::GroupId>) -> serde_json::Value {
+        use qdrant_client::qdrant::group_id::Kind;
+
+        match group_id.and_then(|id| id.kind) {
+            Some(Kind::UnsignedValue(u)) => serde_json::json!(u),
+            Some(Kind::IntegerValue(i)) => serde_json::json!(i),
+            Some(Kind::StringValue(s)) => serde_json::Value::String(s),
+            None => serde_json::Value::Null,
+        }
+    }
+
+    /// Convert Qdrant RetrievedPoint (from get/scroll) to TYL Vector
+    fn retrieved_point_to_vector(
+        point: qdrant_client::qdrant::RetrievedPoint,
+        config: &QdrantConfig,
+    ) -> VectorResult<Vector> {
+        let scored_point = qdrant_client::qdrant::ScoredPoint {
+            id: point.id,
+            payload: point.payload,
+            score: 1.0, // Not used for retrieval
+            vectors: point.vectors,
+            shard_key: None,
+            order_value: None,
+            version: 0,
+        };
+        Self::point_to_vector(scored_point, config)
+    }
+
+    /// Build range condition from filter object (e.g. {"$gte": 10, "$lte": 20})
+    fn build_range_condition(
+        field: &str,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> VectorResult<qdrant_client::qdrant::Condition> {
+        use qdrant_client::qdrant::{Condition, FieldCondition, Range};
+
+        let mut gte = None;
+        let mut lte = None;
+        let mut gt = None;
+        let mut lt = None;
+
+        for (op, value) in obj {
+            let num_val = value.as_f64().ok_or_else(|| {
+                qdrant_errors::invalid_search_params(format!(
+                    "range bound {op} on '{field}' must be a number, got {value}"
+                ))
+            })?;
+
+            match op.as_str() {
+                "$gte" => gte = Some(num_val),
+                "$lte" => lte = Some(num_val),
+                "$gt" => gt = Some(num_val),
+                "$lt" => lt = Some(num_val),
+                _ => continue,
+            }
+        }
+
+        let range = Range { gte, lte, gt, lt };
+
+        Ok(Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
+                FieldCondition {
+                    key: field.to_string(),
+                    r#match: None,
+                    range: Some(range),
+                    geo_bounding_box: None,
+                    geo_radius: None,
+                    geo_polygon: None,
+                    values_count: None,
+                    is_empty: None,
+                    is_null: None,
+                    datetime_range: None,
+                },
+            )),
+        })
+    }
+
+    /// Build an exact match condition for a scalar filter value
+    fn build_match_condition(
+        field: &str,
+        value: &serde_json::Value,
+    ) -> Option<qdrant_client::qdrant::Condition> {
+        use qdrant_client::qdrant::{r#match::MatchValue, Condition, FieldCondition, Match};
+
+        let match_value = match value {
+            serde_json::Value::String(s) => MatchValue::Keyword(s.clone()),
+            serde_json::Value::Number(n) if n.is_i64() => MatchValue::Integer(n.as_i64()?),
+            // Convert float to integer for compatibility with Qdrant
+            serde_json::Value::Number(n) if n.is_f64() => MatchValue::Integer(n.as_f64()? as i64),
+            serde_json::Value::Bool(b) => MatchValue::Boolean(*b),
+            _ => return None,
+        };
+
+        Some(Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
+                FieldCondition {
+                    key: field.to_string(),
+                    r#match: Some(Match {
+                        match_value: Some(match_value),
+                    }),
+                    ..Default::default()
+                },
+            )),
+        })
+    }
+
+    /// Build a condition matching any of `values`, as a nested `should` filter
+    fn build_any_of_condition(
+        field: &str,
+        values: &[serde_json::Value],
+    ) -> VectorResult<qdrant_client::qdrant::Condition> {
+        use qdrant_client::qdrant::{Condition, Filter};
+
+        let should: Vec<Condition> = values
+            .iter()
+            .filter_map(|value| Self::build_match_condition(field, value))
+            .collect();
+        if should.is_empty() {
+            return Err(qdrant_errors::invalid_search_params(format!(
+                "filter on '{field}' needs at least one string, number or bool value"
+            )));
+        }
+
+        Ok(Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Filter(
+                Filter {
+                    should,
+                    ..Default::default()
+                },
+            )),
+        })
+    }
+
+    /// Build IN condition from filter object (e.g. {"$in": ["value1", "value2"]})
+    fn build_in_condition(
+        field: &str,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> VectorResult<qdrant_client::qdrant::Condition> {
+        match obj.get("$in") {
+            Some(serde_json::Value::Array(values)) => Self::build_any_of_condition(field, values),
+            _ => Err(qdrant_errors::invalid_search_params(format!(
+                "$in filter on '{field}' must be an array"
+            ))),
+        }
+    }
+
+    /// Build NOT EQUALS condition from filter object (e.g. {"$ne": "value"})
+    fn build_not_equals_condition(
+        field: &str,
+        _obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> VectorResult<qdrant_client::qdrant::Condition> {
+        // For now, return an error as NOT EQUALS is complex in Qdrant
+        // Would need to be implemented using must_not in the filter
+        Err(qdrant_errors::invalid_search_params(format!(
+            "$ne filter on '{field}' is not supported yet"
+        )))
+    }
+
+    /// Build EXISTS condition from filter object (e.g. {"$exists": true})  
+    fn build_exists_condition(
+        field: &str,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> VectorResult<qdrant_client::qdrant::Condition> {
+        use qdrant_client::qdrant::{Condition, FieldCondition};
+
+        let exists = obj.get("$exists").and_then(|v| v.as_bool()).unwrap_or(true);
+
+        Ok(Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
+                FieldCondition {
+                    key: field.to_string(),
+                    r#match: None,
+                    range: None,
+                    geo_bounding_box: None,
+                    geo_radius: None,
+                    geo_polygon: None,
+                    values_count: None,
+                    is_empty: Some(!exists),
+                    is_null: Some(!exists),
+                    datetime_range: None,
+                },
+            )),
+        })
+    }
+
+    /// Build IS NULL condition from filter object (e.g. {"$is_null": true})
+    fn build_is_null_condition(
+        field: &str,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> VectorResult<qdrant_client::qdrant::Condition> {
+        use qdrant_client::qdrant::{Condition, Filter, IsNullCondition};
+
+        let is_null = obj
+            .get("$is_null")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let condition = Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::IsNull(
+                IsNullCondition {
+                    key: field.to_string(),
+                },
+            )),
+        };
+
+        if is_null {
+            return Ok(condition);
+        }
+
+        // Negate through a nested filter since build_filter only produces `must` clauses
+        Ok(Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Filter(
+                Filter {
+                    must_not: vec![condition],
+                    ..Default::default()
+                },
+            )),
+        })
+    }
+
+    /// Build full-text match condition from filter object (e.g. {"$text": "wireless headphones"})
+    ///
+    /// Matches points whose text field contains every word of the query. The field needs a
+    /// text payload index, which `auto_index_filter_fields` creates when `filter_index_types`
+    /// maps the field to `text`;
+    /// without one Qdrant falls back to a plain substring match.
+    fn build_text_match_condition(
+        field: &str,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> VectorResult<qdrant_client::qdrant::Condition> {
+        use qdrant_client::qdrant::{r#match::MatchValue, Condition, FieldCondition, Match};
+
+        let text = match obj.get("$text") {
+            Some(serde_json::Value::String(text)) if !text.trim().is_empty() => text,
+            _ => {
+                return Err(qdrant_errors::invalid_search_params(format!(
+                    "$text filter on '{field}' must be a non-empty string"
+                )))
+            }
+        };
+
+        Ok(Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
+                FieldCondition {
+                    key: field.to_string(),
+                    r#match: Some(Match {
+                        match_value: Some(MatchValue::Text(text.clone())),
+                    }),
+                    ..Default::default()
+                },
+            )),
+        })
+    }
+
+    /// Build IS EMPTY condition from filter object (e.g. {"$is_empty": true})
+    ///
+    /// Matches points where the field is missing, null or an empty array.
+    fn build_is_empty_condition(
+        field: &str,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> VectorResult<qdrant_client::qdrant::Condition> {
+        use qdrant_client::qdrant::{Condition, Filter, IsEmptyCondition};
+
+        let is_empty = obj
+            .get("$is_empty")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(true);
+
+        let condition = Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::IsEmpty(
+                IsEmptyCondition {
+                    key: field.to_string(),
+                },
+            )),
+        };
+
+        if is_empty {
+            return Ok(condition);
+        }
+
+        Ok(Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Filter(
+                Filter {
+                    must_not: vec![condition],
+                    ..Default::default()
+                },
+            )),
+        })
+    }
+
+    /// Point ID a `$has_id` filter refers to for a TYL vector ID
+    ///
+    /// IDs that are neither UUIDs nor unsigned integers are hashed as with `hash_string_ids`;
+    /// when that is disabled no point can be stored under them, so they match nothing.
+    fn filter_point_id(id: &str) -> PointId {
+        Self::parse_point_id(id)
+            .unwrap_or_else(|_| PointId::from(Self::hash_string_id(id).to_string()))
+    }
+
+    /// Build HAS ID condition from filter object (e.g. {"$has_id": ["id1", "id2"]})
+    ///
+    /// Restricts results to the listed point IDs, e.g. candidates that passed an access
+    /// check. The entry's field name is not used.
+    fn build_has_id_condition(
+        field: &str,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> VectorResult<qdrant_client::qdrant::Condition> {
+        use qdrant_client::qdrant::{Condition, HasIdCondition};
+
+        let invalid = || {
+            qdrant_errors::invalid_search_params(format!(
+                "$has_id filter on '{field}' must be a non-empty array of string or unsigned \
+                 integer IDs"
+            ))
+        };
+
+        let ids = match obj.get("$has_id") {
+            Some(serde_json::Value::Array(ids)) if !ids.is_empty() => ids,
+            _ => return Err(invalid()),
+        };
+        let has_id = ids
+            .iter()
+            .map(|id| match id {
+                serde_json::Value::String(s) => Ok(Self::filter_point_id(s)),
+                serde_json::Value::Number(n) => n.as_u64().map(PointId::from).ok_or_else(invalid),
+                _ => Err(invalid()),
+            })
+            .collect::<VectorResult<Vec<PointId>>>()?;
+
+        Ok(Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::HasId(
+                HasIdCondition { has_id },
+            )),
+        })
+    }
+
+    /// Build the condition for one filter entry, or explain why it cannot be translated
+    fn build_filter_condition(
+        field: &str,
+        value: &serde_json::Value,
+    ) -> VectorResult<qdrant_client::qdrant::Condition> {
+        match value {
+            // Support for special filter objects with operators
+            serde_json::Value::Object(obj)
+                if obj.contains_key("$gte")
+                    || obj.contains_key("$lte")
+                    || obj.contains_key("$gt")
+                    || obj.contains_key("$lt") =>
+            {
+                Self::build_range_condition(field, obj)
+            }
+            serde_json::Value::Object(obj) if obj.contains_key("$in") => {
+                Self::build_in_condition(field, obj)
+            }
+            serde_json::Value::Object(obj) if obj.contains_key("$ne") => {
+                Self::build_not_equals_condition(field, obj)
+            }
+            serde_json::Value::Object(obj) if obj.contains_key("$is_null") => {
+                Self::build_is_null_condition(field, obj)
+            }
+            serde_json::Value::Object(obj) if obj.contains_key("$exists") => {
+                Self::build_exists_condition(field, obj)
+            }
+            serde_json::Value::Object(obj) if obj.contains_key("$is_empty") => {
+                Self::build_is_empty_condition(field, obj)
+            }
+            serde_json::Value::Object(obj) if obj.contains_key("$has_id") => {
+                Self::build_has_id_condition(field, obj)
+            }
+            serde_json::Value::Object(obj) if obj.contains_key("$text") => {
+                Self::build_text_match_condition(field, obj)
+            }
+            serde_json::Value::Object(obj) => {
+                let operators: Vec<&str> = obj.keys().map(String::as_str).collect();
+                Err(qdrant_errors::invalid_search_params(format!(
+                    "filter on '{field}' has no supported operator (got {operators:?})"
+                )))
+            }
+            // A list of allowed values matches any of them
+            serde_json::Value::Array(values) => Self::build_any_of_condition(field, values),
+            serde_json::Value::Null => Err(qdrant_errors::invalid_search_params(format!(
+                "filter on '{field}' is null; use {{\"$is_null\": true}} to match null values"
+            ))),
+            scalar => Self::build_match_condition(field, scalar).ok_or_else(|| {
+                qdrant_errors::invalid_search_params(format!(
+                    "filter value on '{field}' is out of the supported number range"
+                ))
+            }),
+        }
+    }
+
+    /// Build Qdrant filter from search parameters with sophisticated filtering
+    ///
+    /// Entries that cannot be translated are left out; operations log a warning for each of
+    /// them and `validate_filter` turns them into an error.
+    fn build_filter(params: &SearchParams) -> Option<Filter> {
+        let must: Vec<_> = params
+            .filters
+            .iter()
+            .filter_map(|(field, value)| Self::build_filter_condition(field, value).ok())
+            .collect();
+
+        if must.is_empty() {
+            return None;
+        }
+
+        Some(Filter {
+            must,
+            ..Default::default()
+        })
+    }
+
+    /// Filter entries `build_filter` leaves out, with the reason, ordered by field
+    fn skipped_filters(params: &SearchParams) -> Vec<(String, TylError)> {
+        let mut skipped: Vec<(String, TylError)> = params
+            .filters
+            .iter()
+            .filter_map(|(field, value)| {
+                let error = Self::build_filter_condition(field, value).err()?;
+                Some((field.clone(), error))
+            })
+            .collect();
+        skipped.sort_by(|(a, _), (b, _)| a.cmp(b));
+        skipped
+    }
+
+    /// Check that every filter entry in `params` can be translated to a Qdrant condition
+    ///
+    /// Searches skip entries they cannot translate, which silently widens the results. Run
+    /// this first to fail loudly instead: the error lists each skipped field with the reason,
+    /// e.g. unknown operators, non-numeric range bounds or an empty `$in`.
+    pub fn validate_filter(params: &SearchParams) -> VectorResult<()> {
+        let problems: Vec<String> = Self::skipped_filters(params)
+            .into_iter()
+            .map(|(_, e)| e.to_string())
+            .collect();
+        if problems.is_empty() {
+            return Ok(());
+        }
+
+        Err(qdrant_errors::invalid_search_params(format!(
+            "{} filter entries cannot be applied: {}",
+            problems.len(),
+            problems.join("; ")
+        )))
+    }
+
+    /// Operators in filter objects the adapter does not recognize, as `field.$op`, sorted
+    ///
+    /// These are dropped even when the rest of the entry is applied, e.g. the `$gtee` typo in
+    /// `{"$gte": 1, "$gtee": 5}` leaves a range with only a lower bound.
+    fn unknown_filter_operators(params: &SearchParams) -> Vec<String> {
+        let mut unknown: Vec<String> = params
+            .filters
+            .iter()
+            .filter_map(|(field, value)| Some((field, value.as_object()?)))
+            .flat_map(|(field, obj)| {
+                obj.keys()
+                    .filter(|op| !FILTER_OPERATORS.contains(&op.as_str()))
+                    .map(move |op| format!("{field}.{op}"))
+            })
+            .collect();
+        unknown.sort();
+        unknown
+    }
+
+    /// Check `params` for `strict_filters` mode: no unknown operators and no skipped entries
+    fn check_strict_filter(params: &SearchParams) -> VectorResult<()> {
+        let unknown = Self::unknown_filter_operators(params);
+        if !unknown.is_empty() {
+            return Err(qdrant_errors::invalid_search_params(format!(
+                "unrecognized filter operators: {}",
+                unknown.join(", ")
+            )));
+        }
+        Self::validate_filter(params)
+    }
+
+    /// Evaluate the filters in `params` against one vector locally, without a server
+    ///
+    /// Mirrors how Qdrant applies the filter `build_filter` produces, e.g. to debug why a
+    /// known vector is missing from filtered results: entries that cannot be translated are
+    /// skipped (see `validate_filter`), float match values are truncated to integers, and a
+    /// match or range against an array payload is satisfied by any of its elements.
+    /// `$has_id` is checked against the vector's ID.
+    pub fn filter_matches(params: &SearchParams, vector: &Vector) -> bool {
+        use qdrant_client::qdrant::condition::ConditionOneOf;
+
+        params.filters.iter().all(|(field, expected)| {
+            match Self::build_filter_condition(field, expected) {
+                Err(_) => true,
+                Ok(qdrant_client::qdrant::Condition {
+                    condition_one_of: Some(ConditionOneOf::HasId(condition)),
+                }) => condition
+                    .has_id
+                    .contains(&Self::filter_point_id(&vector.id)),
+                Ok(_) => Self::filter_value_matches(vector.metadata.get(field), expected),
+            }
+        })
+    }
+
+    /// Whether a stored payload value satisfies one translatable filter entry
+    fn filter_value_matches(
+        stored: Option<&serde_json::Value>,
+        expected: &serde_json::Value,
+    ) -> bool {
+        use serde_json::Value;
+
+        match expected {
+            Value::Object(obj)
+                if obj.contains_key("$gte")
+                    || obj.contains_key("$lte")
+                    || obj.contains_key("$gt")
+                    || obj.contains_key("$lt") =>
+            {
+                Self::payload_elements(stored).any(|value| {
+                    let Some(n) = value.as_f64() else {
+                        return false;
+                    };
+                    obj.iter().all(|(op, bound)| {
+                        let bound = bound.as_f64().unwrap_or(f64::NAN);
+                        match op.as_str() {
+                            "$gte" => n >= bound,
+                            "$lte" => n <= bound,
+                            "$gt" => n > bound,
+                            "$lt" => n < bound,
+                            _ => true,
+                        }
+                    })
+                })
+            }
+            Value::Object(obj) if obj.contains_key("$in") => match obj.get("$in") {
+                Some(Value::Array(values)) => Self::any_value_matches(stored, values),
+                _ => false,
+            },
+            Value::Object(obj) if obj.contains_key("$is_null") => {
+                let is_null = obj
+                    .get("$is_null")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                matches!(stored, Some(Value::Null)) == is_null
+            }
+            Value::Object(obj) if obj.contains_key("$exists") => {
+                let exists = obj.get("$exists").and_then(|v| v.as_bool()).unwrap_or(true);
+                let present = match stored {
+                    None | Some(Value::Null) => false,
+                    Some(Value::Array(values)) => !values.is_empty(),
+                    Some(_) => true,
+                };
+                present == exists
+            }
+            Value::Object(obj) if obj.contains_key("$is_empty") => {
+                let is_empty = obj
+                    .get("$is_empty")
+                    .and_then(|v| v.as_bool())
+                    .unwrap_or(true);
+                let empty = match stored {
+                    None | Some(Value::Null) => true,
+                    Some(Value::Array(values)) => values.is_empty(),
+                    Some(_) => false,
+                };
+                empty == is_empty
+            }
+            Value::Object(obj) if obj.contains_key("$text") => {
+                let query = obj
+                    .get("$text")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default();
+                Self::payload_elements(stored).any(|value| {
+                    value
+                        .as_str()
+                        .is_some_and(|text| Self::text_contains_words(text, query))
+                })
+            }
+            Value::Array(values) => Self::any_value_matches(stored, values),
+            scalar => Self::any_value_matches(stored, std::slice::from_ref(scalar)),
+        }
+    }
+
+    /// Whether `text` contains every word of `query`, approximating a default text index
+    ///
+    /// Words are runs of alphanumeric characters compared case-insensitively.
+    fn text_contains_words(text: &str, query: &str) -> bool {
+        let words = |s: &str| -> Vec<String> {
+            s.split(|c: char| !c.is_alphanumeric())
+                .filter(|word| !word.is_empty())
+                .map(str::to_lowercase)
+                .collect()
+        };
+        let text_words = words(text);
+        words(query).iter().all(|word| text_words.contains(word))
+    }
+
+    /// Whether any element of the stored value exactly matches any of `values`
+    fn any_value_matches(stored: Option<&serde_json::Value>, values: &[serde_json::Value]) -> bool {
+        use serde_json::Value;
+
+        Self::payload_elements(stored).any(|value| {
+            values.iter().any(|expected| match expected {
+                Value::String(s) => value.as_str() == Some(s.as_str()),
+                Value::Number(n) => {
+                    let n = n.as_i64().or_else(|| n.as_f64().map(|f| f as i64));
+                    n.is_some() && value.as_i64() == n
+                }
+                Value::Bool(b) => value.as_bool() == Some(*b),
+                _ => false,
+            })
+        })
+    }
+
+    /// A stored payload value, or each element when it is an array
+    fn payload_elements(
+        stored: Option<&serde_json::Value>,
+    ) -> Box<dyn Iterator<Item = &serde_json::Value> + '_> {
+        match stored {
+            Some(serde_json::Value::Array(values)) => Box::new(values.iter()),
+            Some(value) => Box::new(std::iter::once(value)),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
+    /// Build range filter for numeric fields
+    pub fn build_range_filter(field: &str, min: Option<f64>, max: Option<f64>) -> Option<Filter> {
+        use qdrant_client::qdrant::{Condition, FieldCondition, Filter, Range};
+
+        if min.is_none() && max.is_none() {
+            return None;
+        }
+
+        let range = Range {
+            lt: max,
+            gt: min,
+            gte: None,
+            lte: None,
+        };
+
+        let condition = Condition {
+            condition_one_of: Some(qdrant_client::qdrant::condition::ConditionOneOf::Field(
+                FieldCondition {
+                    key: field.to_string(),
+                    r#match: None,
+                    range: Some(range),
+                    geo_bounding_box: None,
+                    geo_radius: None,
+                    geo_polygon: None,
+                    values_count: None,
+                    is_empty: None,
+                    is_null: None,
+                    datetime_range: None,
+                },
+            )),
+        };
+
+        Some(Filter {
+            should: Vec::new(),
+            must: vec![condition],
+            must_not: Vec::new(),
+            min_should: None,
+        })
+    }
+
+    /// Build complex filter combining multiple conditions with logical operators
+    pub fn build_complex_filter(
+        must_conditions: Vec<(String, serde_json::Value)>,
+        should_conditions: Vec<(String, serde_json::Value)>,
+        must_not_conditions: Vec<(String, serde_json::Value)>,
+    ) -> Option<Filter> {
+        use qdrant_client::qdrant::{Condition, FieldCondition, Filter, Match};
+
+        let build_condition_list = |conditions: &[(String, serde_json::Value)]| -> Vec<Condition> {
+            conditions
+                .iter()
+                .filter_map(|(field, value)| {
+                    let match_value = match value {
+                        serde_json::Value::String(s) => Some(
+                            qdrant_client::qdrant::r#match::MatchValue::Keyword(s.clone()),
+                        ),
+                        serde_json::Value::Number(n) if n.is_i64() => Some(
+                            qdrant_client::qdrant::r#match::MatchValue::Integer(n.as_i64()?),
+                        ),
+                        serde_json::Value::Bool(b) => {
+                            Some(qdrant_client::qdrant::r#match::MatchValue::Boolean(*b))
+                        }
+                        _ => None,
+                    }?;
+
+                    Some(Condition {
+                        condition_one_of: Some(
+                            qdrant_client::qdrant::condition::ConditionOneOf::Field(
+                                FieldCondition {
+                                    key: field.clone(),
+                                    r#match: Some(Match {
+                                        match_value: Some(match_value),
+                                    }),
+                                    range: None,
+                                    geo_bounding_box: None,
+                                    geo_radius: None,
+                                    geo_polygon: None,
+                                    values_count: None,
+                                    is_empty: None,
+                                    is_null: None,
+                                    datetime_range: None,
+                                },
+                            ),
+                        ),
+                    })
+                })
+                .collect()
+        };
+
+        let must = build_condition_list(&must_conditions);
+        let should = build_condition_list(&should_conditions);
+        let must_not = build_condition_list(&must_not_conditions);
+
+        if must.is_empty() && should.is_empty() && must_not.is_empty() {
+            return None;
+        }
+
+        Some(Filter {
+            must,
+            should,
+            must_not,
+            min_should: None, // TODO: Determine correct MinShould type
+        })
+    }
+}
+
+#[async_trait]
+impl VectorStore for QdrantAdapter {
+    /// Store a single vector in Qdrant
+    async fn store_vector(&self, collection: &str, vector: Vector) -> TylResult<()> {
+        let context = format!(
+            "Storing vector '{}' in collection '{collection}'",
+            vector.id
+        );
+        self.store_vector_in_context(collection, vector, None, &context)
+            .await
+    }
+
+    /// Store multiple vectors in batch
+    async fn store_vectors_batch(
+        &self,
+        collection: &str,
+        vectors: Vec<Vector>,
+    ) -> TylResult<Vec<TylResult<()>>> {
+        let context = format!(
+            "Storing {} vectors in collection '{collection}'",
+            vectors.len()
+        );
+
+        self.with_telemetry("qdrant_store_vectors_batch", &context, async {
+            Self::check_batch_size(vectors.len(), self.config.max_batch_size)?;
+
+            // Vectors with invalid IDs or payloads are reported individually and left out of
+            // the upsert
+            let mut results: Vec<TylResult<()>> = Vec::with_capacity(vectors.len());
+            let mut points: Vec<PointStruct> = Vec::with_capacity(vectors.len());
+            for mut vector in vectors {
+                let point = self
+                    .prepare_vector(collection, &mut vector)
+                    .and_then(|()| Self::vector_to_point_struct(vector, &self.config));
+                match point {
+                    Ok(point) => {
+                        points.push(point);
+                        results.push(Ok(()));
+                    }
+                    Err(e) => results.push(Err(e)),
+                }
+            }
+
+            if points.is_empty() {
+                return Ok(results);
+            }
+
+            let point_count = points.len();
+            let response = Self::map_qdrant_error(
+                self.client
+                    .upsert_points(qdrant_client::qdrant::UpsertPoints {
+                        collection_name: self.server_collection(collection),
+                        points,
+                        ..Default::default()
+                    })
+                    .await,
+                "Failed to store vectors",
+            )?;
+
+            // Qdrant returns success for all or fails for all
+            if response.result.is_none() {
+                for result in results.iter_mut().filter(|r| r.is_ok()) {
+                    *result = Err(vector_errors::storage_failed("Batch storage failed"));
+                }
+            } else {
+                self.log_debug(|| Self::batch_store_summary(collection, point_count));
+            }
+
+            Ok(results)
+        })
+        .await
+    }
+
+    /// Retrieve a vector by ID
+    ///
+    /// Returns `Ok(None)` for a missing vector and a `collection_not_found` error for a
+    /// missing collection.
+    async fn get_vector(&self, collection: &str, id: &str) -> TylResult<Option<Vector>> {
+        self.get_vector_from_shard(collection, id, None).await
+    }
+
+    /// Search for similar vectors
+    ///
+    /// Returns an empty list when nothing matches and a `collection_not_found` error for a
+    /// missing collection.
+    async fn search_similar(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        self.search_similar_with_options(
+            collection,
+            query_vector,
+            params,
+            QdrantSearchOptions::default(),
+        )
+        .await
+    }
+
+    /// Delete a vector by ID
+    async fn delete_vector(&self, collection: &str, id: &str) -> TylResult<()> {
+        let context = format!("Deleting vector '{id}' from collection '{collection}'");
+
+        self.with_telemetry("qdrant_delete_vector", &context, async {
+            let points_selector = PointsSelector {
+                points_selector_one_of: Some(
+                    qdrant_client::qdrant::points_selector::PointsSelectorOneOf::Points(
+                        PointsIdsList {
+                            ids: vec![Self::resolve_point_id(id, &self.config)?],
+                        },
+                    ),
+                ),
+            };
+
+            let delete_points = DeletePoints {
+                collection_name: self.server_collection(collection),
+                points: Some(points_selector),
+                wait: None,
+                shard_key_selector: None,
+                ordering: None,
+            };
+
+            let response = Self::map_qdrant_error(
+                self.client.delete_points(delete_points).await,
+                "Failed to delete vector",
+            )?;
+
+            if response.result.is_none() {
+                return Err(vector_errors::storage_failed("No response from Qdrant"));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Delete multiple vectors by IDs
+    async fn delete_vectors_batch(&self, collection: &str, ids: Vec<String>) -> TylResult<()> {
+        let context = format!(
+            "Deleting {} vectors from collection '{collection}'",
+            ids.len()
+        );
+
+        self.with_telemetry("qdrant_delete_vectors_batch", &context, async {
+            let point_ids = ids
+                .iter()
+                .map(|id| Self::resolve_point_id(id, &self.config))
+                .collect::<VectorResult<Vec<PointId>>>()?;
+
+            let points_selector = PointsSelector {
+                points_selector_one_of: Some(
+                    qdrant_client::qdrant::points_selector::PointsSelectorOneOf::Points(
+                        PointsIdsList { ids: point_ids },
+                    ),
+                ),
+            };
+
+            let delete_points = DeletePoints {
+                collection_name: self.server_collection(collection),
+                points: Some(points_selector),
+                wait: None,
+                shard_key_selector: None,
+                ordering: None,
+            };
+
+            let response = Self::map_qdrant_error(
+                self.client.delete_points(delete_points).await,
+                "Failed to delete vectors",
+            )?;
+
+            if response.result.is_none() {
+                return Err(vector_errors::storage_failed("No response from Qdrant"));
+            }
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl VectorCollectionManager for QdrantAdapter {
+    /// Create a new collection in Qdrant
+    async fn create_collection(&self, config: CollectionConfig) -> TylResult<()> {
+        self.create_collection_with_options(config, QdrantCollectionOptions::default())
+            .await
+    }
+
+    /// Delete a collection
+    async fn delete_collection(&self, collection_name: &str) -> TylResult<()> {
+        let context = format!("Deleting collection '{collection_name}'");
+
+        self.with_telemetry("qdrant_delete_collection", &context, async {
+            let response = Self::map_qdrant_error(
+                self.client
+                    .delete_collection(self.server_collection(collection_name))
+                    .await,
+                "Failed to delete collection",
+            )?;
+
+            if !response.result {
+                return Err(vector_errors::collection_not_found(collection_name));
+            }
+            // A recreated collection starts without payload indexes and may use another metric
+            self.checked_filter_fields
+                .write()
+                .unwrap()
+                .remove(collection_name);
+            self.collection_metrics
+                .write()
+                .unwrap()
+                .remove(collection_name);
+            Ok(())
+        })
+        .await
+    }
+
+    /// List all collections
+    async fn list_collections(&self) -> TylResult<Vec<CollectionConfig>> {
+        self.with_telemetry("qdrant_list_collections", "Listing collections", async {
+            let response = Self::map_qdrant_error(
+                self.client.list_collections().await,
+                "Failed to list collections",
+            )?;
+
+            let mut configs = Vec::new();
+            for collection_description in response.collections {
+                // Collections outside the configured prefix belong to other applications
+                let Some(name) = self
+                    .config
+                    .logical_collection_name(&collection_description.name)
+                else {
+                    continue;
+                };
+                if let Ok(Some(config)) = self.get_collection_info(name).await {
+                    configs.push(config);
+                }
+            }
+            Ok(configs)
+        })
+        .await
+    }
+
+    /// Get collection information
+    async fn get_collection_info(
+        &self,
+        collection_name: &str,
+    ) -> TylResult<Option<CollectionConfig>> {
+        let context = format!("Getting info of collection '{collection_name}'");
+
+        self.with_telemetry("qdrant_get_collection_info", &context, async {
+            let info = Self::map_point_error(
+                self.client
+                    .collection_info(self.server_collection(collection_name))
+                    .await,
+                collection_name,
+                "Failed to get collection info",
+            );
+            let details = match info {
+                Ok(info) => info
+                    .result
+                    .and_then(|info| Self::collection_details_from_info(collection_name, &info)),
+                Err(e) => {
+                    self.refresh_collection_metric(collection_name, None);
+                    return Err(e);
+                }
+            };
+            self.refresh_collection_metric(collection_name, details.as_ref());
+
+            Ok(details.map(|details| details.config))
+        })
+        .await
+    }
+
+    /// Get collection statistics
+    async fn get_collection_stats(
+        &self,
+        collection_name: &str,
+    ) -> TylResult<HashMap<String, serde_json::Value>> {
+        let context = format!("Getting stats of collection '{collection_name}'");
+
+        self.with_telemetry("qdrant_get_collection_stats", &context, async {
+            let info = Self::map_point_error(
+                self.client
+                    .collection_info(self.server_collection(collection_name))
+                    .await,
+                collection_name,
+                "Collection info failed",
+            )?;
+
+            let mut stats = HashMap::new();
+            if let Some(result) = info.result {
+                stats.insert("status".to_string(), serde_json::json!(result.status));
+                if let Some(vectors_count) = result.vectors_count {
+                    stats.insert(
+                        "vectors_count".to_string(),
+                        serde_json::json!(vectors_count),
+                    );
+                }
+                stats.insert(
+                    "segments_count".to_string(),
+                    serde_json::json!(result.segments_count),
+                );
+            }
+            Ok(stats)
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl VectorStoreHealth for QdrantAdapter {
+    /// Check if Qdrant is healthy
+    async fn is_healthy(&self) -> TylResult<bool> {
+        match self.client.health_check().await {
+            Ok(_) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Get detailed health information
+    async fn health_check(&self) -> TylResult<HashMap<String, serde_json::Value>> {
+        let mut health_data = HashMap::new();
+
+        let start_time = Instant::now();
+        let health = self.client.health_check().await;
+        let latency_ms = start_time.elapsed().as_secs_f64() * 1000.0;
+        health_data.insert("latency_ms".to_string(), serde_json::json!(latency_ms));
+
+        match health {
+            Ok(_) => {
+                health_data.insert("status".to_string(), serde_json::json!("healthy"));
+                health_data.insert("qdrant_url".to_string(), serde_json::json!(self.config.url));
+                Ok(health_data)
+            }
+            Err(e) => {
+                health_data.insert("status".to_string(), serde_json::json!("unhealthy"));
+                health_data.insert("error".to_string(), serde_json::json!(e.to_string()));
+                Ok(health_data)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl VectorDatabase for QdrantAdapter {
+    type Config = QdrantConfig;
+
+    /// Connect to Qdrant database
+    async fn connect(config: Self::Config) -> VectorResult<Self>
+    where
+        Self: Sized,
+    {
+        Self::new(config).await
+    }
+
+    /// Get connection information
+    fn connection_info(&self) -> String {
+        format!("Qdrant at {}", self.config.url)
+    }
+
+    /// Close the connection
+    async fn close(&mut self) -> VectorResult<()> {
+        // Qdrant client doesn't require explicit closing
+        Ok(())
+    }
+
+    /// Check feature support
+    fn supports_feature(&self, feature: &str) -> bool {
+        matches!(
+            feature,
+            "collections" | "health_check" | "batch_operations" | "filtering" | "payload"
+        )
+    }
+}
+
+/// Qdrant-specific operations that go beyond the TYL vector port
+impl QdrantAdapter {
+    /// Register payload fields merged into every vector stored in `collection`
+    ///
+    /// Useful for tags every point must carry, such as a tenant ID. Keys set explicitly on a
+    /// vector take precedence over the defaults. Passing an empty map removes the defaults.
+    pub fn set_collection_defaults(
+        &self,
+        collection: &str,
+        defaults: HashMap<String, serde_json::Value>,
+    ) {
+        let mut collection_defaults = self.collection_defaults.write().unwrap();
+        if defaults.is_empty() {
+            collection_defaults.remove(collection);
+        } else {
+            collection_defaults.insert(collection.to_string(), defaults);
+        }
+    }
+
+    /// Register the payload types every vector stored in `collection` must have
+    ///
+    /// Each key in `schema` is required and must hold a value of the given JSON type; other
+    /// keys are unrestricted. Passing an empty map removes the schema.
+    pub fn set_payload_schema(&self, collection: &str, schema: HashMap<String, ExpectedJsonType>) {
+        let mut payload_schemas = self.payload_schemas.write().unwrap();
+        if schema.is_empty() {
+            payload_schemas.remove(collection);
+        } else {
+            payload_schemas.insert(collection.to_string(), schema);
+        }
+    }
+
+    /// Apply the collection's default metadata, then check it against the payload schema
+    fn prepare_vector(&self, collection: &str, vector: &mut Vector) -> VectorResult<()> {
+        if let Some(defaults) = self.collection_defaults.read().unwrap().get(collection) {
+            Self::merge_default_metadata(vector, defaults);
+        }
+        Self::validate_reserved_keys(vector, &self.config)?;
+        match self.payload_schemas.read().unwrap().get(collection) {
+            Some(schema) => Self::validate_payload(vector, schema),
+            None => Ok(()),
+        }
+    }
+
+    /// Reject metadata keys in the configured reserved payload namespace
+    ///
+    /// `content_type_key()` and `embedding_model_key()` are allowed: the adapter returns them
+    /// as metadata, so vectors read back and stored again carry them. The adapter cannot tell
+    /// those round trips from callers setting the keys directly, so their values are only as
+    /// trustworthy as the callers writing to the collection.
+    fn validate_reserved_keys(vector: &Vector, config: &QdrantConfig) -> VectorResult<()> {
+        let content_type_key = config.content_type_key();
+        let embedding_model_key = config.embedding_model_key();
+        let mut reserved: Vec<&String> = vector
+            .metadata
+            .keys()
+            .filter(|key| {
+                **key != content_type_key
+                    && **key != embedding_model_key
+                    && config.is_reserved_key(key)
+            })
+            .collect();
+        reserved.sort();
+        match reserved.first() {
+            Some(key) => Err(qdrant_errors::reserved_payload_key(
+                &vector.id,
+                key,
+                &config.reserved_payload_prefix,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Check that a vector's metadata has every schema key with the expected JSON type
+    fn validate_payload(
+        vector: &Vector,
+        schema: &HashMap<String, ExpectedJsonType>,
+    ) -> VectorResult<()> {
+        for (key, expected) in schema {
+            match vector.metadata.get(key) {
+                None => {
+                    return Err(qdrant_errors::payload_schema_violation(
+                        &vector.id,
+                        key,
+                        "required key is missing",
+                    ))
+                }
+                Some(value) if !expected.matches(value) => {
+                    return Err(qdrant_errors::payload_schema_violation(
+                        &vector.id,
+                        key,
+                        format!("expected {}, got {value}", expected.as_str()),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Add default metadata entries without overwriting keys already set on the vector
+    fn merge_default_metadata(vector: &mut Vector, defaults: &HashMap<String, serde_json::Value>) {
+        for (key, value) in defaults {
+            vector
+                .metadata
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+    }
+
+    /// Access the underlying Qdrant client for features the adapter does not wrap yet
+    ///
+    /// Requests sent through this client bypass the adapter entirely: no logging, tracing,
+    /// circuit breaker, ID mapping or error conversion. Prefer adapter methods where they
+    /// exist.
+    #[cfg(feature = "raw-client")]
+    pub fn raw_client(&self) -> &Qdrant {
+        &self.client
+    }
+
+    /// Whether a collection name matches `pattern`
+    ///
+    /// Patterns containing `*` (any run of characters) or `?` (one character) are globs
+    /// matched against the whole name; any other pattern matches names starting with it.
+    fn collection_name_matches(name: &str, pattern: &str) -> bool {
+        if !pattern.contains(['*', '?']) {
+            return name.starts_with(pattern);
+        }
+
+        let name: Vec<char> = name.chars().collect();
+        let pattern: Vec<char> = pattern.chars().collect();
+        let (mut n, mut p) = (0, 0);
+        // Position of the last `*` and the name position it was tried against
+        let mut backtrack = None;
+        while n < name.len() {
+            match pattern.get(p) {
+                Some('*') => {
+                    backtrack = Some((p, n));
+                    p += 1;
+                }
+                Some(&c) if c == '?' || c == name[n] => {
+                    n += 1;
+                    p += 1;
+                }
+                _ => match backtrack {
+                    Some((star, matched)) => {
+                        backtrack = Some((star, matched + 1));
+                        p = star + 1;
+                        n = matched + 1;
+                    }
+                    None => return false,
+                },
+            }
+        }
+        pattern[p..].iter().all(|&c| c == '*')
+    }
+
+    /// List the collections whose names match a glob or prefix `pattern`
+    ///
+    /// Filtering happens client-side after listing every collection; see
+    /// `collection_name_matches` for the pattern syntax. Names are matched without the
+    /// configured `collection_prefix`.
+    pub async fn list_collections_matching(
+        &self,
+        pattern: &str,
+    ) -> TylResult<Vec<CollectionConfig>> {
+        let mut collections = self.list_collections().await?;
+        collections.retain(|config| Self::collection_name_matches(&config.name, pattern));
+        Ok(collections)
+    }
+
+    /// Create a collection unless one with the same name already exists
+    ///
+    /// Returns `true` if the collection was created and `false` if it already existed, which
+    /// makes idempotent setup code free of error string matching. The existing collection's
+    /// configuration is not compared with `config`.
+    pub async fn create_collection_if_not_exists(
+        &self,
+        config: CollectionConfig,
+    ) -> TylResult<bool> {
+        let context = format!("Creating collection '{}' if it does not exist", config.name);
+
+        self.with_telemetry("qdrant_create_collection_if_not_exists", &context, async {
+            let exists = Self::map_qdrant_error(
+                self.client
+                    .collection_exists(self.server_collection(&config.name))
+                    .await,
+                "Collection exists check failed",
+            )?;
+            if exists {
+                return Ok(false);
+            }
+
+            // Another client may create it between the check and the create call
+            match self.create_collection(config).await {
+                Ok(()) => Ok(true),
+                Err(e) if e.to_string().contains("already exists") => Ok(false),
+                Err(e) => Err(e),
+            }
+        })
+        .await
+    }
+
+    /// Create `config.name` unless it exists, reporting whether an existing one matches
+    ///
+    /// Meant for declarative setup: an existing collection is compared by dimension and
+    /// metric like `assert_collection_schema` and never modified, so a mismatch is returned
+    /// for the caller to resolve instead of failing ambiguously.
+    pub async fn ensure_collection(&self, config: CollectionConfig) -> TylResult<EnsureOutcome> {
+        if self.create_collection_if_not_exists(config.clone()).await? {
+            return Ok(EnsureOutcome::Created);
+        }
+        let actual = self
+            .get_collection_info(&config.name)
+            .await?
+            .ok_or_else(|| vector_errors::collection_not_found(&config.name))?;
+        Ok(Self::existing_collection_outcome(&config, &actual))
+    }
+
+    /// Compare an existing collection against the config `ensure_collection` was given
+    fn existing_collection_outcome(
+        expected: &CollectionConfig,
+        actual: &CollectionConfig,
+    ) -> EnsureOutcome {
+        let differences = Self::collection_schema_differences(expected, actual);
+        if differences.is_empty() {
+            EnsureOutcome::AlreadyMatches
+        } else {
+            EnsureOutcome::ConfigMismatch { differences }
+        }
+    }
+
+    /// Store any number of vectors in `max_batch_size` batches, up to `concurrency` in parallel
+    ///
+    /// Unlike `store_vectors_batch`, large ingestions are not rejected for their size. Returns
+    /// one result per vector in input order; a batch whose upsert fails reports that error
+    /// for each of its vectors while the other batches are still written.
+    pub async fn store_vectors_concurrent(
+        &self,
+        collection: &str,
+        vectors: Vec<Vector>,
+        concurrency: usize,
+    ) -> TylResult<Vec<TylResult<()>>> {
+        Self::store_batches_concurrently(
+            self,
+            collection,
+            vectors,
+            self.config.max_batch_size,
+            concurrency,
+        )
+        .await
+    }
+
+    /// Re-submit the vectors whose entry in a `store_vectors_batch` result failed
+    ///
+    /// `vectors` and `results` must be the batch and its results, index for index. Returns
+    /// `results` with each failed entry replaced by the outcome of its retry; successful
+    /// entries are not written again.
+    pub async fn retry_failed(
+        &self,
+        collection: &str,
+        vectors: &[Vector],
+        results: &[TylResult<()>],
+    ) -> TylResult<Vec<TylResult<()>>> {
+        Self::retry_failed_vectors(
+            self,
+            collection,
+            vectors,
+            results,
+            self.config.max_batch_size,
+        )
+        .await
+    }
+
+    /// Store embeddings that have no IDs yet under freshly generated UUIDs
+    ///
+    /// Embeddings are written in `max_batch_size` batches. Returns each embedding's assigned
+    /// ID with its storage result, in input order, so stored embeddings can be found even when
+    /// others fail. Fails as a whole only for errors that concern the entire call.
+    pub async fn store_embeddings_batch(
+        &self,
+        collection: &str,
+        embeddings: Vec<(Vec<f32>, HashMap<String, serde_json::Value>)>,
+    ) -> TylResult<Vec<(String, TylResult<()>)>> {
+        Self::store_with_generated_ids(self, collection, embeddings, self.config.max_batch_size)
+            .await
+    }
+
+    /// Estimate the RAM a collection's vectors take, for capacity planning
+    ///
+    /// This is an estimate, not a measurement: it applies Qdrant's sizing rule of thumb (raw
+    /// vector size plus 50% for the index and bookkeeping) to the point count, dimension and
+    /// datatype, and adds the quantized copy when quantization is configured. Vectors stored
+    /// `on_disk` are memory-mapped, so only their index overhead and quantized copy count.
+    /// Payloads are not counted, and multivector points count as a single vector.
+    pub async fn estimate_memory_bytes(&self, collection: &str) -> TylResult<u64> {
+        let context = format!("Estimating memory usage of collection '{collection}'");
+
+        self.with_telemetry("qdrant_estimate_memory", &context, async {
+            let info = Self::map_point_error(
+                self.client
+                    .collection_info(self.server_collection(collection))
+                    .await,
+                collection,
+                "Collection info failed",
+            )?
+            .result
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+            Ok(Self::estimate_collection_memory(&info))
+        })
+        .await
+    }
+
+    /// Get a collection's configuration including the parameters of every named vector
+    ///
+    /// `get_collection_info` can only describe one vector; use this for collections with
+    /// named vectors. Returns `None` when Qdrant reports no collection parameters.
+    pub async fn get_collection_details(
+        &self,
+        collection: &str,
+    ) -> TylResult<Option<DetailedCollectionConfig>> {
+        let context = format!("Getting details of collection '{collection}'");
+        self.with_telemetry("qdrant_get_collection_details", &context, async {
+            let info = Self::map_point_error(
+                self.client
+                    .collection_info(self.server_collection(collection))
+                    .await,
+                collection,
+                "Collection info failed",
+            );
+            let details = match info {
+                Ok(info) => info
+                    .result
+                    .and_then(|info| Self::collection_details_from_info(collection, &info)),
+                Err(e) => {
+                    self.refresh_collection_metric(collection, None);
+                    return Err(e);
+                }
+            };
+            self.refresh_collection_metric(collection, details.as_ref());
+            Ok(details)
+        })
+        .await
+    }
+
+    /// Convert Qdrant's collection info, handling both single and named vector configs
+    ///
+    /// A collection without a vectors config keeps the historical 768-dimension cosine
+    /// fallback.
+    fn collection_details_from_info(
+        collection: &str,
+        info: &qdrant_client::qdrant::CollectionInfo,
+    ) -> Option<DetailedCollectionConfig> {
+        use qdrant_client::qdrant::vectors_config::Config;
+
+        let params = info.config.as_ref()?.params.as_ref()?;
+        let named_config = |params: &VectorParams| NamedVectorConfig {
+            dimension: params.size as usize,
+            distance_metric: Self::distance_metric_from_qdrant(params.distance),
+        };
+        let vectors: std::collections::BTreeMap<String, NamedVectorConfig> = match params
+            .vectors_config
+            .as_ref()
+            .and_then(|vectors_config| vectors_config.config.as_ref())
+        {
+            Some(Config::Params(params)) => [(String::new(), named_config(params))].into(),
+            Some(Config::ParamsMap(params_map)) => params_map
+                .map
+                .iter()
+                .map(|(name, params)| (name.clone(), named_config(params)))
+                .collect(),
+            None => Default::default(),
+        };
+
+        let (dimension, distance_metric) = vectors
+            .values()
+            .next()
+            .map_or((768, DistanceMetric::Cosine), |vector| {
+                (vector.dimension, vector.distance_metric.clone())
+            });
+        Some(DetailedCollectionConfig {
+            config: CollectionConfig::new_unchecked(
+                collection.to_string(),
+                dimension,
+                distance_metric,
+            ),
+            vectors,
+        })
+    }
+
+    /// Convert a Qdrant distance, treating unknown values as cosine
+    fn distance_metric_from_qdrant(distance: i32) -> DistanceMetric {
+        match Distance::try_from(distance) {
+            Ok(Distance::Euclid) => DistanceMetric::Euclidean,
+            Ok(Distance::Dot) => DistanceMetric::DotProduct,
+            Ok(Distance::Manhattan) => DistanceMetric::Manhattan,
+            _ => DistanceMetric::Cosine,
+        }
+    }
+
+    /// Memory estimate for a collection described by Qdrant's collection info
+    ///
+    /// Named vectors are estimated separately and summed.
+    fn estimate_collection_memory(info: &qdrant_client::qdrant::CollectionInfo) -> u64 {
+        use qdrant_client::qdrant::{vectors_config::Config, Datatype};
+
+        let points = info.points_count.unwrap_or(0);
+        let Some(config) = &info.config else {
+            return 0;
+        };
+        let vector_params: Vec<&VectorParams> = match config
+            .params
+            .as_ref()
+            .and_then(|params| params.vectors_config.as_ref())
+            .and_then(|vectors_config| vectors_config.config.as_ref())
+        {
+            Some(Config::Params(params)) => vec![params],
+            Some(Config::ParamsMap(params_map)) => params_map.map.values().collect(),
+            None => Vec::new(),
+        };
+
+        vector_params
+            .into_iter()
+            .map(|params| {
+                let datatype = match params.datatype.map(Datatype::try_from) {
+                    Some(Ok(Datatype::Float16)) => VectorDatatype::Float16,
+                    Some(Ok(Datatype::Uint8)) => VectorDatatype::Uint8,
+                    _ => VectorDatatype::Float32,
+                };
+                // Per-vector quantization overrides the collection-wide setting
+                let quantization = params
+                    .quantization_config
+                    .as_ref()
+                    .or(config.quantization_config.as_ref());
+                Self::estimate_vector_memory(
+                    points,
+                    params.size,
+                    datatype,
+                    params.on_disk.unwrap_or(false),
+                    Self::quantized_vector_bytes(quantization, params.size),
+                )
+            })
+            .sum()
+    }
+
+    /// Bytes per point of the quantized vector copy Qdrant keeps, or 0 without quantization
+    fn quantized_vector_bytes(
+        quantization: Option<&qdrant_client::qdrant::QuantizationConfig>,
+        dimension: u64,
+    ) -> u64 {
+        use qdrant_client::qdrant::{quantization_config::Quantization, CompressionRatio};
+
+        let quantization = quantization.and_then(|config| config.quantization.as_ref());
+        if let Some(Quantization::Scalar(_)) = quantization {
+            dimension
+        } else if let Some(Quantization::Binary(_)) = quantization {
+            dimension.div_ceil(8)
+        } else if let Some(Quantization::Product(product)) = quantization {
+            let ratio = match CompressionRatio::try_from(product.compression) {
+                Ok(CompressionRatio::X8) => 8,
+                Ok(CompressionRatio::X16) => 16,
+                Ok(CompressionRatio::X32) => 32,
+                Ok(CompressionRatio::X64) => 64,
+                _ => 4,
+            };
+            (dimension * 4).div_ceil(ratio)
+        } else {
+            0
+        }
+    }
+
+    /// Qdrant's sizing rule of thumb: raw vectors plus 50% overhead, plus any quantized copy
+    ///
+    /// Raw vectors stored `on_disk` live in the page cache rather than RAM and are left out;
+    /// the overhead, mostly the HNSW index, still counts.
+    fn estimate_vector_memory(
+        points: u64,
+        dimension: u64,
+        datatype: VectorDatatype,
+        on_disk: bool,
+        quantized_bytes_per_vector: u64,
+    ) -> u64 {
+        let component_bytes = match datatype {
+            VectorDatatype::Float32 => 4,
+            VectorDatatype::Float16 => 2,
+            VectorDatatype::Uint8 => 1,
+        };
+        let raw = points * dimension * component_bytes;
+        let in_ram = if on_disk { 0 } else { raw };
+        in_ram + raw / 2 + points * quantized_bytes_per_vector
+    }
+
+    /// Every collection with its stats, e.g. for an admin dashboard
+    ///
+    /// Stats are fetched concurrently, at most [`COLLECTION_STATS_CONCURRENCY`] at a time,
+    /// and returned in `list_collections` order. A collection deleted while stats are being
+    /// fetched fails the whole call.
+    pub async fn collections_with_stats(
+        &self,
+    ) -> TylResult<Vec<(CollectionConfig, HashMap<String, serde_json::Value>)>> {
+        use futures::{StreamExt, TryStreamExt};
+
+        let configs = self.list_collections().await?;
+        futures::stream::iter(configs)
+            .map(|config| async move {
+                let stats = self.get_collection_stats(&config.name).await?;
+                Ok::<_, TylError>((config, stats))
+            })
+            .buffered(COLLECTION_STATS_CONCURRENCY)
+            .try_collect()
+            .await
+    }
+
+    /// Delete `config.name` if it exists and create it fresh from `config`
+    ///
+    /// Meant for idempotent setup in tests, CI and full-reset migrations. Not atomic: readers
+    /// see the collection missing between the two steps, and a failed create leaves it
+    /// deleted.
+    pub async fn recreate_collection(&self, config: CollectionConfig) -> TylResult<()> {
+        let context = format!("Recreating collection '{}'", config.name);
+
+        self.with_telemetry("qdrant_recreate_collection", &context, async {
+            let exists = Self::map_qdrant_error(
+                self.client
+                    .collection_exists(self.server_collection(&config.name))
+                    .await,
+                "Collection exists check failed",
+            )?;
+            if exists {
+                self.delete_collection(&config.name).await?;
+            }
+            self.create_collection(config).await
+        })
+        .await
+    }
+
+    /// Split pre-formed points into upsert requests of at most `max_batch_size` points
+    #[cfg(feature = "raw-client")]
+    fn build_raw_upsert_requests(
+        collection: &str,
+        points: Vec<PointStruct>,
+        max_batch_size: usize,
+    ) -> Vec<UpsertPoints> {
+        let mut requests = Vec::with_capacity(points.len().div_ceil(max_batch_size.max(1)));
+        let mut points = points.into_iter().peekable();
+        while points.peek().is_some() {
+            requests.push(UpsertPoints {
+                collection_name: collection.to_string(),
+                points: points.by_ref().take(max_batch_size.max(1)).collect(),
+                wait: Some(true),
+                ..Default::default()
+            });
+        }
+        requests
+    }
+
+    /// Upsert pre-formed Qdrant points, skipping the `Vector` conversion
+    ///
+    /// Points are sent as-is in batches of `max_batch_size`, so callers are responsible for
+    /// valid point IDs and payloads; the `hash_string_ids` mapping is not applied. Batches go
+    /// through the adapter's telemetry and circuit breaker but, like every adapter operation,
+    /// are not retried: a failed batch is returned as an error after earlier batches landed.
+    #[cfg(feature = "raw-client")]
+    pub async fn upsert_points_raw(
+        &self,
+        collection: &str,
+        points: Vec<PointStruct>,
+    ) -> TylResult<()> {
+        let context = format!(
+            "Upserting {} raw points into collection '{collection}'",
+            points.len()
+        );
+
+        self.with_telemetry("qdrant_upsert_points_raw", &context, async {
+            let requests = Self::build_raw_upsert_requests(
+                &self.server_collection(collection),
+                points,
+                self.config.max_batch_size,
+            );
+            for request in requests {
+                let response = Self::map_qdrant_error(
+                    self.client.upsert_points(request).await,
+                    "Raw upsert failed",
+                )?;
+                if response.result.is_none() {
+                    return Err(vector_errors::storage_failed("Raw upsert failed"));
+                }
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Convert a vector datatype to its Qdrant representation
+    fn datatype_to_qdrant(datatype: VectorDatatype) -> qdrant_client::qdrant::Datatype {
+        match datatype {
+            VectorDatatype::Float32 => qdrant_client::qdrant::Datatype::Float32,
+            VectorDatatype::Float16 => qdrant_client::qdrant::Datatype::Float16,
+            VectorDatatype::Uint8 => qdrant_client::qdrant::Datatype::Uint8,
+        }
+    }
+
+    /// Convert a multivector comparator to its Qdrant representation
+    fn comparator_to_qdrant(
+        comparator: MultiVectorComparator,
+    ) -> qdrant_client::qdrant::MultiVectorComparator {
+        match comparator {
+            MultiVectorComparator::MaxSim => qdrant_client::qdrant::MultiVectorComparator::MaxSim,
+        }
+    }
+
+    /// Build the collection creation request, preferring per-collection overrides
+    #[allow(deprecated)] // `on_disk_payload` is deprecated in newer protos but still honoured
+    fn build_create_collection_request(
+        config: &CollectionConfig,
+        options: &QdrantCollectionOptions,
+        defaults: &QdrantConfig,
+    ) -> CreateCollection {
+        let vectors_config = VectorsConfig {
+            config: Some(qdrant_client::qdrant::vectors_config::Config::Params(
+                VectorParams {
+                    size: config.dimension as u64,
+                    distance: Self::distance_metric_to_qdrant(&config.distance_metric) as i32,
+                    hnsw_config: None,
+                    quantization_config: None,
+                    on_disk: None,
+                    datatype: Some(Self::datatype_to_qdrant(options.datatype) as i32),
+                    multivector_config: options.multivector.map(|comparator| {
+                        qdrant_client::qdrant::MultiVectorConfig {
+                            comparator: Self::comparator_to_qdrant(comparator) as i32,
+                        }
+                    }),
+                },
+            )),
+        };
+
+        CreateCollection {
+            collection_name: defaults.qualified_collection_name(&config.name),
+            vectors_config: Some(vectors_config),
+            shard_number: Some(
+                options
+                    .shard_number
+                    .unwrap_or(defaults.default_shard_number),
+            ),
+            replication_factor: Some(
+                options
+                    .replication_factor
+                    .unwrap_or(defaults.default_replication_factor),
+            ),
+            wal_config: options
+                .wal
+                .as_ref()
+                .map(|wal| qdrant_client::qdrant::WalConfigDiff {
+                    wal_capacity_mb: Some(wal.capacity_mb),
+                    wal_segments_ahead: Some(wal.segments_ahead),
+                    ..Default::default()
+                }),
+            on_disk_payload: options.on_disk_payload.or(defaults.default_on_disk_payload),
+            hnsw_config: options
+                .hnsw
+                .as_ref()
+                .map(|hnsw| qdrant_client::qdrant::HnswConfigDiff {
+                    m: hnsw.m,
+                    ef_construct: hnsw.ef_construct,
+                    full_scan_threshold: hnsw.full_scan_threshold,
+                    on_disk: hnsw.on_disk,
+                    ..Default::default()
+                }),
+            ..Default::default()
+        }
+    }
+
+    /// Create a collection with Qdrant-specific settings such as shard and replica counts
+    pub async fn create_collection_with_options(
+        &self,
+        config: CollectionConfig,
+        options: QdrantCollectionOptions,
+    ) -> TylResult<()> {
+        let context = format!("Creating collection '{}'", config.name);
+
+        self.with_telemetry("qdrant_create_collection", &context, async {
+            config.validate()?;
+            options.validate()?;
+
+            let create_collection =
+                Self::build_create_collection_request(&config, &options, &self.config);
+
+            let response = self
+                .client
+                .create_collection(create_collection)
+                .await
+                .map_err(|e| {
+                    circuit_breaker::observe_error(&e);
+                    if e.to_string().contains("already exists") {
+                        vector_errors::storage_failed(format!(
+                            "Collection '{}' already exists",
+                            config.name
+                        ))
+                    } else {
+                        vector_errors::storage_failed(format!("Failed to create collection: {e}"))
+                    }
+                })?;
+
+            if !response.result {
+                return Err(vector_errors::storage_failed("Failed to create collection"));
+            }
+            // The name may have belonged to a collection another client dropped
+            self.checked_filter_fields
+                .write()
+                .unwrap()
+                .remove(&config.name);
+            self.collection_metrics
+                .write()
+                .unwrap()
+                .insert(config.name.clone(), config.distance_metric.clone());
+            Ok(())
+        })
+        .await
+    }
+
+    /// Build the collection update that makes Qdrant index all segments
+    fn build_optimize_request(collection: &str) -> UpdateCollection {
+        Self::build_optimizer_update_request(collection, Some(FORCE_INDEXING_THRESHOLD_KB), None)
+    }
+
+    /// Build the collection update used by `update_optimizer_config`
+    ///
+    /// Thresholds left as `None` are omitted from the diff and keep their current value.
+    fn build_optimizer_update_request(
+        collection: &str,
+        indexing_threshold: Option<u64>,
+        default_segment_number: Option<u64>,
+    ) -> UpdateCollection {
+        UpdateCollection {
+            collection_name: collection.to_string(),
+            optimizers_config: Some(OptimizersConfigDiff {
+                indexing_threshold,
+                default_segment_number,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Force HNSW index building for a collection, e.g. after a large bulk load
+    ///
+    /// Lowers the collection's `indexing_threshold` to [`FORCE_INDEXING_THRESHOLD_KB`] so the
+    /// optimizer indexes every segment instead of waiting for them to grow. The new threshold
+    /// is kept on the collection.
+    pub async fn optimize_collection(&self, collection: &str) -> TylResult<()> {
+        let context = format!("Optimizing collection '{collection}'");
+
+        self.with_telemetry("qdrant_optimize_collection", &context, async {
+            let response = self
+                .client
+                .update_collection(Self::build_optimize_request(
+                    &self.server_collection(collection),
+                ))
+                .await
+                .map_err(|e| {
+                    circuit_breaker::observe_error(&e);
+                    qdrant_errors::index_optimization_failed(collection, e.to_string())
+                })?;
+
+            if !response.result {
+                return Err(qdrant_errors::index_optimization_failed(
+                    collection,
+                    "Qdrant rejected the optimizer update",
+                ));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Update a collection's optimizer thresholds after creation
+    ///
+    /// Typically used around bulk loads: raise `indexing_threshold` while ingesting, then lower
+    /// it so Qdrant builds the HNSW index. `None` leaves a setting unchanged.
+    pub async fn update_optimizer_config(
+        &self,
+        collection: &str,
+        indexing_threshold: Option<u64>,
+        default_segment_number: Option<u64>,
+    ) -> TylResult<()> {
+        let context = format!("Updating optimizer config of collection '{collection}'");
+
+        self.with_telemetry("qdrant_update_optimizer_config", &context, async {
+            let request = Self::build_optimizer_update_request(
+                &self.server_collection(collection),
+                indexing_threshold,
+                default_segment_number,
+            );
+            let response = Self::map_qdrant_error(
+                self.client.update_collection(request).await,
+                "Failed to update optimizer config",
+            )?;
+
+            if !response.result {
+                return Err(vector_errors::storage_failed(format!(
+                    "Qdrant rejected the optimizer update for collection '{collection}'"
+                )));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Build the scroll request used by `find_by_filter`
+    fn build_scroll_request(
+        collection: &str,
+        filter: &SearchParams,
+        limit: usize,
+    ) -> qdrant_client::qdrant::ScrollPoints {
+        qdrant_client::qdrant::ScrollPoints {
+            collection_name: collection.to_string(),
+            filter: Self::build_filter(filter),
+            limit: Some(limit.min(u32::MAX as usize) as u32),
+            with_payload: Some(Self::payload_selector(true)),
+            with_vectors: Some(Self::vectors_selector(true)),
+            ..Default::default()
+        }
+    }
+
+    /// Build the scroll request used by `scroll_ordered`, ordering by a payload key
+    fn build_ordered_scroll_request(
+        collection: &str,
+        order_key: &str,
+        ascending: bool,
+        limit: usize,
+    ) -> qdrant_client::qdrant::ScrollPoints {
+        let direction = if ascending {
+            qdrant_client::qdrant::Direction::Asc
+        } else {
+            qdrant_client::qdrant::Direction::Desc
+        };
+        qdrant_client::qdrant::ScrollPoints {
+            collection_name: collection.to_string(),
+            limit: Some(limit.min(u32::MAX as usize) as u32),
+            with_payload: Some(Self::payload_selector(true)),
+            with_vectors: Some(Self::vectors_selector(true)),
+            order_by: Some(qdrant_client::qdrant::OrderBy {
+                key: order_key.to_string(),
+                direction: Some(direction as i32),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Reject batches larger than `max_batch_size` with `batch_size_exceeded`
+    fn check_batch_size(size: usize, max_batch_size: usize) -> VectorResult<()> {
+        if size > max_batch_size {
+            return Err(qdrant_errors::batch_size_exceeded(size, max_batch_size));
+        }
+        Ok(())
+    }
+
+    /// Filter fields in `params` missing from `indexed`, ordered by name
+    fn unindexed_filter_fields<'a>(
+        params: &'a SearchParams,
+        indexed: &std::collections::HashSet<String>,
+    ) -> Vec<&'a String> {
+        let mut fields: Vec<_> = params
+            .filters
+            .keys()
+            .filter(|field| !indexed.contains(*field))
+            .collect();
+        fields.sort();
+        fields
+    }
+
+    /// Build the request creating a payload index on `field`
+    ///
+    /// The request does not wait for the index to be built, so it does not hold up the
+    /// operation that triggered it.
+    fn build_field_index_request(
+        collection: &str,
+        field: &str,
+        index_type: PayloadIndexType,
+    ) -> qdrant_client::qdrant::CreateFieldIndexCollection {
+        use qdrant_client::qdrant::FieldType;
+
+        let field_type = match index_type {
+            PayloadIndexType::Keyword => FieldType::Keyword,
+            PayloadIndexType::Integer => FieldType::Integer,
+            PayloadIndexType::Float => FieldType::Float,
+            PayloadIndexType::Bool => FieldType::Bool,
+            PayloadIndexType::Text => FieldType::Text,
+            PayloadIndexType::Datetime => FieldType::Datetime,
+        };
+        qdrant_client::qdrant::CreateFieldIndexCollection {
+            collection_name: collection.to_string(),
+            wait: Some(false),
+            field_name: field.to_string(),
+            field_type: Some(field_type as i32),
+            ..Default::default()
+        }
+    }
+
+    /// Build the grouped search request used by `search_groups`
+    fn build_search_groups_request(
+        collection: &str,
+        query_vector: Vec<f32>,
+        group_by: &str,
+        groups_limit: usize,
+        per_group: usize,
+        params: &SearchParams,
+    ) -> qdrant_client::qdrant::SearchPointGroups {
+        qdrant_client::qdrant::SearchPointGroups {
+            collection_name: collection.to_string(),
+            vector: query_vector,
+            filter: Self::build_filter(params),
+            limit: groups_limit as u32,
+            group_by: group_by.to_string(),
+            group_size: per_group as u32,
+            score_threshold: params.threshold,
+            with_payload: Some(Self::payload_selector(true)),
+            with_vectors: Some(Self::vectors_selector(params.include_vectors)),
+            ..Default::default()
+        }
+    }
+
+    /// Search for similar vectors, returning at most `per_group` hits per value of `group_by`
+    ///
+    /// Up to `groups_limit` groups are returned; `params.limit` is ignored while its filters,
+    /// threshold and vector inclusion still apply. Points without the `group_by` field are
+    /// skipped by Qdrant.
+    pub async fn search_groups(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        group_by: &str,
+        groups_limit: usize,
+        per_group: usize,
+        params: SearchParams,
+    ) -> TylResult<Vec<VectorGroup>> {
+        let context = format!(
+            "Searching groups by '{group_by}' in collection '{collection}' with limit {groups_limit}"
+        );
+
+        self.with_telemetry("qdrant_search_groups", &context, async {
+            self.check_skipped_filters(collection, &params)?;
+            self.check_filter_indexes(collection, &params).await;
+            let request = Self::build_search_groups_request(
+                &self.server_collection(collection),
+                query_vector,
+                group_by,
+                groups_limit,
+                per_group,
+                &params,
+            );
+
+            let response = Self::map_point_error(
+                self.client.search_groups(request).await,
+                collection,
+                "Grouped search failed",
+            )?;
+
+            let mut groups = Vec::new();
+            for group in response.result.map(|r| r.groups).unwrap_or_default() {
+                let mut hits = Vec::with_capacity(group.hits.len());
+                for point in group.hits {
+                    let score = point.score;
+                    hits.push(VectorSearchResult::new(
+                        Self::point_to_vector(point, &self.config)?,
+                        score,
+                    ));
+                }
+                groups.push(VectorGroup {
+                    group_id: Self::group_id_to_json(group.id),
+                    hits,
+                });
+            }
+
+            Ok(groups)
+        })
+        .await
+    }
+
+    /// Wrap raw vector data as a discover example - helper for request construction
+    fn vector_example(data: Vec<f32>) -> qdrant_client::qdrant::VectorExample {
+        qdrant_client::qdrant::VectorExample {
+            example: Some(qdrant_client::qdrant::vector_example::Example::Vector(
+                qdrant_client::qdrant::Vector::from(data),
+            )),
+        }
+    }
+
+    /// Validate discover inputs, which need a target or at least one context pair
+    fn validate_discover_inputs(
+        target: &Option<Vec<f32>>,
+        context: &[(Vec<f32>, Vec<f32>)],
+    ) -> VectorResult<()> {
+        if target.is_none() && context.is_empty() {
+            return Err(qdrant_errors::invalid_search_params(
+                "Discover requires a target vector or at least one context pair",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Build the discover request used by `discover`
+    fn build_discover_request(
+        collection: &str,
+        target: Option<Vec<f32>>,
+        context: Vec<(Vec<f32>, Vec<f32>)>,
+        params: &SearchParams,
+    ) -> qdrant_client::qdrant::DiscoverPoints {
+        qdrant_client::qdrant::DiscoverPoints {
+            collection_name: collection.to_string(),
+            target: target.map(|target| qdrant_client::qdrant::TargetVector {
+                target: Some(qdrant_client::qdrant::target_vector::Target::Single(
+                    Self::vector_example(target),
+                )),
+            }),
+            context: context
+                .into_iter()
+                .map(
+                    |(positive, negative)| qdrant_client::qdrant::ContextExamplePair {
+                        positive: Some(Self::vector_example(positive)),
+                        negative: Some(Self::vector_example(negative)),
+                    },
+                )
+                .collect(),
+            filter: Self::build_filter(params),
+            limit: params.limit as u64,
+            with_payload: Some(Self::payload_selector(true)),
+            with_vectors: Some(Self::vectors_selector(params.include_vectors)),
+            ..Default::default()
+        }
+    }
+
+    /// Discover points using a target and `(positive, negative)` context pairs
+    ///
+    /// With only context pairs Qdrant runs a pure context search; with a target it ranks
+    /// points close to the target within the zones favoured by the context. Filters, limit
+    /// and vector inclusion come from `params`; the threshold is ignored because discover
+    /// scores are not similarity scores.
+    pub async fn discover(
+        &self,
+        collection: &str,
+        target: Option<Vec<f32>>,
+        context: Vec<(Vec<f32>, Vec<f32>)>,
+        params: SearchParams,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        Self::validate_discover_inputs(&target, &context)?;
+        let context_description = format!(
+            "Discovering in collection '{collection}' with {} context pairs and limit {}",
+            context.len(),
+            params.limit
+        );
+
+        self.with_telemetry("qdrant_discover", &context_description, async {
+            self.check_skipped_filters(collection, &params)?;
+            let request = Self::build_discover_request(
+                &self.server_collection(collection),
+                target,
+                context,
+                &params,
+            );
+
+            let response = Self::map_point_error(
+                self.client.discover(request).await,
+                collection,
+                "Discover failed",
+            )?;
+
+            let mut results = Vec::with_capacity(response.result.len());
+            for point in response.result {
+                let score = point.score;
+                results.push(VectorSearchResult::new(
+                    Self::point_to_vector(point, &self.config)?,
+                    score,
+                ));
+            }
+
+            Ok(results)
+        })
+        .await
+    }
+
+    /// Fetch vectors matching the filters in `filter` without similarity ranking
+    ///
+    /// Only `filter.filters` is used; limit and threshold are ignored in favour of `limit`.
+    /// Results come back in point ID order and carry no score.
+    pub async fn find_by_filter(
+        &self,
+        collection: &str,
+        filter: SearchParams,
+        limit: usize,
+    ) -> TylResult<Vec<Vector>> {
+        let context = format!("Finding vectors by filter in collection '{collection}'");
+
+        self.with_telemetry("qdrant_find_by_filter", &context, async {
+            self.check_skipped_filters(collection, &filter)?;
+            self.check_filter_indexes(collection, &filter).await;
+
+            let response = Self::map_point_error(
+                self.client
+                    .scroll(Self::build_scroll_request(
+                        &self.server_collection(collection),
+                        &filter,
+                        limit,
+                    ))
+                    .await,
+                collection,
+                "Scroll failed",
+            )?;
+
+            response
+                .result
+                .into_iter()
+                .map(|point| Self::retrieved_point_to_vector(point, &self.config))
+                .collect()
+        })
+        .await
+    }
+
+    /// Fetch up to `limit` vectors ordered by the numeric payload field `order_key`
+    ///
+    /// Qdrant derives each point's order value from the payload at query time, so storing a
+    /// vector with e.g. a `created_ts` metadata entry is all that is needed to order by it.
+    /// The field must have a range-capable payload index (integer, float or datetime), and
+    /// points without a value under `order_key` are left out.
+    pub async fn scroll_ordered(
+        &self,
+        collection: &str,
+        order_key: &str,
+        ascending: bool,
+        limit: usize,
+    ) -> TylResult<Vec<Vector>> {
+        let context = format!("Scrolling collection '{collection}' ordered by '{order_key}'");
+
+        self.with_telemetry("qdrant_scroll_ordered", &context, async {
+            let response = Self::map_point_error(
+                self.client
+                    .scroll(Self::build_ordered_scroll_request(
+                        &self.server_collection(collection),
+                        order_key,
+                        ascending,
+                        limit,
+                    ))
+                    .await,
+                collection,
+                "Ordered scroll failed",
+            )?;
+
+            response
+                .result
+                .into_iter()
+                .map(|point| Self::retrieved_point_to_vector(point, &self.config))
+                .collect()
+        })
+        .await
+    }
+
+    /// Apply `auto_index_filter_fields` to the filter fields of `params`
+    ///
+    /// Each field is checked once per collection; later operations skip the collection info
+    /// request unless they filter on a field not seen before. Failures are logged as warnings
+    /// and leave the fields unchecked, so the next operation tries again.
+    async fn check_filter_indexes(&self, collection: &str, params: &SearchParams) {
+        let policy = self.config.auto_index_filter_fields;
+        if policy == UnindexedFilterPolicy::Ignore {
+            return;
+        }
+
+        let checked = self
+            .checked_filter_fields
+            .read()
+            .unwrap()
+            .get(collection)
+            .cloned()
+            .unwrap_or_default();
+        if params.filters.keys().all(|field| checked.contains(field)) {
+            return;
+        }
+
+        let server_collection = self.server_collection(collection);
+        let info = match self.client.collection_info(server_collection.clone()).await {
+            Ok(info) => info,
+            Err(e) => {
+                self.log(QdrantLogLevel::Warn, || {
+                    format!("Could not check payload indexes of collection '{collection}': {e}")
+                });
+                return;
+            }
+        };
+        let mut indexed: std::collections::HashSet<String> = info
+            .result
+            .map(|result| result.payload_schema.into_keys().collect())
+            .unwrap_or_default();
+        indexed.extend(checked);
+
+        let mut newly_checked: Vec<String> = params
+            .filters
+            .keys()
+            .filter(|field| indexed.contains(*field))
+            .cloned()
+            .collect();
+        for field in Self::unindexed_filter_fields(params, &indexed) {
+            let index_type = self.config.filter_index_types.get(field).copied();
+            match index_type.filter(|_| policy == UnindexedFilterPolicy::Create) {
+                Some(index_type) => {
+                    let request =
+                        Self::build_field_index_request(&server_collection, field, index_type);
+                    if let Err(e) = self.client.create_field_index(request).await {
+                        self.log(QdrantLogLevel::Warn, || {
+                            format!(
+                                "Could not create payload index on '{field}' in collection \
+                                 '{collection}': {e}"
+                            )
+                        });
+                        continue;
+                    }
+                    self.log(QdrantLogLevel::Info, || {
+                        format!("Requested payload index on '{field}' in collection '{collection}'")
+                    });
+                }
+                None => self.log(QdrantLogLevel::Warn, || {
+                    format!(
+                        "Filter field '{field}' in collection '{collection}' has no payload \
+                         index; filtering will scan points"
+                    )
+                }),
+            }
+            newly_checked.push(field.clone());
+        }
+
+        self.checked_filter_fields
+            .write()
+            .unwrap()
+            .entry(collection.to_string())
+            .or_default()
+            .extend(newly_checked);
+    }
+
+    /// Reject (with `strict_filters`) or log a warning for filter parts the request will not apply
+    fn check_skipped_filters(&self, collection: &str, params: &SearchParams) -> VectorResult<()> {
+        if self.config.strict_filters {
+            return Self::check_strict_filter(params);
+        }
+        for operator in Self::unknown_filter_operators(params) {
+            self.log(QdrantLogLevel::Warn, || {
+                format!(
+                    "Ignoring unrecognized filter operator {operator} in collection '{collection}'"
+                )
+            });
+        }
+        for (field, reason) in Self::skipped_filters(params) {
+            self.log(QdrantLogLevel::Warn, || {
+                format!(
+                    "Skipping filter on '{field}' in collection '{collection}', so results are \
+                     not restricted by it: {reason}"
+                )
+            });
+        }
+        Ok(())
+    }
+
+    /// Fetch one page of points in ID order - helper for whole-collection operations
+    ///
+    /// Pages hold up to `max_batch_size` points so each page can be written back as one batch.
+    async fn scroll_page(
+        &self,
+        collection: &str,
+        offset: Option<PointId>,
+        with_vectors: bool,
+    ) -> TylResult<(Vec<Vector>, Option<PointId>)> {
+        let request = qdrant_client::qdrant::ScrollPoints {
+            collection_name: self.server_collection(collection),
+            offset,
+            limit: Some(self.config.max_batch_size.min(u32::MAX as usize) as u32),
+            with_payload: Some(Self::payload_selector(true)),
+            with_vectors: Some(Self::vectors_selector(with_vectors)),
+            ..Default::default()
+        };
+
+        let response = Self::map_point_error(
+            self.client.scroll(request).await,
+            collection,
+            "Scroll failed",
+        )?;
+        let vectors = response
+            .result
+            .into_iter()
+            .map(|point| Self::retrieved_point_to_vector(point, &self.config))
+            .collect::<VectorResult<Vec<Vector>>>()?;
+
+        Ok((vectors, response.next_page_offset))
+    }
+
+    /// Regenerate embeddings for vectors carrying text under `text_payload_key`
+    ///
+    /// All texts go to the service in one `generate_embeddings_batch` call. Vectors without a
+    /// string at that key are left out of the returned batch.
+    async fn reembed_vectors(
+        vectors: Vec<Vector>,
+        service: &impl EmbeddingService,
+        text_payload_key: &str,
+        content_type: &ContentType,
+        config: &QdrantConfig,
+    ) -> TylResult<Vec<Vector>> {
+        let (mut reembedded, texts): (Vec<Vector>, Vec<String>) = vectors
+            .into_iter()
+            .filter_map(|vector| {
+                let text = vector.metadata.get(text_payload_key)?.as_str()?.to_string();
+                Some((vector, text))
+            })
+            .unzip();
+        if texts.is_empty() {
+            return Ok(reembedded);
+        }
+
+        let embeddings = service
+            .generate_embeddings_batch(texts, content_type.clone())
+            .await?;
+        if embeddings.len() != reembedded.len() {
+            return Err(TylError::validation(
+                "embeddings",
+                format!(
+                    "Embedding service returned {} embeddings for {} texts",
+                    embeddings.len(),
+                    reembedded.len()
+                ),
+            ));
+        }
+
+        for (vector, embedding) in reembedded.iter_mut().zip(embeddings) {
+            apply_embedding(vector, embedding, config);
+        }
+        Ok(reembedded)
+    }
+
+    /// Embed `text` with `service` into a vector tagged with its model and content type
+    async fn embed_text(
+        id: &str,
+        text: &str,
+        content_type: ContentType,
+        service: &impl EmbeddingService,
+        metadata: HashMap<String, serde_json::Value>,
+        config: &QdrantConfig,
+    ) -> TylResult<Vector> {
+        let embedding = service.generate_embedding(text, content_type).await?;
+        Ok(vector_from_embedding(id, embedding, metadata, config))
+    }
+
+    /// Embed `text` with `service` and store it, recording the model and content type in the
+    /// payload
+    ///
+    /// Like [`vector_from_embedding`], the model goes under `embedding_model_key()` and the
+    /// content type under `content_type_key()` of the adapter's config, so multimodal
+    /// collections can be searched per modality with `SearchParamsExt::with_content_type_key`.
+    pub async fn store_text(
+        &self,
+        collection: &str,
+        id: &str,
+        text: &str,
+        content_type: ContentType,
+        service: &impl EmbeddingService,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> TylResult<()> {
+        let vector =
+            Self::embed_text(id, text, content_type, service, metadata, &self.config).await?;
+        self.store_vector(collection, vector).await
+    }
+
+    /// Length of the embeddings `service` produces, measured with a probe embedding
+    async fn probe_embedding_dimension(service: &impl EmbeddingService) -> TylResult<usize> {
+        let probe = service
+            .generate_embedding("dimension probe", ContentType::Text)
+            .await?;
+        Ok(probe.vector.len())
+    }
+
+    /// Check that `service` produces embeddings of length `expected` using a probe embedding
+    async fn check_embedding_dimension(
+        expected: usize,
+        service: &impl EmbeddingService,
+    ) -> TylResult<()> {
+        let actual = Self::probe_embedding_dimension(service).await?;
+        if actual != expected {
+            return Err(qdrant_errors::vector_dimension_mismatch(expected, actual));
+        }
+        Ok(())
+    }
+
+    /// Create a collection sized for the embeddings `service` produces
+    ///
+    /// Generates one probe embedding and uses its length as the dimension, so the collection
+    /// cannot be set up with a dimension that differs from the model's.
+    pub async fn create_collection_for_service(
+        &self,
+        name: &str,
+        service: &impl EmbeddingService,
+        metric: DistanceMetric,
+    ) -> TylResult<()> {
+        let dimension = Self::probe_embedding_dimension(service).await?;
+        self.create_collection(CollectionConfig::new(name, dimension, metric)?)
+            .await
+    }
+
+    /// Check that `service` produces embeddings matching the dimension of `collection`
+    ///
+    /// Generates one probe embedding, so a model wired to the wrong collection fails at setup
+    /// with `vector_dimension_mismatch` instead of on the first store.
+    pub async fn validate_embedding_compatibility(
+        &self,
+        collection: &str,
+        service: &impl EmbeddingService,
+    ) -> TylResult<()> {
+        let config = self
+            .get_collection_info(collection)
+            .await?
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+        Self::check_embedding_dimension(config.dimension, service).await
+    }
+
+    /// Check that the live collection `name` matches `expected`, e.g. in CI against a cluster
+    ///
+    /// Compares dimension and distance metric and fails with a validation error listing every
+    /// mismatch. `expected.name` is not compared, so one expected config can check several
+    /// collections. Use `assert_collection_schema_with_options` to compare HNSW settings too.
+    pub async fn assert_collection_schema(
+        &self,
+        name: &str,
+        expected: &CollectionConfig,
+    ) -> TylResult<()> {
+        self.assert_collection_schema_with_options(
+            name,
+            expected,
+            &QdrantCollectionOptions::default(),
+        )
+        .await
+    }
+
+    /// Like `assert_collection_schema`, also comparing the HNSW settings set in `options`
+    ///
+    /// Only the HNSW fields set in `options.hnsw` are compared with the live index config;
+    /// the other options describe how a collection is created and are not checked.
+    pub async fn assert_collection_schema_with_options(
+        &self,
+        name: &str,
+        expected: &CollectionConfig,
+        options: &QdrantCollectionOptions,
+    ) -> TylResult<()> {
+        let context = format!("Checking schema of collection '{name}'");
+        self.with_telemetry("qdrant_assert_collection_schema", &context, async {
+            let info = Self::map_point_error(
+                self.client
+                    .collection_info(self.server_collection(name))
+                    .await,
+                name,
+                "Collection info failed",
+            )?
+            .result
+            .ok_or_else(|| vector_errors::collection_not_found(name))?;
+            let actual = Self::collection_details_from_info(name, &info)
+                .ok_or_else(|| vector_errors::collection_not_found(name))?
+                .config;
+            let actual_hnsw = info
+                .config
+                .as_ref()
+                .and_then(|config| config.hnsw_config.as_ref())
+                .map(Self::hnsw_from_qdrant)
+                .unwrap_or_default();
+            Self::check_collection_schema(name, expected, &actual, options, &actual_hnsw)
+        })
+        .await
+    }
+
+    /// HNSW settings as reported by Qdrant
+    fn hnsw_from_qdrant(hnsw: &qdrant_client::qdrant::HnswConfigDiff) -> QdrantHnswConfig {
+        QdrantHnswConfig {
+            m: hnsw.m,
+            ef_construct: hnsw.ef_construct,
+            full_scan_threshold: hnsw.full_scan_threshold,
+            on_disk: hnsw.on_disk,
+        }
+    }
+
+    /// Fail with a validation error describing each difference from the expected schema
+    fn check_collection_schema(
+        name: &str,
+        expected: &CollectionConfig,
+        actual: &CollectionConfig,
+        options: &QdrantCollectionOptions,
+        actual_hnsw: &QdrantHnswConfig,
+    ) -> VectorResult<()> {
+        let mut mismatches = Self::collection_schema_differences(expected, actual);
+        if let Some(expected_hnsw) = &options.hnsw {
+            mismatches.extend(Self::hnsw_differences(expected_hnsw, actual_hnsw));
+        }
+        if mismatches.is_empty() {
+            return Ok(());
+        }
+        Err(qdrant_errors::collection_schema_mismatch(name, &mismatches))
+    }
+
+    /// Each HNSW setting set in `expected` that differs in `actual`
+    fn hnsw_differences(expected: &QdrantHnswConfig, actual: &QdrantHnswConfig) -> Vec<String> {
+        fn describe<T: std::fmt::Display>(value: Option<T>) -> String {
+            value.map_or_else(|| "unset".to_string(), |value| value.to_string())
+        }
+        fn compare<T: PartialEq + std::fmt::Display + Copy>(
+            mismatches: &mut Vec<String>,
+            field: &str,
+            expected: Option<T>,
+            actual: Option<T>,
+        ) {
+            if expected.is_some() && actual != expected {
+                mismatches.push(format!(
+                    "hnsw {field} is {}, expected {}",
+                    describe(actual),
+                    describe(expected)
+                ));
+            }
+        }
+
+        let mut mismatches = Vec::new();
+        compare(&mut mismatches, "m", expected.m, actual.m);
+        compare(
+            &mut mismatches,
+            "ef_construct",
+            expected.ef_construct,
+            actual.ef_construct,
+        );
+        compare(
+            &mut mismatches,
+            "full_scan_threshold",
+            expected.full_scan_threshold,
+            actual.full_scan_threshold,
+        );
+        compare(&mut mismatches, "on_disk", expected.on_disk, actual.on_disk);
+        mismatches
+    }
+
+    /// Each difference in dimension and metric between two collection configs
+    fn collection_schema_differences(
+        expected: &CollectionConfig,
+        actual: &CollectionConfig,
+    ) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        if actual.dimension != expected.dimension {
+            mismatches.push(format!(
+                "dimension is {}, expected {}",
+                actual.dimension, expected.dimension
+            ));
+        }
+        let expected_metric = Self::distance_metric_to_qdrant(&expected.distance_metric);
+        let actual_metric = Self::distance_metric_to_qdrant(&actual.distance_metric);
+        if actual_metric != expected_metric {
+            mismatches.push(format!(
+                "distance metric is {}, expected {}",
+                actual_metric.as_str_name(),
+                expected_metric.as_str_name()
+            ));
+        }
+        mismatches
+    }
+
+    /// Re-embed every vector in a collection with a new embedding service
+    ///
+    /// Scrolls the collection page by page, regenerates each page's embeddings from the text
+    /// stored under `text_payload_key` with one `generate_embeddings_batch` call and upserts
+    /// the page back as one batch, keeping IDs and payloads.
+    /// Points without text under that key are skipped. Returns the number re-embedded. The
+    /// service is checked with `validate_embedding_compatibility` before any point is changed.
+    pub async fn reembed_collection(
+        &self,
+        collection: &str,
+        new_service: &impl EmbeddingService,
+        text_payload_key: &str,
+        content_type: ContentType,
+    ) -> TylResult<usize> {
+        self.validate_embedding_compatibility(collection, new_service)
+            .await?;
+
+        let context =
+            format!("Re-embedding collection '{collection}' from payload key '{text_payload_key}'");
+
+        self.with_telemetry("qdrant_reembed_collection", &context, async {
+            let mut reembedded = 0;
+            let mut offset = None;
+            loop {
+                let (page, next_offset) = self.scroll_page(collection, offset, false).await?;
+                let batch = Self::reembed_vectors(
+                    page,
+                    new_service,
+                    text_payload_key,
+                    &content_type,
+                    &self.config,
+                )
+                .await?;
+
+                if !batch.is_empty() {
+                    reembedded += batch.len();
+                    for result in self.store_vectors_batch(collection, batch).await? {
+                        result?;
+                    }
+                }
+
+                match next_offset {
+                    Some(next) => offset = Some(next),
+                    None => break,
+                }
+            }
+            Ok(reembedded)
+        })
+        .await
+    }
+
+    /// Copy a collection's configuration and points under a new name
+    ///
+    /// Fails if `dest` already exists. `dest` gets the source's vector parameters (size,
+    /// metric, datatype, on-disk storage), shard and replica counts, on-disk payload, HNSW,
+    /// WAL, optimizer, quantization and strict mode settings. Not copied: payload indexes,
+    /// aliases, custom sharding (`dest` shards automatically) and the adapter's local
+    /// collection defaults and payload schemas. Points are scrolled from `source` and stored
+    /// into `dest` one page at a time; returns the number of points copied.
+    ///
+    /// Points are copied through their single unnamed vector, so collections with named,
+    /// sparse or multi vectors are rejected before `dest` is created.
+    pub async fn copy_collection(&self, source: &str, dest: &str) -> TylResult<usize> {
+        let context = format!("Copying collection '{source}' to '{dest}'");
+
+        self.with_telemetry("qdrant_copy_collection", &context, async {
+            let info = Self::map_point_error(
+                self.client
+                    .collection_info(self.server_collection(source))
+                    .await,
+                source,
+                "Collection info failed",
+            )?
+            .result
+            .ok_or_else(|| vector_errors::collection_not_found(source))?;
+            let source_config = info
+                .config
+                .as_ref()
+                .ok_or_else(|| vector_errors::collection_not_found(source))?;
+            Self::check_copyable_points(source, source_config)?;
+            let details = Self::collection_details_from_info(source, &info)
+                .ok_or_else(|| vector_errors::collection_not_found(source))?;
+            // Validates the destination name
+            let dest_config = CollectionConfig::new(
+                dest,
+                details.config.dimension,
+                details.config.distance_metric,
+            )?;
+
+            let exists = Self::map_qdrant_error(
+                self.client
+                    .collection_exists(self.server_collection(dest))
+                    .await,
+                "Collection exists check failed",
+            )?;
+            if exists {
+                return Err(vector_errors::storage_failed(format!(
+                    "Collection '{dest}' already exists"
+                )));
+            }
+
+            let response = Self::map_qdrant_error(
+                self.client
+                    .create_collection(Self::build_copy_collection_request(
+                        &self.server_collection(dest),
+                        source_config,
+                    ))
+                    .await,
+                "Failed to create collection",
+            )?;
+            if !response.result {
+                return Err(vector_errors::storage_failed("Failed to create collection"));
+            }
+            self.checked_filter_fields.write().unwrap().remove(dest);
+            self.collection_metrics
+                .write()
+                .unwrap()
+                .insert(dest.to_string(), dest_config.distance_metric);
+
+            let mut copied = 0;
+            let mut offset = None;
+            loop {
+                let (page, next_offset) = self.scroll_page(source, offset, true).await?;
+                if !page.is_empty() {
+                    copied += page.len();
+                    for result in self.store_vectors_batch(dest, page).await? {
+                        result?;
+                    }
+                }
+
+                match next_offset {
+                    Some(next) => offset = Some(next),
+                    None => break,
+                }
+            }
+            Ok(copied)
+        })
+        .await
+    }
+
+    /// Reject collections whose points do not fit through the single-vector `Vector` model
+    fn check_copyable_points(
+        collection: &str,
+        config: &qdrant_client::qdrant::CollectionConfig,
+    ) -> VectorResult<()> {
+        use qdrant_client::qdrant::vectors_config::Config;
+
+        let params = config.params.as_ref();
+        let reason =
+            match params.and_then(|params| params.vectors_config.as_ref()?.config.as_ref()) {
+                Some(Config::ParamsMap(_)) => Some("it has named vectors"),
+                Some(Config::Params(params)) if params.multivector_config.is_some() => {
+                    Some("it is a multivector collection")
+                }
+                _ => None,
+            }
+            .or_else(|| {
+                params
+                    .and_then(|params| params.sparse_vectors_config.as_ref())
+                    .filter(|sparse| !sparse.map.is_empty())
+                    .map(|_| "it has sparse vectors")
+            });
+        match reason {
+            Some(reason) => Err(qdrant_errors::collection_not_copyable(collection, reason)),
+            None => Ok(()),
+        }
+    }
+
+    /// Build the request creating `dest` with the settings of a source collection
+    ///
+    /// Everything is taken from `source` as reported by Qdrant, so the adapter's defaults do
+    /// not apply. Sharding is left automatic: points are copied without their shard keys.
+    #[allow(deprecated)] // `on_disk_payload` is deprecated in newer protos but still honoured
+    fn build_copy_collection_request(
+        dest: &str,
+        source: &qdrant_client::qdrant::CollectionConfig,
+    ) -> CreateCollection {
+        let params = source.params.clone().unwrap_or_default();
+        CreateCollection {
+            collection_name: dest.to_string(),
+            vectors_config: params.vectors_config,
+            sparse_vectors_config: params.sparse_vectors_config,
+            shard_number: Some(params.shard_number).filter(|&shards| shards > 0),
+            replication_factor: params.replication_factor,
+            write_consistency_factor: params.write_consistency_factor,
+            on_disk_payload: Some(params.on_disk_payload),
+            hnsw_config: source.hnsw_config.clone(),
+            wal_config: source.wal_config.clone(),
+            optimizers_config: source.optimizer_config.clone(),
+            quantization_config: source.quantization_config.clone(),
+            strict_mode_config: source.strict_mode_config.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Write one vector as a JSONL line of a collection export
+    async fn write_export_line(
+        writer: &mut (impl tokio::io::AsyncWrite + Unpin),
+        vector: Vector,
+    ) -> VectorResult<()> {
+        use tokio::io::AsyncWriteExt;
+
+        let record = ExportedVector {
+            id: vector.id,
+            embedding: vector.embedding,
+            metadata: vector.metadata,
+        };
+        let mut line = serde_json::to_vec(&record)
+            .map_err(|e| vector_errors::storage_failed(format!("Failed to write export: {e}")))?;
+        line.push(b'\n');
+        writer
+            .write_all(&line)
+            .await
+            .map_err(|e| vector_errors::storage_failed(format!("Failed to write export: {e}")))
+    }
+
+    /// Flush an export writer once every line is written
+    async fn flush_export(writer: &mut (impl tokio::io::AsyncWrite + Unpin)) -> VectorResult<()> {
+        use tokio::io::AsyncWriteExt;
+
+        writer
+            .flush()
+            .await
+            .map_err(|e| vector_errors::storage_failed(format!("Failed to write export: {e}")))
+    }
+
+    /// Store `vectors` in batches of `batch_size`, writing up to `concurrency` batches at once
+    ///
+    /// Returns one result per vector in input order; a batch that fails as a whole reports
+    /// its error for each of its vectors.
+    async fn store_batches_concurrently<S: VectorStore>(
+        store: &S,
+        collection: &str,
+        vectors: Vec<Vector>,
+        batch_size: usize,
+        concurrency: usize,
+    ) -> TylResult<Vec<TylResult<()>>> {
+        use futures::StreamExt;
+
+        if concurrency == 0 {
+            return Err(TylError::validation(
+                "concurrency",
+                "Concurrency must be greater than 0",
+            ));
+        }
+
+        let batch_size = batch_size.max(1);
+        let mut batches = Vec::with_capacity(vectors.len().div_ceil(batch_size));
+        let mut vectors = vectors.into_iter().peekable();
+        while vectors.peek().is_some() {
+            batches.push(vectors.by_ref().take(batch_size).collect::<Vec<_>>());
+        }
+
+        let batch_results: Vec<Vec<TylResult<()>>> = futures::stream::iter(batches)
+            .map(|batch| async move {
+                let len = batch.len();
+                match store.store_vectors_batch(collection, batch).await {
+                    Ok(results) => results,
+                    // One error per vector; `TylError` is not `Clone`
+                    Err(e) => (0..len)
+                        .map(|_| Err(vector_errors::storage_failed(e.to_string())))
+                        .collect(),
+                }
+            })
+            .buffered(concurrency)
+            .collect()
+            .await;
+        Ok(batch_results.into_iter().flatten().collect())
+    }
+
+    /// Store the vectors whose result failed again in batches of `batch_size`, merging the
+    /// retry outcomes into `results`
+    async fn retry_failed_vectors<S: VectorStore>(
+        store: &S,
+        collection: &str,
+        vectors: &[Vector],
+        results: &[TylResult<()>],
+        batch_size: usize,
+    ) -> TylResult<Vec<TylResult<()>>> {
+        if vectors.len() != results.len() {
+            return Err(TylError::validation(
+                "results",
+                format!(
+                    "Got {} results for {} vectors; pass the batch and its results unchanged",
+                    results.len(),
+                    vectors.len()
+                ),
+            ));
+        }
+
+        let retried: Vec<Vector> = vectors
+            .iter()
+            .zip(results)
+            .filter(|(_, result)| result.is_err())
+            .map(|(vector, _)| vector.clone())
+            .collect();
+        let mut retry_results = if retried.is_empty() {
+            Vec::new()
+        } else {
+            Self::store_batches_concurrently(store, collection, retried, batch_size, 1).await?
+        }
+        .into_iter();
+
+        // Built afresh rather than copied, as `TylError` is not `Clone`
+        Ok(results
+            .iter()
+            .map(|result| match result {
+                Ok(()) => Ok(()),
+                Err(_) => retry_results.next().unwrap_or_else(|| {
+                    Err(vector_errors::storage_failed(
+                        "Missing result for retried vector",
+                    ))
+                }),
+            })
+            .collect())
+    }
+
+    /// Assign each embedding a UUIDv4 and store them in batches of `batch_size`, pairing each
+    /// ID with its result
+    async fn store_with_generated_ids<S: VectorStore>(
+        store: &S,
+        collection: &str,
+        embeddings: Vec<(Vec<f32>, HashMap<String, serde_json::Value>)>,
+        batch_size: usize,
+    ) -> TylResult<Vec<(String, TylResult<()>)>> {
+        let mut ids = Vec::with_capacity(embeddings.len());
+        let vectors = embeddings
+            .into_iter()
+            .map(|(embedding, metadata)| {
+                let id = uuid::Uuid::new_v4().to_string();
+                ids.push(id.clone());
+                Vector::with_metadata(id, embedding, metadata)
+            })
+            .collect();
+
+        let results =
+            Self::store_batches_concurrently(store, collection, vectors, batch_size, 1).await?;
+        Ok(ids.into_iter().zip(results).collect())
+    }
+
+    /// Read a JSONL collection export and store it in batches of `batch_size`
+    ///
+    /// Returns the number of vectors stored. Batches stored before a failure stay stored, so
+    /// errors are wrapped to report how many vectors were committed.
+    async fn import_export_lines<S: VectorStore>(
+        store: &S,
+        collection: &str,
+        reader: impl tokio::io::AsyncRead + Unpin,
+        batch_size: usize,
+    ) -> TylResult<usize> {
+        let mut committed = 0;
+        let result =
+            Self::import_lines_into(store, collection, reader, batch_size, &mut committed).await;
+        result
+            .map(|()| committed)
+            .map_err(|e| qdrant_errors::import_interrupted(committed, e))
+    }
+
+    async fn import_lines_into<S: VectorStore>(
+        store: &S,
+        collection: &str,
+        reader: impl tokio::io::AsyncRead + Unpin,
+        batch_size: usize,
+        committed: &mut usize,
+    ) -> TylResult<()> {
+        use tokio::io::AsyncBufReadExt;
+
+        let mut batch = Vec::with_capacity(batch_size);
+        let mut lines = tokio::io::BufReader::new(reader).lines();
+        let mut line_number = 0;
+        while let Some(line) = lines
+            .next_line()
+            .await
+            .map_err(|e| vector_errors::storage_failed(format!("Failed to read import: {e}")))?
+        {
+            line_number += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: ExportedVector = serde_json::from_str(&line)
+                .map_err(|e| TylError::validation("import", format!("Line {line_number}: {e}")))?;
+            let mut vector = Vector::new(record.id, record.embedding);
+            vector.metadata = record.metadata;
+            batch.push(vector);
+
+            if batch.len() >= batch_size {
+                Self::store_import_batch(store, collection, std::mem::take(&mut batch), committed)
+                    .await?;
+            }
+        }
+        if !batch.is_empty() {
+            Self::store_import_batch(store, collection, batch, committed).await?;
+        }
+        Ok(())
+    }
+
+    /// Store one import batch, adding the vectors that were stored to `committed`
+    async fn store_import_batch<S: VectorStore>(
+        store: &S,
+        collection: &str,
+        batch: Vec<Vector>,
+        committed: &mut usize,
+    ) -> TylResult<()> {
+        let mut first_error = None;
+        for result in store.store_vectors_batch(collection, batch).await? {
+            match result {
+                Ok(()) => *committed += 1,
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+
+    /// Build the scroll request used by `distinct_values`: only `field` of the payload, no
+    /// vectors
+    fn build_facet_scroll_request(
+        collection: &str,
+        field: &str,
+        offset: Option<PointId>,
+        page_size: usize,
+    ) -> qdrant_client::qdrant::ScrollPoints {
+        qdrant_client::qdrant::ScrollPoints {
+            collection_name: collection.to_string(),
+            offset,
+            limit: Some(page_size.min(u32::MAX as usize) as u32),
+            with_payload: Some(WithPayloadSelector {
+                selector_options: Some(
+                    qdrant_client::qdrant::with_payload_selector::SelectorOptions::Include(
+                        qdrant_client::qdrant::PayloadIncludeSelector {
+                            fields: vec![field.to_string()],
+                        },
+                    ),
+                ),
+            }),
+            with_vectors: Some(Self::vectors_selector(false)),
+            ..Default::default()
+        }
+    }
+
+    /// Distinct values of a payload field with their point counts, most frequent first
+    ///
+    /// Built by scrolling the collection for just `field` and counting in Rust, so the cost
+    /// grows with the collection size. Scrolling stops after `max_points` points, in point ID
+    /// order; counts are then only those of the points scanned. On a collection being written
+    /// to the counts are approximate too: points written during the scroll may or may not be
+    /// counted. At most `limit` values are returned.
+    pub async fn distinct_values(
+        &self,
+        collection: &str,
+        field: &str,
+        limit: usize,
+        max_points: usize,
+    ) -> TylResult<Vec<(serde_json::Value, u64)>> {
+        let context = format!(
+            "Collecting distinct values of '{field}' in collection '{collection}' from at most \
+             {max_points} points"
+        );
+
+        self.with_telemetry("qdrant_distinct_values", &context, async {
+            let mut counts = FacetCounts::default();
+            let mut scanned = 0;
+            let mut offset = None;
+            while scanned < max_points {
+                let page_size = self.config.max_batch_size.min(max_points - scanned);
+                let response = Self::map_point_error(
+                    self.client
+                        .scroll(Self::build_facet_scroll_request(
+                            &self.server_collection(collection),
+                            field,
+                            offset,
+                            page_size,
+                        ))
+                        .await,
+                    collection,
+                    "Scroll failed",
+                )?;
+                scanned += response.result.len();
+                for mut point in response.result {
+                    if let Some(value) = point
+                        .payload
+                        .remove(field)
+                        .and_then(Self::qdrant_to_json_value)
+                    {
+                        counts.add(&value);
+                    }
+                }
+
+                match response.next_page_offset {
+                    Some(next) => offset = Some(next),
+                    None => break,
+                }
+            }
+            Ok(counts.top(limit))
+        })
+        .await
+    }
+
+    /// Download a server-side snapshot of a collection to `dest`, returning its size in bytes
+    ///
+    /// The snapshot is streamed from the REST API at `QdrantConfig::rest_api_url`, so backups
+    /// can be taken off-site without access to Qdrant's storage volume. `dest` is replaced if
+    /// it exists. Snapshots are node-local: in a cluster, download them from each node.
+    pub async fn download_snapshot(
+        &self,
+        collection: &str,
+        snapshot_name: &str,
+        dest: &std::path::Path,
+    ) -> TylResult<u64> {
+        let context = format!(
+            "Downloading snapshot '{snapshot_name}' of collection '{collection}' to {}",
+            dest.display()
+        );
+
+        self.with_telemetry("qdrant_download_snapshot", &context, async {
+            let server_collection = self.server_collection(collection);
+            let snapshots = Self::map_qdrant_error(
+                self.client.list_snapshots(server_collection.clone()).await,
+                "Failed to list snapshots",
+            )?;
+            // Qdrant answers unknown snapshots with an error body that would be saved as the file
+            if !snapshots
+                .snapshot_descriptions
+                .iter()
+                .any(|snapshot| snapshot.name == snapshot_name)
+            {
+                return Err(qdrant_errors::snapshot_not_found(collection, snapshot_name));
+            }
+
+            Self::download_snapshot_file(
+                &self.client,
+                &self.config.rest_api_url(),
+                &server_collection,
+                snapshot_name,
+                dest,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Stream a snapshot from the REST API at `rest_url` into `dest` and return its size
+    async fn download_snapshot_file(
+        client: &Qdrant,
+        rest_url: &str,
+        server_collection: &str,
+        snapshot_name: &str,
+        dest: &std::path::Path,
+    ) -> VectorResult<u64> {
+        let download = qdrant_client::qdrant::SnapshotDownloadBuilder::new(dest, server_collection)
+            .snapshot_name(snapshot_name)
+            .rest_api_uri(rest_url);
+        Self::map_qdrant_error(
+            client.download_snapshot(download).await,
+            "Failed to download snapshot",
+        )?;
+
+        let metadata = Self::map_qdrant_error(
+            tokio::fs::metadata(dest).await,
+            "Failed to read downloaded snapshot",
+        )?;
+        Ok(metadata.len())
+    }
+
+    /// Export every vector in a collection as JSONL, one serialized vector per line
+    ///
+    /// Unlike Qdrant snapshots, which stay on the server, the export is portable and can be
+    /// loaded into any collection with `import_collection`. Write to a `tokio::fs::File` to
+    /// export to disk without blocking the runtime. Returns the number exported.
+    pub async fn export_collection(
+        &self,
+        collection: &str,
+        mut writer: impl tokio::io::AsyncWrite + Unpin,
+    ) -> TylResult<usize> {
+        let context = format!("Exporting collection '{collection}'");
+
+        self.with_telemetry("qdrant_export_collection", &context, async {
+            let mut exported = 0;
+            let mut offset = None;
+            loop {
+                let (page, next_offset) = self.scroll_page(collection, offset, true).await?;
+                exported += page.len();
+                for vector in page {
+                    Self::write_export_line(&mut writer, vector).await?;
+                }
+
+                match next_offset {
+                    Some(next) => offset = Some(next),
+                    None => break,
+                }
+            }
+            Self::flush_export(&mut writer).await?;
+            Ok(exported)
+        })
+        .await
+    }
+
+    /// SHA-256 checksum of every point's ID and payload, as a hex string
+    ///
+    /// Compare checksums of a source collection and its replica or restored backup to detect
+    /// divergence. This scrolls the whole collection, so expect it to take as long as an
+    /// export. Points are folded in point ID order, so checksums are only comparable between
+    /// deployments with the same `hash_string_ids` setting.
+    pub async fn collection_checksum(&self, collection: &str) -> TylResult<String> {
+        self.checksum_collection(collection, false).await
+    }
+
+    /// Like `collection_checksum`, but the embeddings are part of the checksum too
+    pub async fn collection_checksum_with_embeddings(&self, collection: &str) -> TylResult<String> {
+        self.checksum_collection(collection, true).await
+    }
+
+    async fn checksum_collection(
+        &self,
+        collection: &str,
+        include_embeddings: bool,
+    ) -> TylResult<String> {
+        use sha2::{Digest, Sha256};
+
+        let context = format!("Computing checksum of collection '{collection}'");
+
+        self.with_telemetry("qdrant_collection_checksum", &context, async {
+            let mut hasher = Sha256::new();
+            let mut offset = None;
+            loop {
+                let (page, next_offset) = self
+                    .scroll_page(collection, offset, include_embeddings)
+                    .await?;
+                for vector in &page {
+                    Self::update_checksum(&mut hasher, vector, include_embeddings);
+                }
+
+                match next_offset {
+                    Some(next) => offset = Some(next),
+                    None => break,
+                }
+            }
+            Ok(format!("{:x}", hasher.finalize()))
+        })
+        .await
+    }
+
+    /// Fold one vector into a collection checksum
+    ///
+    /// Fields are length-prefixed and payload keys sorted, so the result does not depend on
+    /// map iteration order and adjacent fields cannot run into each other.
+    fn update_checksum(hasher: &mut sha2::Sha256, vector: &Vector, include_embeddings: bool) {
+        use sha2::Digest;
+
+        let mut update = |bytes: &[u8]| {
+            hasher.update((bytes.len() as u64).to_le_bytes());
+            hasher.update(bytes);
+        };
+
+        update(vector.id.as_bytes());
+        let mut keys: Vec<&String> = vector.metadata.keys().collect();
+        keys.sort();
+        update(&(keys.len() as u64).to_le_bytes());
+        for key in keys {
+            update(key.as_bytes());
+            update(vector.metadata[key].to_string().as_bytes());
+        }
+        if include_embeddings {
+            let embedding: Vec<u8> = vector
+                .embedding
+                .iter()
+                .flat_map(|component| component.to_le_bytes())
+                .collect();
+            update(&embedding);
+        }
+    }
+
+    /// Import a JSONL export produced by `export_collection` into an existing collection
+    ///
+    /// Vectors are stored in batches of `max_batch_size`; returns the number imported. Read
+    /// from a `tokio::fs::File` to import from disk without blocking the runtime. Batches
+    /// stored before a failure are kept, and the error reports how many vectors were
+    /// committed.
+    pub async fn import_collection(
+        &self,
+        collection: &str,
+        reader: impl tokio::io::AsyncRead + Unpin,
+    ) -> TylResult<usize> {
+        let context = format!("Importing into collection '{collection}'");
+
+        self.with_telemetry("qdrant_import_collection", &context, async {
+            Self::import_export_lines(self, collection, reader, self.config.max_batch_size).await
+        })
+        .await
+    }
+
+    /// Read CSV rows into vectors and store them in batches of `batch_size`
+    ///
+    /// Every embedding column must hold one float; metadata columns are stored as strings.
+    #[cfg(feature = "import")]
+    async fn import_csv_rows<S: VectorStore>(
+        store: &S,
+        collection: &str,
+        reader: impl std::io::Read,
+        options: &CsvImportOptions,
+        dimension: usize,
+        batch_size: usize,
+    ) -> TylResult<usize> {
+        if options.embedding_columns.len() != dimension {
+            return Err(qdrant_errors::vector_dimension_mismatch(
+                dimension,
+                options.embedding_columns.len(),
+            ));
+        }
+
+        let csv_error = |e: csv::Error| TylError::validation("csv", e.to_string());
+        let mut csv_reader = csv::Reader::from_reader(reader);
+        let headers = csv_reader.headers().map_err(csv_error)?.clone();
+        let column_index = |name: &str| {
+            headers
+                .iter()
+                .position(|header| header == name)
+                .ok_or_else(|| TylError::validation("csv", format!("Missing column '{name}'")))
+        };
+        let id_index = column_index(&options.id_column)?;
+        let embedding_indexes = options
+            .embedding_columns
+            .iter()
+            .map(|name| column_index(name))
+            .collect::<VectorResult<Vec<usize>>>()?;
+        let metadata_indexes = options
+            .metadata_columns
+            .iter()
+            .map(|name| Ok((name.clone(), column_index(name)?)))
+            .collect::<VectorResult<Vec<(String, usize)>>>()?;
+
+        let mut imported = 0;
+        let mut batch = Vec::with_capacity(batch_size);
+        let mut records = csv_reader.records().peekable();
+        while let Some(record) = records.next() {
+            let record = record.map_err(csv_error)?;
+            let field = |index: usize| record.get(index).unwrap_or_default();
+
+            let embedding = embedding_indexes
+                .iter()
+                .map(|&index| {
+                    field(index).trim().parse::<f32>().map_err(|e| {
+                        TylError::validation(
+                            "csv",
+                            format!("Invalid embedding value '{}': {e}", field(index)),
+                        )
+                    })
+                })
+                .collect::<VectorResult<Vec<f32>>>()?;
+            let mut vector = Vector::new(field(id_index).to_string(), embedding);
+            for (name, index) in &metadata_indexes {
+                vector.add_metadata(name.as_str(), serde_json::json!(field(*index)));
+            }
+            batch.push(vector);
+
+            if batch.len() >= batch_size || records.peek().is_none() {
+                imported += batch.len();
+                for result in store
+                    .store_vectors_batch(collection, std::mem::take(&mut batch))
+                    .await?
+                {
+                    result?;
+                }
+            }
+        }
+        Ok(imported)
+    }
+
+    /// Bulk load vectors from CSV with one column per embedding dimension
+    ///
+    /// The number of embedding columns must match the collection's dimension. Metadata columns
+    /// are stored as string payload fields. Returns the number of vectors imported.
+    #[cfg(feature = "import")]
+    pub async fn import_csv(
+        &self,
+        collection: &str,
+        reader: impl std::io::Read,
+        options: &CsvImportOptions,
+    ) -> TylResult<usize> {
+        let context = format!("Importing CSV into collection '{collection}'");
+
+        self.with_telemetry("qdrant_import_csv", &context, async {
+            let config = self
+                .get_collection_info(collection)
+                .await?
+                .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+
+            Self::import_csv_rows(
+                self,
+                collection,
+                reader,
+                options,
+                config.dimension,
+                self.config.max_batch_size,
+            )
+            .await
+        })
+        .await
+    }
+
+    /// Build the search request used by `search_similar_with_options`
+    fn build_search_request(
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: &SearchParams,
+        options: &QdrantSearchOptions,
+        config: &QdrantConfig,
+    ) -> qdrant_client::qdrant::SearchPoints {
+        qdrant_client::qdrant::SearchPoints {
+            collection_name: collection.to_string(),
+            vector: query_vector,
+            limit: params.limit as u64,
+            score_threshold: params.threshold,
+            filter: Self::build_filter(params),
+            with_payload: Some(Self::search_payload_selector(options, config)),
+            with_vectors: Some(Self::vectors_selector(params.include_vectors)),
+            params: Self::search_runtime_params(options),
+            shard_key_selector: options.shard_key.clone().map(Into::into),
+            ..Default::default()
+        }
+    }
+
+    /// Build the query used by `search_using_stored_vector`
+    ///
+    /// The query vector is referenced by point ID and looked up in `source_collection` by
+    /// Qdrant, so the embedding never travels through the client.
+    fn build_lookup_search_request(
+        collection: &str,
+        source_collection: &str,
+        source_id: PointId,
+        params: &SearchParams,
+    ) -> qdrant_client::qdrant::QueryPoints {
+        use qdrant_client::qdrant::{query, vector_input, LookupLocation, Query, VectorInput};
+
+        qdrant_client::qdrant::QueryPoints {
+            collection_name: collection.to_string(),
+            query: Some(Query {
+                variant: Some(query::Variant::Nearest(VectorInput {
+                    variant: Some(vector_input::Variant::Id(source_id)),
+                })),
+            }),
+            lookup_from: Some(LookupLocation {
+                collection_name: source_collection.to_string(),
+                ..Default::default()
+            }),
+            filter: Self::build_filter(params),
+            limit: Some(params.limit as u64),
+            score_threshold: params.threshold,
+            with_payload: Some(Self::payload_selector(true)),
+            with_vectors: Some(Self::vectors_selector(params.include_vectors)),
+            ..Default::default()
+        }
+    }
+
+    /// Query-time HNSW parameters, omitted when the collection defaults apply
+    fn search_runtime_params(
+        options: &QdrantSearchOptions,
+    ) -> Option<qdrant_client::qdrant::SearchParams> {
+        if options.hnsw_ef.is_none() && !options.exact {
+            return None;
+        }
+        Some(qdrant_client::qdrant::SearchParams {
+            hnsw_ef: options.hnsw_ef,
+            exact: options.exact.then_some(true),
+            ..Default::default()
+        })
+    }
+
+    /// Split a search of `limit` results into `(offset, limit)` pages of at most `page_size`
+    ///
+    /// A zero limit needs no page, so no request is sent for it.
+    fn search_pages(limit: usize, page_size: usize) -> Vec<(usize, usize)> {
+        (0..limit)
+            .step_by(page_size.max(1))
+            .map(|offset| (offset, page_size.max(1).min(limit - offset)))
+            .collect()
+    }
+
+    /// Add a fetched page of at most `page_limit` results to `results` and report progress
+    ///
+    /// Returns whether later pages can hold results; a short page means the collection ran
+    /// out of matches.
+    fn accept_search_page(
+        results: &mut Vec<VectorSearchResult>,
+        page: Vec<VectorSearchResult>,
+        page_limit: usize,
+        on_batch: &mut impl FnMut(usize),
+    ) -> bool {
+        let page_len = page.len();
+        results.extend(page);
+        on_batch(results.len());
+        page_len >= page_limit
+    }
+
+    /// Search for similar vectors, reporting progress while large result sets are paged in
+    ///
+    /// Limits above `max_batch_size` are fetched in pages of that size and `on_batch` is called
+    /// with the cumulative number of results after each page; smaller searches call it once.
+    /// A zero limit returns no results without a request and reports 0 once.
+    pub async fn search_similar_with_progress(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+        mut on_batch: impl FnMut(usize),
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let context = format!(
+            "Searching similar vectors in collection '{collection}' with limit {} and progress",
+            params.limit
+        );
+
+        self.with_telemetry("qdrant_search_similar", &context, async {
+            self.check_skipped_filters(collection, &params)?;
+            if params.limit == 0 {
+                on_batch(0);
+                return Ok(Vec::new());
+            }
+            let options = QdrantSearchOptions::default();
+            // Grows page by page: high limits are the point of this method, and are rarely
+            // filled
+            let mut results = Vec::new();
+            for (offset, limit) in Self::search_pages(params.limit, self.config.max_batch_size) {
+                let mut request = Self::build_search_request(
+                    &self.server_collection(collection),
+                    query_vector.clone(),
+                    &params,
+                    &options,
+                    &self.config,
+                );
+                request.limit = limit as u64;
+                request.offset = Some(offset as u64);
+
+                let response = Self::map_point_error(
+                    self.client.search_points(request).await,
+                    collection,
+                    "Search failed",
+                )?;
+                let mut page = Vec::with_capacity(response.result.len());
+                for point in response.result {
+                    let score = point.score;
+                    page.push(VectorSearchResult::new(
+                        Self::point_to_vector(point, &self.config)?,
+                        score,
+                    ));
+                }
+
+                if !Self::accept_search_page(&mut results, page, limit, &mut on_batch) {
+                    break;
+                }
+            }
+            self.log_debug(|| Self::search_summary(collection, &results));
+            Ok(results)
+        })
+        .await
+    }
+
+    /// Fail with a validation error unless the collection's metric is the expected one
+    fn check_distance_metric(
+        collection: &str,
+        expected: &DistanceMetric,
+        actual: &DistanceMetric,
+    ) -> VectorResult<()> {
+        let expected = Self::distance_metric_to_qdrant(expected);
+        let actual = Self::distance_metric_to_qdrant(actual);
+        if expected != actual {
+            return Err(qdrant_errors::distance_metric_mismatch(
+                collection,
+                expected.as_str_name(),
+                actual.as_str_name(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Search for similar vectors with Qdrant-specific options such as payload inclusion
+    pub async fn search_similar_with_options(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+        options: QdrantSearchOptions,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let context = format!(
+            "Searching similar vectors in collection '{collection}' with limit {}",
+            params.limit
+        );
+
+        self.with_telemetry("qdrant_search_similar", &context, async {
+            if let Some(expected) = &options.expected_metric {
+                let actual = self
+                    .get_collection_info(collection)
+                    .await?
+                    .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+                Self::check_distance_metric(collection, expected, &actual.distance_metric)?;
+            }
+
+            self.check_skipped_filters(collection, &params)?;
+            self.check_filter_indexes(collection, &params).await;
+
+            let search_points = Self::build_search_request(
+                &self.server_collection(collection),
+                query_vector,
+                &params,
+                &options,
+                &self.config,
+            );
+
+            let response = Self::map_point_error(
+                self.client.search_points(search_points).await,
+                collection,
+                "Search failed",
+            )?;
+
+            let mut results = Vec::new();
+            for point in response.result {
+                let vector = Self::point_to_vector(point.clone(), &self.config)?;
+                let result = VectorSearchResult::new(vector, point.score);
+                results.push(result);
+            }
+
+            if let Some(key) = &options.dedup_by {
+                let metric = self.collection_metric(collection).await?;
+                results = Self::dedup_results(results, key, &metric);
+            }
+
+            self.log_debug(|| Self::search_summary(collection, &results));
+            Ok(results)
+        })
+        .await
+    }
+
+    /// Keep the best-scoring result under `metric` per distinct value of the payload `key`
+    ///
+    /// Results are returned best first; results without `key` are kept.
+    fn dedup_results(
+        mut results: Vec<VectorSearchResult>,
+        key: &str,
+        metric: &DistanceMetric,
+    ) -> Vec<VectorSearchResult> {
+        if is_higher_better(metric) {
+            results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        } else {
+            results.sort_by(|a, b| a.score.total_cmp(&b.score));
+        }
+        let mut seen = std::collections::HashSet::new();
+        results.retain(|result| match result.vector.metadata.get(key) {
+            Some(value) => seen.insert(value.to_string()),
+            None => true,
+        });
+        results
+    }
+
+    /// Search for similar vectors after checking the collection uses `expected_metric`
+    ///
+    /// The metric is fixed at collection creation, so a mismatch means the query was built for
+    /// a different collection setup; it is reported as a validation error instead of results.
+    pub async fn search_similar_checked(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+        expected_metric: DistanceMetric,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let options = QdrantSearchOptions::new().expect_metric(expected_metric);
+        self.search_similar_with_options(collection, query_vector, params, options)
+            .await
+    }
+
+    /// Search with the configured `default_search_limit` and `default_search_threshold`
+    ///
+    /// Keeps query behavior consistent across a service; build `SearchParams` and call
+    /// `search_similar` when a query needs filters or different limits.
+    pub async fn search_with_defaults(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        self.search_similar(
+            collection,
+            query_vector,
+            self.config.default_search_params(),
+        )
+        .await
+    }
+
+    /// Build the upsert for `points`, routed to the shards under `shard_key` if given
+    fn build_upsert_request(
+        collection: &str,
+        points: Vec<PointStruct>,
+        shard_key: Option<&str>,
+    ) -> UpsertPoints {
+        UpsertPoints {
+            collection_name: collection.to_string(),
+            points,
+            shard_key_selector: shard_key.map(|key| key.to_string().into()),
+            ..Default::default()
+        }
+    }
+
+    /// Build the request used by `get_vector`, read from the shards under `shard_key` if given
+    fn build_get_request(
+        collection: &str,
+        point_id: PointId,
+        shard_key: Option<&str>,
+    ) -> GetPoints {
+        GetPoints {
+            collection_name: collection.to_string(),
+            ids: vec![point_id],
+            with_payload: Some(Self::payload_selector(true)),
+            with_vectors: Some(Self::vectors_selector(true)),
+            shard_key_selector: shard_key.map(|key| key.to_string().into()),
+            ..Default::default()
+        }
+    }
+
+    /// Retrieve a vector by ID, optionally only from the shards under `shard_key`
+    async fn get_vector_from_shard(
+        &self,
+        collection: &str,
+        id: &str,
+        shard_key: Option<&str>,
+    ) -> TylResult<Option<Vector>> {
+        let context = format!("Getting vector '{id}' from collection '{collection}'");
+
+        self.with_telemetry("qdrant_get_vector", &context, async {
+            let get_points = Self::build_get_request(
+                &self.server_collection(collection),
+                Self::resolve_point_id(id, &self.config)?,
+                shard_key,
+            );
+
+            let points = Self::map_point_error(
+                self.client.get_points(get_points).await,
+                collection,
+                "Failed to get vector",
+            )?;
+
+            if let Some(point) = points.result.into_iter().next() {
+                Ok(Some(Self::retrieved_point_to_vector(point, &self.config)?))
+            } else {
+                Ok(None)
+            }
+        })
+        .await
+    }
+
+    /// Upsert one vector, logging `context` with the operation's telemetry
+    async fn store_vector_in_context(
+        &self,
+        collection: &str,
+        vector: Vector,
+        shard_key: Option<&str>,
+        context: &str,
+    ) -> TylResult<()> {
+        self.with_telemetry("qdrant_store_vector", context, async {
+            let mut vector = vector;
+            self.prepare_vector(collection, &mut vector)?;
+            let point = Self::vector_to_point_struct(vector, &self.config)?;
+
+            let response = Self::map_qdrant_error(
+                self.client
+                    .upsert_points(Self::build_upsert_request(
+                        &self.server_collection(collection),
+                        vec![point],
+                        shard_key,
+                    ))
+                    .await,
+                "Failed to store vector",
+            )?;
+
+            if response.result.is_none() {
+                return Err(vector_errors::storage_failed("No response from Qdrant"));
+            }
+
+            Ok(())
+        })
+        .await
+    }
+
+    /// Store a vector, tagging the operation's log records with a client-generated request ID
+    ///
+    /// The ID is appended to the telemetry context as `request_id=...`, so the start and
+    /// outcome records of the upsert can be correlated with application and Qdrant logs.
+    pub async fn store_vector_with_request_id(
+        &self,
+        collection: &str,
+        vector: Vector,
+        request_id: &str,
+    ) -> TylResult<()> {
+        let context = format!(
+            "Storing vector '{}' in collection '{collection}' (request_id={request_id})",
+            vector.id
+        );
+        self.store_vector_in_context(collection, vector, None, &context)
+            .await
+    }
+
+    /// Store a vector in the shards under `shard_key`, e.g. a tenant's dedicated shard
+    ///
+    /// The collection must use custom sharding with `shard_key` created. Read it back with
+    /// `get_vector_with_shard_key` and search with `QdrantSearchOptions::with_shard_key`.
+    pub async fn store_vector_with_shard_key(
+        &self,
+        collection: &str,
+        vector: Vector,
+        shard_key: &str,
+    ) -> TylResult<()> {
+        let context = format!(
+            "Storing vector '{}' in collection '{collection}' under shard key '{shard_key}'",
+            vector.id
+        );
+        self.store_vector_in_context(collection, vector, Some(shard_key), &context)
+            .await
+    }
+
+    /// Retrieve a vector by ID from the shards under `shard_key` only
+    pub async fn get_vector_with_shard_key(
+        &self,
+        collection: &str,
+        id: &str,
+        shard_key: &str,
+    ) -> TylResult<Option<Vector>> {
+        self.get_vector_from_shard(collection, id, Some(shard_key))
+            .await
+    }
+
+    /// Search for candidates, then reorder them with a caller-supplied re-ranker
+    ///
+    /// `params.limit` sets how many candidates the re-ranker sees; fetch more than you need
+    /// when re-ranking with e.g. a cross-encoder. The re-ranker's output is returned as is, so
+    /// it may also drop or rescore candidates.
+    pub async fn search_similar_then_rerank(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+        reranker: impl Fn(&[VectorSearchResult]) -> Vec<VectorSearchResult>,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let candidates = self
+            .search_similar(collection, query_vector, params)
+            .await?;
+        Ok(reranker(&candidates))
+    }
+
+    /// Search `collection` using the vector stored under `source_id` in `source_collection`
+    ///
+    /// Qdrant looks the query vector up server-side (`lookup_from`), which avoids fetching
+    /// and re-sending large embeddings. Both collections must use the same dimension, and
+    /// when they are the same collection the source point itself is among the results.
+    pub async fn search_using_stored_vector(
+        &self,
+        collection: &str,
+        source_collection: &str,
+        source_id: &str,
+        params: SearchParams,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let context = format!(
+            "Searching collection '{collection}' with vector '{source_id}' from collection \
+             '{source_collection}'"
+        );
+
+        self.with_telemetry("qdrant_search_using_stored_vector", &context, async {
+            self.check_skipped_filters(collection, &params)?;
+            let source_server = self.server_collection(source_collection);
+            let request = Self::build_lookup_search_request(
+                &self.server_collection(collection),
+                &source_server,
+                Self::resolve_point_id(source_id, &self.config)?,
+                &params,
+            );
+
+            // Either collection may be the missing one
+            let response = self.client.query(request).await.map_err(|e| {
+                circuit_breaker::observe_error(&e);
+                match Self::missing_collection(&e) {
+                    Some(name) if name == source_server => {
+                        vector_errors::collection_not_found(source_collection)
+                    }
+                    Some(_) => vector_errors::collection_not_found(collection),
+                    None => vector_errors::storage_failed(format!(
+                        "Search with stored vector failed: {e}"
+                    )),
+                }
+            })?;
+
+            let mut results = Vec::with_capacity(response.result.len());
+            for point in response.result {
+                let score = point.score;
+                results.push(VectorSearchResult::new(
+                    Self::point_to_vector(point, &self.config)?,
+                    score,
+                ));
+            }
+            Ok(results)
+        })
+        .await
+    }
+
+    /// Keep only results within `1 - relative_threshold` of the best score's magnitude
+    ///
+    /// Under `metric` that is at least `best - |best| * (1 - relative_threshold)` for
+    /// similarities and at most `best + |best| * (1 - relative_threshold)` for distances, so
+    /// negative dot product scores and distances are handled alike.
+    fn apply_relative_threshold(
+        mut results: Vec<VectorSearchResult>,
+        relative_threshold: f32,
+        metric: &DistanceMetric,
+    ) -> VectorResult<Vec<VectorSearchResult>> {
+        if !(relative_threshold > 0.0 && relative_threshold <= 1.0) {
+            return Err(qdrant_errors::invalid_search_params(format!(
+                "Relative threshold must be in (0, 1], got {relative_threshold}"
+            )));
+        }
+
+        let higher_is_better = is_higher_better(metric);
+        let best = |a: f32, b: f32| if higher_is_better { a.max(b) } else { a.min(b) };
+        let Some(best_score) = results.iter().map(|r| r.score).reduce(best) else {
+            return Ok(results);
+        };
+
+        let margin = best_score.abs() * (1.0 - relative_threshold);
+        let cutoff = if higher_is_better {
+            best_score - margin
+        } else {
+            best_score + margin
+        };
+        results.retain(|r| passes_score_threshold(metric, r.score, cutoff));
+        Ok(results)
+    }
+
+    /// Search for the top results that score within a fraction of the best match
+    ///
+    /// Fetches up to `limit` candidates and keeps those within `1 - relative_threshold` of the
+    /// best score, so `0.9` returns results within 10% of the best one. On Euclidean and
+    /// Manhattan collections that means at most 10% farther than the nearest result.
+    pub async fn search_top_relative(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        limit: usize,
+        relative_threshold: f32,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let results = self
+            .search_similar(collection, query_vector, SearchParams::with_limit(limit))
+            .await?;
+        let metric = self.collection_metric(collection).await?;
+        Self::apply_relative_threshold(results, relative_threshold, &metric)
+    }
+
+    /// Drop `results` unless the best one passes `min_best_score` under `metric`
+    ///
+    /// With `error_if_irrelevant` a miss is a `no_relevant_results` error instead of an empty
+    /// list. No results at all count as a miss.
+    fn apply_relevance_gate(
+        collection: &str,
+        results: Vec<VectorSearchResult>,
+        metric: &DistanceMetric,
+        min_best_score: f32,
+        error_if_irrelevant: bool,
+    ) -> VectorResult<Vec<VectorSearchResult>> {
+        let best = |a: f32, b: f32| {
+            if is_higher_better(metric) {
+                a.max(b)
+            } else {
+                a.min(b)
+            }
+        };
+        let best_score = results.iter().map(|r| r.score).reduce(best);
+        if best_score.is_some_and(|best| passes_score_threshold(metric, best, min_best_score)) {
+            return Ok(results);
+        }
+        if error_if_irrelevant {
+            return Err(qdrant_errors::no_relevant_results(
+                collection,
+                best_score,
+                min_best_score,
+            ));
+        }
+        Ok(Vec::new())
+    }
+
+    /// Search, treating results as "no good answer" unless the best one is relevant enough
+    ///
+    /// A relevance gate for e.g. RAG: when the top result does not pass `min_best_score` every
+    /// result is dropped, returning an empty list or, with `error_if_irrelevant`, a
+    /// `no_relevant_results` error. Like `params.threshold`, `min_best_score` is a minimum
+    /// similarity, or a maximum distance on Euclidean and Manhattan collections; unlike it,
+    /// results that miss it are kept as long as the best one passes.
+    pub async fn search_similar_require_relevance(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+        min_best_score: f32,
+        error_if_irrelevant: bool,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let results = self
+            .search_similar(collection, query_vector, params)
+            .await?;
+        let metric = self.collection_metric(collection).await?;
+        Self::apply_relevance_gate(
+            collection,
+            results,
+            &metric,
+            min_best_score,
+            error_if_irrelevant,
+        )
+    }
+
+    /// Add a `$has_id` allowlist to `params`, ANDed with its other filters
+    fn restrict_to_ids(params: SearchParams, allowed_ids: &[String]) -> SearchParams {
+        params.with_filter(
+            ALLOWED_IDS_FILTER_KEY,
+            serde_json::json!({ "$has_id": allowed_ids }),
+        )
+    }
+
+    /// Search only among `allowed_ids`, e.g. the points a user is permitted to see
+    ///
+    /// The allowlist is applied server-side as a `$has_id` condition alongside the filters in
+    /// `params`; an empty allowlist returns no results without a request. Every ID is sent
+    /// with the request, so request size grows with the allowlist: beyond a few thousand IDs
+    /// prefer tagging points with an access-control payload field and filtering on that.
+    pub async fn search_within_ids(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        allowed_ids: &[String],
+        params: SearchParams,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        if allowed_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.search_similar(
+            collection,
+            query_vector,
+            Self::restrict_to_ids(params, allowed_ids),
+        )
+        .await
+    }
+
+    /// Build the match-all delete request used by `clear_collection`
+    fn build_clear_request(collection: &str) -> DeletePoints {
+        DeletePoints {
+            collection_name: collection.to_string(),
+            points: Some(PointsSelector {
+                points_selector_one_of: Some(
+                    qdrant_client::qdrant::points_selector::PointsSelectorOneOf::Filter(
+                        Filter::default(),
+                    ),
+                ),
+            }),
+            wait: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Delete every vector in a collection while keeping its configuration and indexes
+    ///
+    /// This is the only operation that sends a match-all filter, so clearing a collection is
+    /// always an explicit call rather than the result of an empty filter elsewhere.
+    pub async fn clear_collection(&self, collection: &str) -> TylResult<()> {
+        let context = format!("Clearing all vectors in collection '{collection}'");
+
+        self.with_telemetry("qdrant_clear_collection", &context, async {
+            let response = Self::map_qdrant_error(
+                self.client
+                    .delete_points(Self::build_clear_request(
+                        &self.server_collection(collection),
+                    ))
+                    .await,
+                "Failed to clear collection",
+            )?;
+
+            if response.result.is_none() {
+                return Err(vector_errors::storage_failed("No response from Qdrant"));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Build the exact count request used by `count_matching`
+    fn build_count_request(
+        collection: &str,
+        filter: &SearchParams,
+    ) -> qdrant_client::qdrant::CountPoints {
+        qdrant_client::qdrant::CountPoints {
+            collection_name: collection.to_string(),
+            filter: Self::build_filter(filter),
+            exact: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Build the delete request used by `delete_by_filter`
+    ///
+    /// Refuses params without filters so an empty filter can never wipe the collection.
+    ///
+    /// Filters are always checked strictly, whatever `strict_filters` says: a skipped entry
+    /// would widen the delete to points the caller meant to keep.
+    fn build_delete_by_filter_request(
+        collection: &str,
+        filter: &SearchParams,
+    ) -> VectorResult<DeletePoints> {
+        Self::check_strict_filter(filter)?;
+        let filter = Self::build_filter(filter).ok_or_else(|| {
+            qdrant_errors::invalid_search_params(
+                "delete_by_filter requires at least one filter; use clear_collection to delete all",
+            )
+        })?;
+
+        Ok(DeletePoints {
+            collection_name: collection.to_string(),
+            points: Some(PointsSelector {
+                points_selector_one_of: Some(
+                    qdrant_client::qdrant::points_selector::PointsSelectorOneOf::Filter(filter),
+                ),
+            }),
+            wait: Some(true),
+            ..Default::default()
+        })
+    }
+
+    /// Count the points matching the metadata filters in `filter`
+    pub async fn count_matching(&self, collection: &str, filter: SearchParams) -> TylResult<u64> {
+        let context = format!("Counting matching points in collection '{collection}'");
+
+        self.with_telemetry("qdrant_count_matching", &context, async {
+            self.check_skipped_filters(collection, &filter)?;
+            self.check_filter_indexes(collection, &filter).await;
+            let response = Self::map_point_error(
+                self.client
+                    .count(Self::build_count_request(
+                        &self.server_collection(collection),
+                        &filter,
+                    ))
+                    .await,
+                collection,
+                "Count failed",
+            )?;
+            Ok(response.result.map(|result| result.count).unwrap_or(0))
+        })
+        .await
+    }
+
+    /// Delete every point matching the metadata filters in `filter`
+    ///
+    /// Returns the number of matching points. With `dry_run` set nothing is deleted, so the
+    /// count can be checked before committing to a destructive operation. At least one filter
+    /// is required; use `clear_collection` to delete everything. Unlike searches, a filter
+    /// entry that cannot be translated (e.g. `$ne`) always fails the delete instead of being
+    /// skipped.
+    pub async fn delete_by_filter(
+        &self,
+        collection: &str,
+        filter: SearchParams,
+        dry_run: bool,
+    ) -> TylResult<u64> {
+        let context =
+            format!("Deleting by filter in collection '{collection}' (dry run: {dry_run})");
+
+        self.with_telemetry("qdrant_delete_by_filter", &context, async {
+            let request =
+                Self::build_delete_by_filter_request(&self.server_collection(collection), &filter)?;
+            let matching = self.count_matching(collection, filter).await?;
+            if dry_run || matching == 0 {
+                return Ok(matching);
+            }
+
+            let response = Self::map_qdrant_error(
+                self.client.delete_points(request).await,
+                "Delete by filter failed",
+            )?;
+            if response.result.is_none() {
+                return Err(vector_errors::storage_failed("No response from Qdrant"));
+            }
+            Ok(matching)
+        })
+        .await
+    }
+
+    /// Search like `search_similar`, also returning how long the search took
+    ///
+    /// The collection's metric, needed for `higher_is_better`, is read on the first search of
+    /// each collection and cached from then on; that lookup is not counted in `elapsed`.
+    pub async fn search_detailed(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+    ) -> TylResult<SearchOutcome> {
+        let start_time = Instant::now();
+        let threshold_applied = params.threshold.is_some();
+        let results = self
+            .search_similar(collection, query_vector, params)
+            .await?;
+        let elapsed = start_time.elapsed();
+        let higher_is_better = is_higher_better(&self.collection_metric(collection).await?);
+        Ok(SearchOutcome {
+            returned_count: results.len(),
+            results,
+            elapsed,
+            threshold_applied,
+            higher_is_better,
+        })
+    }
+
+    /// Distance metric of `collection`, looked up once and then served from a cache
+    ///
+    /// The cache only learns of a metric change through this adapter's creates, deletes and
+    /// collection info reads; `get_collection_info` refreshes it after an outside recreate.
+    async fn collection_metric(&self, collection: &str) -> TylResult<DistanceMetric> {
+        if let Some(metric) = self.collection_metrics.read().unwrap().get(collection) {
+            return Ok(metric.clone());
+        }
+        // Fills the cache as a side effect
+        Ok(self
+            .get_collection_info(collection)
+            .await?
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?
+            .distance_metric)
+    }
+
+    /// Replace the cached metric of `collection` with what its info reported, or drop it
+    fn refresh_collection_metric(
+        &self,
+        collection: &str,
+        details: Option<&DetailedCollectionConfig>,
+    ) {
+        let mut metrics = self.collection_metrics.write().unwrap();
+        match details {
+            Some(details) => {
+                metrics.insert(
+                    collection.to_string(),
+                    details.config.distance_metric.clone(),
+                );
+            }
+            None => {
+                metrics.remove(collection);
+            }
+        }
+    }
+
+    /// Store a vector like `store_vector`, returning its ID and how long the store took
+    pub async fn store_vector_detailed(
+        &self,
+        collection: &str,
+        vector: Vector,
+    ) -> TylResult<StoreOutcome> {
+        let start_time = Instant::now();
+        let id = vector.id.clone();
+        self.store_vector(collection, vector).await?;
+        Ok(StoreOutcome {
+            id,
+            collection: collection.to_string(),
+            duration: start_time.elapsed(),
+        })
+    }
+
+    /// Store a vector only if no point with the same ID exists yet
+    ///
+    /// Returns `true` if the vector was inserted and `false` if the ID already existed, in
+    /// which case the stored point is left untouched. This is a get followed by an upsert, so
+    /// a concurrent writer storing the same ID between the two calls can still be overwritten.
+    pub async fn store_vector_if_absent(
+        &self,
+        collection: &str,
+        vector: Vector,
+    ) -> TylResult<bool> {
+        if self.get_vector(collection, &vector.id).await?.is_some() {
+            return Ok(false);
+        }
+
+        self.store_vector(collection, vector).await?;
+        Ok(true)
+    }
+
+    /// Store a vector, keeping stored metadata keys that `vector.metadata` does not set
+    ///
+    /// An existing point is updated in place by one batch request that replaces its embedding
+    /// and merges `vector.metadata` into its payload with Qdrant's `SetPayload`, so new keys
+    /// win and keys written by concurrent writers are kept. Payload schema types are checked
+    /// for the keys being set; collection defaults are not applied again. A point that does
+    /// not exist yet is stored like `store_vector`.
+    pub async fn store_vector_merge_metadata(
+        &self,
+        collection: &str,
+        vector: Vector,
+    ) -> TylResult<()> {
+        let context = format!(
+            "Merging metadata of vector '{}' in collection '{collection}'",
+            vector.id
+        );
+        let merged = self
+            .with_telemetry("qdrant_store_vector_merge_metadata", &context, async {
+                Self::validate_metadata_update(
+                    &vector,
+                    self.payload_schemas.read().unwrap().get(collection),
+                    &self.config,
+                )?;
+                let request = Self::build_merge_metadata_request(
+                    &self.server_collection(collection),
+                    &vector,
+                    &self.config,
+                )?;
+                match self.client.update_points_batch(request).await {
+                    Ok(_) => Ok(true),
+                    Err(e) if Self::missing_point(&e) => Ok(false),
+                    Err(e) => Self::map_point_error(Err(e), collection, "Metadata merge failed"),
+                }
+            })
+            .await?;
+        if merged {
+            return Ok(());
+        }
+        self.store_vector(collection, vector).await
+    }
+
+    /// Check metadata merged into a stored point: no reserved keys, schema types of set keys
+    fn validate_metadata_update(
+        vector: &Vector,
+        schema: Option<&HashMap<String, ExpectedJsonType>>,
+        config: &QdrantConfig,
+    ) -> VectorResult<()> {
+        Self::validate_reserved_keys(vector, config)?;
+        let Some(schema) = schema else {
+            return Ok(());
+        };
+        let mut keys: Vec<_> = vector.metadata.keys().collect();
+        keys.sort();
+        for key in keys {
+            let value = &vector.metadata[key];
+            if let Some(expected) = schema.get(key).filter(|expected| !expected.matches(value)) {
+                return Err(qdrant_errors::payload_schema_violation(
+                    &vector.id,
+                    key,
+                    format!("expected {}, got {value}", expected.as_str()),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Build the request used by `store_vector_merge_metadata`: replace the embedding of an
+    /// existing point, then merge the vector's metadata into its payload
+    fn build_merge_metadata_request(
+        collection: &str,
+        vector: &Vector,
+        config: &QdrantConfig,
+    ) -> VectorResult<qdrant_client::qdrant::UpdateBatchPoints> {
+        use qdrant_client::qdrant::points_update_operation::{
+            Operation, SetPayload, UpdateVectors,
+        };
+        use qdrant_client::qdrant::{PointVectors, PointsUpdateOperation};
+
+        let point_id = Self::resolve_point_id(&vector.id, config)?;
+        let payload = vector
+            .metadata
+            .iter()
+            .filter_map(|(key, value)| {
+                Some((key.clone(), Self::json_to_qdrant_value(value.clone())?))
+            })
+            .collect();
+        let update_vectors = UpdateVectors {
+            points: vec![PointVectors {
+                id: Some(point_id.clone()),
+                vectors: Some(vector.embedding.clone().into()),
+            }],
+            ..Default::default()
+        };
+        let set_payload = SetPayload {
+            payload,
+            points_selector: Some(PointsSelector {
+                points_selector_one_of: Some(
+                    qdrant_client::qdrant::points_selector::PointsSelectorOneOf::Points(
+                        PointsIdsList {
+                            ids: vec![point_id],
+                        },
+                    ),
+                ),
+            }),
+            ..Default::default()
+        };
+        Ok(qdrant_client::qdrant::UpdateBatchPoints {
+            collection_name: collection.to_string(),
+            wait: Some(true),
+            operations: vec![
+                PointsUpdateOperation {
+                    operation: Some(Operation::UpdateVectors(update_vectors)),
+                },
+                PointsUpdateOperation {
+                    operation: Some(Operation::SetPayload(set_payload)),
+                },
+            ],
+            ..Default::default()
+        })
+    }
+
+    /// Reject multivector rows that are empty or of differing lengths
+    fn validate_multivector_rows(rows: &[Vec<f32>]) -> VectorResult<()> {
+        let Some(first) = rows.first() else {
+            return Err(qdrant_errors::invalid_multivector(
+                "a multivector needs at least one row",
+            ));
+        };
+        if first.is_empty() {
+            return Err(qdrant_errors::invalid_multivector(
+                "multivector rows cannot be empty",
+            ));
+        }
+        if let Some(row) = rows.iter().position(|row| row.len() != first.len()) {
+            return Err(qdrant_errors::invalid_multivector(format!(
+                "row {row} has {} components but row 0 has {}",
+                rows[row].len(),
+                first.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Build the point stored by `store_multivector`: payload as for `vector`, rows as vectors
+    fn build_multivector_point(
+        vector: Vector,
+        rows: Vec<Vec<f32>>,
+        config: &QdrantConfig,
+    ) -> VectorResult<PointStruct> {
+        Self::validate_multivector_rows(&rows)?;
+        let mut point = Self::vector_to_point_struct(vector, config)?;
+        point.vectors = Some(qdrant_client::qdrant::Vector::new_multi(rows).into());
+        Ok(point)
+    }
+
+    /// Build the request used by `update_named_vector`: one vector of one point, no payload
+    fn build_update_named_vector_request(
+        collection: &str,
+        point_id: PointId,
+        vector_name: &str,
+        embedding: Vec<f32>,
+    ) -> qdrant_client::qdrant::UpdatePointVectors {
+        qdrant_client::qdrant::UpdatePointVectors {
+            collection_name: collection.to_string(),
+            points: vec![qdrant_client::qdrant::PointVectors {
+                id: Some(point_id),
+                vectors: Some(HashMap::from([(vector_name.to_string(), embedding)]).into()),
+            }],
+            ..Default::default()
+        }
+    }
+
+    /// Replace one named vector of a point, leaving its payload and other vectors untouched
+    ///
+    /// Useful for collections with several named vectors, e.g. refreshing the `body`
+    /// embedding while keeping `title`. The collection must define `vector_name`; the empty
+    /// name targets the default vector of collections created by this adapter.
+    pub async fn update_named_vector(
+        &self,
+        collection: &str,
+        id: &str,
+        vector_name: &str,
+        embedding: Vec<f32>,
+    ) -> TylResult<()> {
+        let context =
+            format!("Updating vector '{vector_name}' of point '{id}' in collection '{collection}'");
+
+        self.with_telemetry("qdrant_update_named_vector", &context, async {
+            let point_id = Self::resolve_point_id(id, &self.config)?;
+            let request = Self::build_update_named_vector_request(
+                &self.server_collection(collection),
+                point_id,
+                vector_name,
+                embedding,
+            );
+            let response = Self::map_qdrant_error(
+                self.client.update_vectors(request).await,
+                "Failed to update named vector",
+            )?;
+
+            if response.result.is_none() {
+                return Err(vector_errors::storage_failed("No response from Qdrant"));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Store a point holding several vectors in a multivector collection
+    ///
+    /// Every row must have the collection's dimension. Collection defaults and payload schemas
+    /// apply as for `store_vector`. MaxSim search over multivectors is not supported yet.
+    pub async fn store_multivector(
+        &self,
+        collection: &str,
+        id: &str,
+        rows: Vec<Vec<f32>>,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> TylResult<()> {
+        let context = format!(
+            "Storing {}-row multivector '{id}' in collection '{collection}'",
+            rows.len()
+        );
+
+        self.with_telemetry("qdrant_store_multivector", &context, async {
+            let mut vector = Vector::new(id, Vec::new());
+            vector.metadata = metadata;
+            self.prepare_vector(collection, &mut vector)?;
+            let point = Self::build_multivector_point(vector, rows, &self.config)?;
+
+            let response = Self::map_qdrant_error(
+                self.client
+                    .upsert_points(UpsertPoints {
+                        collection_name: self.server_collection(collection),
+                        points: vec![point],
+                        ..Default::default()
+                    })
+                    .await,
+                "Failed to store multivector",
+            )?;
+
+            if response.result.is_none() {
+                return Err(vector_errors::storage_failed("No response from Qdrant"));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Build the query used by `get_vectors_with_versions`
+    ///
+    /// `GetPoints` results carry no version, so this is an ID-filtered query without a query
+    /// vector, which returns scored points including their `version`.
+    fn build_versioned_get_request(
+        collection: &str,
+        point_ids: &[PointId],
+    ) -> qdrant_client::qdrant::QueryPoints {
+        qdrant_client::qdrant::QueryPoints {
+            collection_name: collection.to_string(),
+            filter: Some(Filter::must([qdrant_client::qdrant::Condition::has_id(
+                point_ids.to_vec(),
+            )])),
+            limit: Some(point_ids.len() as u64),
+            with_payload: Some(Self::payload_selector(true)),
+            with_vectors: Some(Self::vectors_selector(true)),
+            ..Default::default()
+        }
+    }
+
+    /// Build the request used by `get_payload`, which leaves out the embedding
+    fn build_payload_get_request(collection: &str, point_id: PointId) -> GetPoints {
+        GetPoints {
+            collection_name: collection.to_string(),
+            ids: vec![point_id],
+            with_payload: Some(Self::payload_selector(true)),
+            with_vectors: Some(Self::vectors_selector(false)),
+            ..Default::default()
+        }
+    }
+
+    /// Build the request used by `existing_ids`, which returns point IDs only
+    fn build_existence_request(collection: &str, point_ids: Vec<PointId>) -> GetPoints {
+        GetPoints {
+            collection_name: collection.to_string(),
+            ids: point_ids,
+            with_payload: Some(Self::payload_selector(false)),
+            with_vectors: Some(Self::vectors_selector(false)),
+            ..Default::default()
+        }
+    }
+
+    /// The subset of `ids` stored in a collection, e.g. to skip known documents during ingestion
+    ///
+    /// Checks every ID in a single request without fetching payloads or vectors.
+    pub async fn existing_ids(
+        &self,
+        collection: &str,
+        ids: Vec<String>,
+    ) -> TylResult<std::collections::HashSet<String>> {
+        let context = format!(
+            "Checking {} IDs for existence in collection '{collection}'",
+            ids.len()
+        );
+
+        self.with_telemetry("qdrant_existing_ids", &context, async {
+            let mut ids_by_point = HashMap::with_capacity(ids.len());
+            for id in ids {
+                ids_by_point.insert(Self::resolve_point_id(&id, &self.config)?, id);
+            }
+            if ids_by_point.is_empty() {
+                return Ok(std::collections::HashSet::new());
+            }
+
+            let request = Self::build_existence_request(
+                &self.server_collection(collection),
+                ids_by_point.keys().cloned().collect(),
+            );
+            let response = Self::map_point_error(
+                self.client.get_points(request).await,
+                collection,
+                "Failed to check IDs",
+            )?;
+
+            Ok(response
+                .result
+                .into_iter()
+                .filter_map(|point| ids_by_point.remove(point.id.as_ref()?))
+                .collect())
+        })
+        .await
+    }
+
+    /// Fetch a vector's metadata without its embedding, e.g. to display a document's title
+    ///
+    /// Returns `None` if no vector with `id` exists.
+    pub async fn get_payload(
+        &self,
+        collection: &str,
+        id: &str,
+    ) -> TylResult<Option<HashMap<String, serde_json::Value>>> {
+        let context = format!("Getting payload of vector '{id}' from collection '{collection}'");
+
+        self.with_telemetry("qdrant_get_payload", &context, async {
+            let point_id = Self::resolve_point_id(id, &self.config)?;
+            let response = Self::map_point_error(
+                self.client
+                    .get_points(Self::build_payload_get_request(
+                        &self.server_collection(collection),
+                        point_id,
+                    ))
+                    .await,
+                collection,
+                "Failed to get payload",
+            )?;
+
+            Ok(response.result.into_iter().next().map(|point| {
+                let mut metadata = Self::payload_to_metadata(point.payload);
+                metadata.remove(&self.config.original_id_key());
+                metadata
+            }))
+        })
+        .await
+    }
+
+    /// Fetch many vectors together with their Qdrant point versions in a single call
+    ///
+    /// Results are in the order of `ids`, with `None` for IDs that do not exist. A point's
+    /// version changes on every write, so sync pipelines can skip points whose version matches
+    /// the one they last saw.
+    pub async fn get_vectors_with_versions(
+        &self,
+        collection: &str,
+        ids: Vec<String>,
+    ) -> TylResult<Vec<Option<(Vector, u64)>>> {
+        let context = format!(
+            "Getting {} vectors with versions from collection '{collection}'",
+            ids.len()
+        );
+
+        self.with_telemetry("qdrant_get_vectors_with_versions", &context, async {
+            let point_ids = ids
+                .iter()
+                .map(|id| Self::resolve_point_id(id, &self.config))
+                .collect::<VectorResult<Vec<_>>>()?;
+            if point_ids.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            let response = Self::map_point_error(
+                self.client
+                    .query(Self::build_versioned_get_request(
+                        &self.server_collection(collection),
+                        &point_ids,
+                    ))
+                    .await,
+                collection,
+                "Failed to get vectors",
+            )?;
+
+            let mut found = HashMap::new();
+            for point in response.result {
+                let Some(point_id) = point.id.clone() else {
+                    continue;
+                };
+                let version = point.version;
+                found.insert(
+                    point_id,
+                    (Self::point_to_vector(point, &self.config)?, version),
+                );
+            }
+            Ok(point_ids.iter().map(|id| found.get(id).cloned()).collect())
+        })
+        .await
+    }
+
+    /// Current state of the circuit breaker; always `Closed` when it is disabled
+    pub fn circuit_state(&self) -> CircuitState {
+        self.circuit_breaker
+            .as_ref()
+            .map_or(CircuitState::Closed, |breaker| {
+                breaker.state(Instant::now())
+            })
+    }
+
+    /// Collect connection diagnostics: URL, server version, health and collection count
+    ///
+    /// Never fails on an unreachable server; `healthy` is `false` and the server-reported
+    /// fields are `None` instead, so the result can always be logged. Runs outside the
+    /// circuit breaker: it is not rejected while the breaker is open and its outcome is not
+    /// recorded.
+    pub async fn connection_diagnostics(&self) -> TylResult<ConnectionDiagnostics> {
+        let context = format!("Collecting diagnostics for Qdrant at {}", self.config.url);
+
+        self.with_telemetry_outside_breaker("qdrant_connection_diagnostics", &context, async {
+            let server_version = self
+                .client
+                .health_check()
+                .await
+                .ok()
+                .map(|reply| reply.version);
+            let collection_count = match server_version {
+                Some(_) => self
+                    .client
+                    .list_collections()
+                    .await
+                    .ok()
+                    .map(|response| response.collections.len()),
+                None => None,
+            };
+
+            Ok(ConnectionDiagnostics {
+                url: self.config.url.clone(),
+                healthy: server_version.is_some(),
+                server_version,
+                collection_count,
+                timeout: Duration::from_secs(self.config.timeout_seconds),
+            })
+        })
+        .await
+    }
+
+    /// Fetch cluster state (peers, Raft term, leader and disk usage) for monitoring
+    ///
+    /// Uses the `/cluster` and `/telemetry` endpoints of the REST API at
+    /// `QdrantConfig::rest_api_url`, as neither has a gRPC equivalent. A cluster without a
+    /// `leader` or with pending operations is degraded. Requires the `cluster-info` feature.
+    #[cfg(feature = "cluster-info")]
+    pub async fn cluster_info(&self) -> TylResult<ClusterInfo> {
+        let context = format!("Fetching cluster info from {}", self.config.rest_api_url());
+
+        self.with_telemetry("qdrant_cluster_info", &context, async {
+            let mut info = Self::parse_cluster_info(&self.get_rest_json("/cluster").await?)?;
+            // Segment disk usage is only listed at the most detailed telemetry level
+            let telemetry = self.get_rest_json("/telemetry?details_level=4").await?;
+            info.disk_usage_bytes = Self::parse_disk_usage(&telemetry);
+            Ok(info)
+        })
+        .await
+    }
+
+    /// GET `path` from the REST API and return the JSON body
+    #[cfg(feature = "cluster-info")]
+    async fn get_rest_json(&self, path: &str) -> VectorResult<serde_json::Value> {
+        let url = format!("{}{path}", self.config.rest_api_url());
+        let mut request = reqwest::Client::new()
+            .get(&url)
+            .timeout(Duration::from_secs(self.config.timeout_seconds));
+        if let Some(api_key) = &self.config.api_key {
+            request = request.header("api-key", api_key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| qdrant_errors::connection_failed(format!("Failed to fetch {url}: {e}")))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(qdrant_errors::api_error(format!(
+                "{url} request failed with status {status}"
+            )));
+        }
+        response
+            .json()
+            .await
+            .map_err(|e| qdrant_errors::api_error(format!("Invalid response from {url}: {e}")))
+    }
+
+    /// Sum of the `disk_usage_bytes` of every segment in a `GET /telemetry` response
+    #[cfg(feature = "cluster-info")]
+    fn parse_disk_usage(body: &serde_json::Value) -> Option<u64> {
+        fn sum(value: &serde_json::Value) -> Option<u64> {
+            match value {
+                serde_json::Value::Object(fields) => fields
+                    .iter()
+                    .filter_map(|(key, value)| match key.as_str() {
+                        "disk_usage_bytes" => value.as_u64(),
+                        _ => sum(value),
+                    })
+                    .reduce(|a, b| a + b),
+                serde_json::Value::Array(items) => {
+                    items.iter().filter_map(sum).reduce(|a, b| a + b)
+                }
+                _ => None,
+            }
+        }
+        sum(&body["result"]["collections"])
+    }
+
+    /// Parse the body of a `GET /cluster` response
+    #[cfg(feature = "cluster-info")]
+    fn parse_cluster_info(body: &serde_json::Value) -> VectorResult<ClusterInfo> {
+        let result = body
+            .get("result")
+            .ok_or_else(|| qdrant_errors::api_error("cluster info response has no result"))?;
+        let raft = &result["raft_info"];
+
+        let mut peers: Vec<ClusterPeer> = result["peers"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter_map(|(id, peer)| {
+                Some(ClusterPeer {
+                    id: id.parse().ok()?,
+                    uri: peer["uri"].as_str().unwrap_or_default().to_string(),
+                })
+            })
+            .collect();
+        peers.sort_by_key(|peer| peer.id);
+
+        Ok(ClusterInfo {
+            enabled: result["status"].as_str() == Some("enabled"),
+            peer_id: result["peer_id"].as_u64(),
+            peers,
+            raft_term: raft["term"].as_u64(),
+            raft_commit: raft["commit"].as_u64(),
+            pending_operations: raft["pending_operations"].as_u64(),
+            leader: raft["leader"].as_u64(),
+            role: raft["role"].as_str().map(String::from),
+            consensus_status: result["consensus_thread_status"]["consensus_thread_status"]
+                .as_str()
+                .map(String::from),
+            disk_usage_bytes: None,
+        })
+    }
+
+    /// Wait until a collection reports `Green` status, e.g. before searching after a bulk load
+    ///
+    /// Polls the collection status every [`READINESS_POLL_INTERVAL`] and returns
+    /// `collection_not_ready` with the last seen status once `timeout` elapses.
+    pub async fn wait_until_ready(&self, collection: &str, timeout: Duration) -> TylResult<()> {
+        let context = format!("Waiting for collection '{collection}' to be ready");
+
+        self.with_telemetry("qdrant_wait_until_ready", &context, async {
+            let deadline = Instant::now() + timeout;
+            loop {
+                let info = Self::map_point_error(
+                    self.client
+                        .collection_info(self.server_collection(collection))
+                        .await,
+                    collection,
+                    "Collection info failed",
+                )?;
+
+                let status = info
+                    .result
+                    .and_then(|result| CollectionStatus::try_from(result.status).ok())
+                    .unwrap_or(CollectionStatus::UnknownCollectionStatus);
+                if status == CollectionStatus::Green {
+                    return Ok(());
+                }
+
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(qdrant_errors::collection_not_ready(
+                        collection,
+                        status.as_str_name(),
+                    ));
+                }
+                tokio::time::sleep(READINESS_POLL_INTERVAL.min(deadline - now)).await;
+            }
+        })
+        .await
+    }
+
+    /// Check that a collection exists and reports `Green` status, e.g. for readiness probes
+    ///
+    /// Unlike `is_healthy`, which only checks the server, this returns `false` for a missing
+    /// collection or one that is still optimizing or in an error state.
+    pub async fn is_collection_healthy(&self, collection: &str) -> TylResult<bool> {
+        let context = format!("Checking health of collection '{collection}'");
+
+        self.with_telemetry("qdrant_is_collection_healthy", &context, async {
+            let exists = Self::map_qdrant_error(
+                self.client
+                    .collection_exists(self.server_collection(collection))
+                    .await,
+                "Collection exists check failed",
+            )?;
+            if !exists {
+                return Ok(false);
+            }
+
+            let info = Self::map_qdrant_error(
+                self.client
+                    .collection_info(self.server_collection(collection))
+                    .await,
+                "Collection info failed",
+            )?;
+            let status = info
+                .result
+                .and_then(|result| CollectionStatus::try_from(result.status).ok());
+            Ok(status == Some(CollectionStatus::Green))
+        })
+        .await
+    }
+}
+
+/// Qdrant-specific error helpers following TYL framework patterns
+pub mod qdrant_errors {
+    use super::*;
+
+    /// Create a Qdrant connection error
+    pub fn connection_failed(message: impl Into<String>) -> TylError {
+        let message = message.into();
+        vector_errors::connection_failed(format!("Qdrant: {message}"))
+    }
+
+    /// Create a Qdrant API error
+    pub fn api_error(message: impl Into<String>) -> TylError {
+        let message = message.into();
+        TylError::network(format!("Qdrant API error: {message}"))
+    }
+
+    /// Collection creation failed with specific reason
+    pub fn collection_creation_failed(name: &str, reason: impl Into<String>) -> TylError {
+        let reason = reason.into();
+        TylError::database(format!(
+            "Failed to create Qdrant collection '{name}': {reason}"
+        ))
+    }
+
+    /// Vector dimension mismatch error
+    pub fn vector_dimension_mismatch(expected: usize, actual: usize) -> TylError {
+        TylError::validation(
+            "vector_dimension",
+            format!("Expected {expected}, got {actual}"),
+        )
+    }
+
+    /// Index optimization failed
+    pub fn index_optimization_failed(collection: &str, reason: impl Into<String>) -> TylError {
+        let reason = reason.into();
+        TylError::database(format!(
+            "Index optimization failed for collection '{collection}': {reason}"
+        ))
+    }
+
+    /// Snapshot is not listed for the collection
+    pub fn snapshot_not_found(collection: &str, snapshot_name: &str) -> TylError {
+        TylError::not_found("snapshot", format!("{collection}/{snapshot_name}"))
+    }
+
+    /// Collection not ready for operations
+    pub fn collection_not_ready(collection: &str, status: &str) -> TylError {
+        TylError::database(format!(
+            "Collection '{collection}' is not ready (status: {status})"
+        ))
+    }
+
+    /// Batch operation validation error
+    pub fn batch_size_exceeded(size: usize, max_size: usize) -> TylError {
+        TylError::validation(
+            "batch_size",
+            format!("Size {size} exceeds maximum {max_size}"),
+        )
+    }
+
+    /// Point ID conversion error
+    pub fn invalid_point_id(id: &str, reason: impl Into<String>) -> TylError {
+        let reason = reason.into();
+        TylError::validation("point_id", format!("Invalid ID '{id}': {reason}"))
+    }
+
+    /// Import failed after `committed` vectors were already stored
+    pub fn import_interrupted(committed: usize, error: TylError) -> TylError {
+        TylError::database(format!(
+            "Import stopped after committing {committed} vectors: {error}"
+        ))
+    }
+
+    /// Search parameter validation error
+    pub fn invalid_search_params(reason: impl Into<String>) -> TylError {
+        TylError::validation("search_params", reason.into())
+    }
+
+    /// Multivector rows are malformed
+    pub fn invalid_multivector(reason: impl Into<String>) -> TylError {
+        TylError::validation("multivector", reason.into())
+    }
+
+    /// Collection options validation error
+    pub fn invalid_collection_options(reason: impl Into<String>) -> TylError {
+        TylError::validation("collection_options", reason.into())
+    }
+
+    /// The best search result scored below the caller's relevance floor
+    pub fn no_relevant_results(
+        collection: &str,
+        best_score: Option<f32>,
+        min_best_score: f32,
+    ) -> TylError {
+        let best = match best_score {
+            Some(score) => format!("best score {score}"),
+            None => "no results".to_string(),
+        };
+        TylError::not_found(
+            "relevant results",
+            format!("collection '{collection}' ({best}, required {min_best_score})"),
+        )
+    }
+
+    /// Operation tried to reach outside its tenant scope
+    pub fn tenant_scope_violation(tenant_key: &str, tenant_id: &str) -> TylError {
+        TylError::validation(
+            tenant_key,
+            format!("Operation is scoped to tenant '{tenant_id}' and cannot target another tenant"),
+        )
+    }
+
+    /// Vector metadata does not satisfy the collection's payload schema
+    pub fn payload_schema_violation(id: &str, key: &str, reason: impl Into<String>) -> TylError {
+        let reason = reason.into();
+        TylError::validation(
+            "payload_schema",
+            format!("Vector '{id}' field '{key}': {reason}"),
+        )
+    }
+
+    /// Metadata key uses the adapter's reserved payload namespace
+    pub fn reserved_payload_key(id: &str, key: &str, prefix: &str) -> TylError {
+        TylError::validation(
+            "metadata",
+            format!("Vector '{id}' key '{key}' uses the reserved payload prefix '{prefix}'"),
+        )
+    }
+
+    /// Collection cannot be copied by `copy_collection`
+    pub fn collection_not_copyable(collection: &str, reason: &str) -> TylError {
+        TylError::validation(
+            "collection",
+            format!("Collection '{collection}' cannot be copied: {reason}"),
+        )
+    }
+
+    /// Unknown distance metric name
+    pub fn invalid_distance_metric(value: &str) -> TylError {
+        TylError::validation(
+            "distance_metric",
+            format!(
+                "Unknown distance metric '{value}', expected cosine, euclidean, dot or manhattan"
+            ),
+        )
+    }
+
+    /// Live collection configuration differs from the expected one
+    pub fn collection_schema_mismatch(collection: &str, mismatches: &[String]) -> TylError {
+        TylError::validation(
+            "collection_schema",
+            format!(
+                "Collection '{collection}' does not match the expected schema: {}",
+                mismatches.join("; ")
+            ),
+        )
+    }
+
+    /// Collection distance metric differs from the one the caller expected
+    pub fn distance_metric_mismatch(collection: &str, expected: &str, actual: &str) -> TylError {
+        TylError::validation(
+            "distance_metric",
+            format!("Collection '{collection}' uses {actual}, expected {expected}"),
+        )
+    }
+}
+
+// Mock implementation for testing
+#[cfg(feature = "mock")]
+pub mod mock;
+
+#[cfg(feature = "mock")]
+pub use mock::MockQdrantAdapter;
+
+// Batching writer for streaming ingestion
+pub mod buffered_writer;
+
+pub use buffered_writer::BufferedVectorWriter;
+
+// Fail-fast protection while Qdrant is unavailable
+pub mod circuit_breaker;
+
+use circuit_breaker::CircuitBreaker;
+pub use circuit_breaker::CircuitState;
+
+// Background health polling for long-lived services
+pub mod health_monitor;
+
+pub use health_monitor::{HealthHandle, HealthMonitor, HealthSnapshot};
+
+// Parent span propagation into adapter telemetry
+pub mod trace_context;
+
+pub use trace_context::with_parent_span;
+
+// Tenant isolation for multitenant collections
+pub mod tenant;
+
+pub use tenant::{TenantScopedStore, VectorFilterDelete};
+
+// Schema migration tools with Pact.io validation
+#[cfg(feature = "schema-migration")]
+pub mod migration;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qdrant_config_creation() {
+        let config = QdrantConfig::default();
+        assert_eq!(config.url, "http://localhost:6333");
+        assert_eq!(config.timeout_seconds, 30);
+        assert_eq!(config.max_batch_size, 100);
+        assert!(config.enable_compression);
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_qdrant_config_validation() {
+        let mut config = QdrantConfig::default();
+        assert!(config.validate().is_ok());
+
+        // Test empty URL
+        config.url = String::new();
+        assert!(config.validate().is_err());
+
+        // Failover URLs replace the single URL but cannot contain empty entries
+        config.urls = vec!["http://node-1:6334".to_string()];
+        assert!(config.validate().is_ok());
+        config.urls.push(String::new());
+        assert!(config.validate().is_err());
+        config.urls.clear();
+
+        // Test zero timeout
+        config.url = "http://localhost:6333".to_string();
+        config.timeout_seconds = 0;
+        assert!(config.validate().is_err());
+
+        // Test zero batch size
+        config.timeout_seconds = 30;
+        config.max_batch_size = 0;
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn test_endpoints_prefer_failover_urls() {
+        let mut config = QdrantConfig::default();
+        assert_eq!(config.endpoints(), vec!["http://localhost:6333"]);
+
+        config.urls = vec![
+            "http://node-1:6334".to_string(),
+            "http://node-2:6334".to_string(),
+        ];
+        assert_eq!(
+            config.endpoints(),
+            vec!["http://node-1:6334", "http://node-2:6334"]
+        );
+    }
+
+    #[cfg(feature = "logging")]
+    struct CapturingLogger(std::sync::Arc<std::sync::Mutex<Vec<String>>>);
+
+    #[cfg(feature = "logging")]
+    impl Logger for CapturingLogger {
+        fn log(&self, record: &LogRecord) {
+            self.0.lock().unwrap().push(format!("{record:?}"));
+        }
+    }
+
+    /// Adapter for an unreachable server whose log records are captured as `Debug` strings
+    #[cfg(feature = "logging")]
+    fn capturing_adapter(
+        config: QdrantConfig,
+    ) -> (QdrantAdapter, std::sync::Arc<std::sync::Mutex<Vec<String>>>) {
+        // Nothing listens on port 1, so requests fail after their start record is logged
+        let config = QdrantConfig {
+            url: "http://127.0.0.1:1".to_string(),
+            timeout_seconds: 1,
+            ..config
+        };
+        let client = Qdrant::from_url(&config.url)
+            .skip_compatibility_check()
+            .build()
+            .unwrap();
+        let mut adapter = QdrantAdapter::from_client(client, config);
+        let records = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        adapter.logger = Box::new(CapturingLogger(std::sync::Arc::clone(&records)));
+        (adapter, records)
+    }
+
+    #[cfg(feature = "logging")]
+    #[tokio::test]
+    async fn test_store_vector_with_request_id_logs_request_id() {
+        let (adapter, records) = capturing_adapter(QdrantConfig::default());
+
+        let vector = Vector::new("1".to_string(), vec![1.0, 0.0]);
+        let result = adapter
+            .store_vector_with_request_id("docs", vector, "req-7f3a")
+            .await;
+        assert!(result.is_err());
+
+        // Both the start and the failure record carry the request ID
+        let records = records.lock().unwrap();
+        let tagged = records
+            .iter()
+            .filter(|record| record.contains("request_id=req-7f3a"))
+            .count();
+        assert_eq!(tagged, 2, "{records:?}");
+    }
+
+    #[cfg(feature = "tracing")]
+    struct CapturingTracer(std::sync::Arc<std::sync::Mutex<Vec<(String, Option<String>)>>>);
+
+    #[cfg(feature = "tracing")]
+    impl TracingManager for CapturingTracer {
+        fn start_span(
+            &self,
+            operation_name: &str,
+            parent_span_id: Option<String>,
+        ) -> TylResult<String> {
+            let mut spans = self.0.lock().unwrap();
+            spans.push((operation_name.to_string(), parent_span_id));
+            Ok(format!("span-{}", spans.len()))
+        }
+
+        fn end_span(&self, _span_id: String) -> TylResult<()> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    #[tokio::test]
+    async fn test_spans_use_the_parent_span_in_scope() {
+        let client = Qdrant::from_url("http://127.0.0.1:1")
+            .skip_compatibility_check()
+            .build()
+            .unwrap();
+        let mut adapter = QdrantAdapter::from_client(client, QdrantConfig::default());
+        let spans = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        adapter.tracer = Box::new(CapturingTracer(std::sync::Arc::clone(&spans)));
+
+        let traced =
+            |operation: &'static str| adapter.with_telemetry(operation, "", async { Ok(()) });
+        traced("qdrant_root").await.unwrap();
+        with_parent_span("request-span-42", async {
+            traced("qdrant_child").await.unwrap();
+        })
+        .await;
+
+        let spans = spans.lock().unwrap();
+        assert_eq!(
+            *spans,
+            vec![
+                ("qdrant_root".to_string(), None),
+                (
+                    "qdrant_child".to_string(),
+                    Some("request-span-42".to_string())
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_debug_output_redacts_api_key() {
+        let config = QdrantConfig {
+            api_key: Some("s3cr3t-key".to_string()),
+            ..QdrantConfig::default()
+        };
+        let debug = format!("{config:?}");
+        assert!(!debug.contains("s3cr3t-key"), "{debug}");
+        assert!(debug.contains(r#"api_key: Some("***")"#), "{debug}");
+        assert!(debug.contains("http://localhost:6333"), "{debug}");
+
+        let without_key = format!("{:?}", QdrantConfig::default());
+        assert!(without_key.contains("api_key: None"), "{without_key}");
+
+        let client = Qdrant::from_url(&config.url)
+            .skip_compatibility_check()
+            .build()
+            .unwrap();
+        let adapter = QdrantAdapter::from_client(client, config);
+        for output in [
+            format!("{adapter:?}"),
+            format!("{adapter:#?}"),
+            adapter.to_string(),
+        ] {
+            assert!(!output.contains("s3cr3t-key"), "{output}");
+            assert!(output.contains("http://localhost:6333"), "{output}");
+        }
+    }
+
+    #[cfg(feature = "cluster-info")]
+    #[test]
+    fn test_parse_cluster_info() {
+        let body = serde_json::json!({
+            "result": {
+                "status": "enabled",
+                "peer_id": 2,
+                "peers": {
+                    "2": {"uri": "http://qdrant-1:6335/"},
+                    "1": {"uri": "http://qdrant-0:6335/"}
+                },
+                "raft_info": {
+                    "term": 4,
+                    "commit": 120,
+                    "pending_operations": 0,
+                    "leader": 1,
+                    "role": "Follower",
+                    "is_voter": true
+                },
+                "consensus_thread_status": {"consensus_thread_status": "working"},
+                "message_send_failures": {}
+            },
+            "status": "ok",
+            "time": 0.0001
+        });
+        let info = QdrantAdapter::parse_cluster_info(&body).unwrap();
+        assert!(info.enabled);
+        assert_eq!(info.peer_id, Some(2));
+        assert_eq!(
+            info.peers.iter().map(|peer| peer.id).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(info.peers[0].uri, "http://qdrant-0:6335/");
+        assert_eq!(info.raft_term, Some(4));
+        assert_eq!(info.raft_commit, Some(120));
+        assert_eq!(info.pending_operations, Some(0));
+        assert_eq!(info.leader, Some(1));
+        assert_eq!(info.role.as_deref(), Some("Follower"));
+        assert_eq!(info.consensus_status.as_deref(), Some("working"));
+
+        // Without distributed mode only the status is reported
+        let single = serde_json::json!({"result": {"status": "disabled"}, "status": "ok"});
+        assert_eq!(
+            QdrantAdapter::parse_cluster_info(&single).unwrap(),
+            ClusterInfo::default()
+        );
+
+        assert!(QdrantAdapter::parse_cluster_info(&serde_json::json!({"status": "ok"})).is_err());
+
+        let telemetry = serde_json::json!({
+            "result": {
+                "collections": {
+                    "number_of_collections": 2,
+                    "collections": [
+                        {"id": "docs", "shards": [{"local": {"segments": [
+                            {"info": {"disk_usage_bytes": 1000, "ram_usage_bytes": 10}},
+                            {"info": {"disk_usage_bytes": 24}}
+                        ]}}]},
+                        {"id": "notes", "shards": [{"local": {"segments": [
+                            {"info": {"disk_usage_bytes": 2000}}
+                        ]}}]}
+                    ]
+                }
+            }
+        });
+        assert_eq!(QdrantAdapter::parse_disk_usage(&telemetry), Some(3024));
+        let without_segments = serde_json::json!({"result": {"collections": {}}});
+        assert_eq!(QdrantAdapter::parse_disk_usage(&without_segments), None);
+    }
+
+    #[test]
+    fn test_rest_api_url() {
+        let mut config = QdrantConfig {
+            url: "http://qdrant:6334/".to_string(),
+            ..QdrantConfig::default()
+        };
+        assert_eq!(config.rest_api_url(), "http://qdrant:6333");
+
+        config.url = "https://cluster.cloud.qdrant.io".to_string();
+        assert_eq!(config.rest_api_url(), "https://cluster.cloud.qdrant.io");
+
+        config.rest_url = Some("http://qdrant-rest:8080/".to_string());
+        assert_eq!(config.rest_api_url(), "http://qdrant-rest:8080");
+    }
+
+    #[tokio::test]
+    async fn test_download_snapshot_file_writes_streamed_bytes() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let snapshot = b"snapshot-bytes".repeat(1024);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let rest_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn({
+            let snapshot = snapshot.clone();
+            async move {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = vec![0; 4096];
+                let read = socket.read(&mut request).await.unwrap();
+                let header = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                    snapshot.len()
+                );
+                socket.write_all(header.as_bytes()).await.unwrap();
+                socket.write_all(&snapshot).await.unwrap();
+                String::from_utf8_lossy(&request[..read]).into_owned()
+            }
+        });
+
+        let client = Qdrant::from_url("http://127.0.0.1:6334")
+            .skip_compatibility_check()
+            .build()
+            .unwrap();
+        let dir = std::env::temp_dir().join(format!("tyl-snapshot-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dest = dir.join("docs.snapshot");
+
+        let size = QdrantAdapter::download_snapshot_file(
+            &client,
+            &rest_url,
+            "docs",
+            "docs-2024.snapshot",
+            &dest,
+        )
+        .await
+        .unwrap();
+
+        let request = server.await.unwrap();
+        assert!(
+            request.starts_with("GET /collections/docs/snapshots/docs-2024.snapshot "),
+            "{request}"
+        );
+        assert_eq!(size, snapshot.len() as u64);
+        assert_eq!(std::fs::read(&dest).unwrap(), snapshot);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_failover_uses_first_reachable_endpoint() {
+        let endpoints = vec![
+            "http://down:6334".to_string(),
+            "http://up-1:6334".to_string(),
+            "http://up-2:6334".to_string(),
+        ];
+        let mut attempts = Vec::new();
+        let (url, connection) = QdrantAdapter::connect_with_failover(&endpoints, |url| {
+            attempts.push(url.clone());
+            async move {
+                if url.contains("down") {
+                    Err(vector_errors::connection_failed("refused"))
+                } else {
+                    Ok(url.len())
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(url, "http://up-1:6334");
+        assert_eq!(connection, url.len());
+        assert_eq!(attempts, vec!["http://down:6334", "http://up-1:6334"]);
+
+        let error = QdrantAdapter::connect_with_failover(&endpoints[..1], |_| async {
+            Err::<(), _>(vector_errors::connection_failed("refused"))
+        })
+        .await
+        .unwrap_err();
+        assert!(error.to_string().contains("http://down:6334"));
+    }
+
+    #[test]
+    fn test_distance_metric_from_str() {
+        let accepted = [
+            ("cosine", Distance::Cosine),
+            ("Cosine", Distance::Cosine),
+            ("euclidean", Distance::Euclid),
+            ("EUCLIDEAN", Distance::Euclid),
+            ("dot", Distance::Dot),
+            ("DotProduct", Distance::Dot),
+            ("dotproduct", Distance::Dot),
+            ("manhattan", Distance::Manhattan),
+            (" Manhattan ", Distance::Manhattan),
+        ];
+        for (text, expected) in accepted {
+            let metric = QdrantAdapter::distance_metric_from_str(text).unwrap();
+            assert_eq!(QdrantAdapter::distance_metric_to_qdrant(&metric), expected);
+        }
+
+        let error = QdrantAdapter::distance_metric_from_str("hamming").unwrap_err();
+        assert!(error.to_string().contains("hamming"));
+    }
+
+    #[test]
+    fn test_distance_metric_conversion() {
+        assert_eq!(
+            QdrantAdapter::distance_metric_to_qdrant(&DistanceMetric::Cosine),
+            qdrant_client::qdrant::Distance::Cosine
+        );
+        assert_eq!(
+            QdrantAdapter::distance_metric_to_qdrant(&DistanceMetric::Euclidean),
+            qdrant_client::qdrant::Distance::Euclid
+        );
+        assert_eq!(
+            QdrantAdapter::distance_metric_to_qdrant(&DistanceMetric::DotProduct),
+            qdrant_client::qdrant::Distance::Dot
+        );
+        assert_eq!(
+            QdrantAdapter::distance_metric_to_qdrant(&DistanceMetric::Manhattan),
+            qdrant_client::qdrant::Distance::Manhattan
+        );
+    }
+
+    #[test]
+    fn test_vector_to_point_conversion() {
+        let mut vector = Vector::new("550e8400-e29b-41d4-a716-446655440000", vec![0.1, 0.2, 0.3]);
+        vector.add_metadata("category", serde_json::json!("test"));
+
+        let point = QdrantAdapter::vector_to_point_struct(vector.clone(), &QdrantConfig::default())
+            .unwrap();
+
+        // Verify the conversion worked (basic checks without deep inspection)
+        assert!(!point.payload.is_empty());
+        assert!(point.payload.contains_key("category"));
+    }
+
+    #[test]
+    fn test_point_id_validation() {
+        use qdrant_client::qdrant::point_id::PointIdOptions;
+
+        // Valid UUID
+        let uuid_id = "550e8400-e29b-41d4-a716-446655440000";
+        let point_id = QdrantAdapter::parse_point_id(uuid_id).unwrap();
+        assert_eq!(
+            point_id.point_id_options,
+            Some(PointIdOptions::Uuid(uuid_id.to_string()))
+        );
+
+        // Valid integer string is sent as a numeric ID
+        let point_id = QdrantAdapter::parse_point_id("42").unwrap();
+        assert_eq!(point_id.point_id_options, Some(PointIdOptions::Num(42)));
+
+        // Arbitrary strings are rejected before reaching Qdrant
+        let error = QdrantAdapter::parse_point_id("doc_1").unwrap_err();
+        assert!(error.to_string().contains("Invalid ID 'doc_1'"));
+
+        let vector = Vector::new("doc_1", vec![0.1, 0.2, 0.3]);
+        assert!(QdrantAdapter::vector_to_point_struct(vector, &QdrantConfig::default()).is_err());
+    }
+
+    #[test]
+    fn test_point_ids_round_trip_unchanged() {
+        use qdrant_client::qdrant::point_id::PointIdOptions;
+
+        let config = QdrantConfig::default();
+        for id in [
+            "42",
+            "0",
+            "18446744073709551615",
+            "550e8400-e29b-41d4-a716-446655440000",
+        ] {
+            let restored = round_trip(Vector::new(id, vec![0.1, 0.2]), &config);
+            assert_eq!(restored.id, id);
+            assert!(!restored.metadata.contains_key(ORIGINAL_ID_PAYLOAD_KEY));
+        }
+
+        // Canonical integer IDs are stored as numeric point IDs without an ID payload
+        let point =
+            QdrantAdapter::vector_to_point_struct(Vector::new("42", vec![0.1]), &config).unwrap();
+        assert_eq!(
+            point.id.unwrap().point_id_options,
+            Some(PointIdOptions::Num(42))
+        );
+        assert!(!point.payload.contains_key(ORIGINAL_ID_PAYLOAD_KEY));
+
+        // Non-canonical spellings map to the same point but keep the caller's spelling
+        for id in ["042", "550E8400-E29B-41D4-A716-446655440000"] {
+            let restored = round_trip(Vector::new(id, vec![0.1, 0.2]), &config);
+            assert_eq!(restored.id, id);
+        }
+        assert_eq!(
+            QdrantAdapter::parse_point_id("550E8400-E29B-41D4-A716-446655440000")
+                .unwrap()
+                .point_id_options,
+            Some(PointIdOptions::Uuid(
+                "550e8400-e29b-41d4-a716-446655440000".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_string_id_hashing_is_stable() {
+        let first = QdrantAdapter::hash_string_id("doc_1");
+        let second = QdrantAdapter::hash_string_id("doc_1");
+        assert_eq!(first, second);
+        assert_eq!(first.get_version_num(), 5);
+        assert_ne!(first, QdrantAdapter::hash_string_id("doc_2"));
+
+        let config = QdrantConfig {
+            hash_string_ids: true,
+            ..QdrantConfig::default()
+        };
+        let point_id = QdrantAdapter::resolve_point_id("doc_1", &config).unwrap();
+        assert_eq!(point_id, PointId::from(first.to_string()));
+
+        // UUIDs and integers are never hashed
+        let point_id = QdrantAdapter::resolve_point_id("42", &config).unwrap();
+        assert_eq!(point_id, PointId::from(42u64));
+    }
+
+    /// Convert a vector to a point and back, as a store followed by a retrieve would
+    fn round_trip(vector: Vector, config: &QdrantConfig) -> Vector {
+        use qdrant_client::qdrant::{vectors_output::VectorsOptions, ScoredPoint, VectorsOutput};
+
+        let embedding = vector.embedding.clone();
+        let point = QdrantAdapter::vector_to_point_struct(vector, config).unwrap();
+        let scored_point = ScoredPoint {
+            id: point.id,
+            payload: point.payload,
+            vectors: Some(VectorsOutput {
+                vectors_options: Some(VectorsOptions::Vector(
+                    qdrant_client::qdrant::VectorOutput {
+                        data: embedding,
+                        ..Default::default()
+                    },
+                )),
+            }),
+            ..Default::default()
+        };
+
+        QdrantAdapter::point_to_vector(scored_point).unwrap()
+    }
+
+    #[test]
+    fn test_hashed_string_id_round_trip() {
+        let config = QdrantConfig {
+            hash_string_ids: true,
+            ..QdrantConfig::default()
+        };
+        let mut vector = Vector::new("doc_1", vec![0.1, 0.2, 0.3]);
+        vector.add_metadata("category", serde_json::json!("test"));
+
+        let point = QdrantAdapter::vector_to_point_struct(vector.clone(), &config).unwrap();
+        assert!(point.payload.contains_key(ORIGINAL_ID_PAYLOAD_KEY));
+
+        let restored = round_trip(vector, &config);
+        assert_eq!(restored.id, "doc_1");
+        assert_eq!(
+            restored.metadata.get("category"),
+            Some(&serde_json::json!("test"))
+        );
+        assert!(!restored.metadata.contains_key(ORIGINAL_ID_PAYLOAD_KEY));
+    }
+
+    #[test]
+    fn test_array_and_nested_metadata_round_trip() {
+        let tags = serde_json::json!(["rust", "vectors", 42, true]);
+        let author = serde_json::json!({
+            "name": "Ada",
+            "stats": {"posts": 12, "rating": 4.5},
+            "aliases": ["countess", "analyst"]
+        });
+
+        let mut vector = Vector::new("550e8400-e29b-41d4-a716-446655440000", vec![0.1, 0.2]);
+        vector.add_metadata("tags", tags.clone());
+        vector.add_metadata("author", author.clone());
+
+        let restored = round_trip(vector, &QdrantConfig::default());
+        assert_eq!(restored.metadata.get("tags"), Some(&tags));
+        assert_eq!(restored.metadata.get("author"), Some(&author));
+    }
+
+    #[test]
+    fn test_null_metadata_round_trip() {
+        let mut vector = Vector::new("550e8400-e29b-41d4-a716-446655440000", vec![0.1, 0.2]);
+        vector.add_metadata("deleted_at", serde_json::Value::Null);
+        vector.add_metadata("history", serde_json::json!([null, "v1"]));
+
+        let restored = round_trip(vector, &QdrantConfig::default());
+        assert_eq!(
+            restored.metadata.get("deleted_at"),
+            Some(&serde_json::Value::Null)
+        );
+        assert_eq!(
+            restored.metadata.get("history"),
+            Some(&serde_json::json!([null, "v1"]))
+        );
+    }
+
+    #[test]
+    fn test_large_u64_metadata_is_not_dropped() {
+        let large = u64::MAX - 1;
+        let mut vector = Vector::new("550e8400-e29b-41d4-a716-446655440000", vec![0.1, 0.2]);
+        vector.add_metadata("timestamp_ns", serde_json::json!(large));
+
+        let point = QdrantAdapter::vector_to_point_struct(vector.clone(), &QdrantConfig::default())
+            .unwrap();
+        assert!(point.payload.contains_key("timestamp_ns"));
+
+        let restored = round_trip(vector, &QdrantConfig::default());
+        let value = restored
+            .metadata
+            .get("timestamp_ns")
+            .and_then(|v| v.as_f64())
+            .expect("large u64 should be kept as a number");
+        assert_eq!(value, large as f64);
+    }
+
+    #[test]
+    fn test_is_null_filter_condition() {
+        use qdrant_client::qdrant::condition::ConditionOneOf;
+
+        let params = SearchParams::with_limit(10)
+            .with_filter("deleted_at", serde_json::json!({"$is_null": true}));
+        let filter = QdrantAdapter::build_filter(&params).unwrap();
+        assert!(matches!(
+            &filter.must[0].condition_one_of,
+            Some(ConditionOneOf::IsNull(condition)) if condition.key == "deleted_at"
+        ));
+
+        let params = SearchParams::with_limit(10)
+            .with_filter("deleted_at", serde_json::json!({"$is_null": false}));
+        let filter = QdrantAdapter::build_filter(&params).unwrap();
+        assert!(matches!(
+            &filter.must[0].condition_one_of,
+            Some(ConditionOneOf::Filter(nested)) if nested.must_not.len() == 1
+        ));
+    }
+
+    #[test]
+    fn test_has_id_filter_condition() {
+        use qdrant_client::qdrant::condition::ConditionOneOf;
+
+        let obj = serde_json::json!({"$has_id": ["42", "550E8400-E29B-41D4-A716-446655440000", 7]});
+        let condition =
+            QdrantAdapter::build_has_id_condition("id", obj.as_object().unwrap()).unwrap();
+        let Some(ConditionOneOf::HasId(condition)) = condition.condition_one_of else {
+            panic!("expected a HasId condition");
+        };
+        assert_eq!(
+            condition.has_id,
+            vec![
+                PointId::from(42u64),
+                PointId::from("550e8400-e29b-41d4-a716-446655440000".to_string()),
+                PointId::from(7u64),
+            ]
+        );
+
+        // String IDs resolve to the same point IDs they are stored under when hashed
+        let obj = serde_json::json!({"$has_id": ["doc-1"]});
+        let condition =
+            QdrantAdapter::build_has_id_condition("id", obj.as_object().unwrap()).unwrap();
+        let config = QdrantConfig {
+            hash_string_ids: true,
+            ..QdrantConfig::default()
+        };
+        assert!(matches!(
+            condition.condition_one_of,
+            Some(ConditionOneOf::HasId(condition))
+                if condition.has_id == vec![QdrantAdapter::resolve_point_id("doc-1", &config).unwrap()]
+        ));
+
+        // Used through build_filter alongside payload conditions
+        let params = SearchParams::with_limit(10)
+            .with_filter("id", serde_json::json!({"$has_id": ["1", "2"]}))
+            .with_filter("lang", serde_json::json!("en"));
+        let filter = QdrantAdapter::build_filter(&params).unwrap();
+        assert_eq!(filter.must.len(), 2);
+        assert!(filter.must.iter().any(|condition| matches!(
+            &condition.condition_one_of,
+            Some(ConditionOneOf::HasId(condition)) if condition.has_id.len() == 2
+        )));
+
+        for invalid in [
+            serde_json::json!({"$has_id": []}),
+            serde_json::json!({"$has_id": "1"}),
+            serde_json::json!({"$has_id": [true]}),
+            serde_json::json!({"$has_id": [-1]}),
+        ] {
+            assert!(
+                QdrantAdapter::build_has_id_condition("id", invalid.as_object().unwrap()).is_err(),
+                "{invalid} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_text_match_filter_condition() {
+        use qdrant_client::qdrant::{condition::ConditionOneOf, r#match::MatchValue};
+
+        let params = SearchParams::with_limit(10).with_filter(
+            "description",
+            serde_json::json!({"$text": "wireless headphones"}),
+        );
+        let filter = QdrantAdapter::build_filter(&params).unwrap();
+        assert!(matches!(
+            &filter.must[0].condition_one_of,
+            Some(ConditionOneOf::Field(condition))
+                if condition.key == "description"
+                    && matches!(
+                        condition.r#match.as_ref().and_then(|m| m.match_value.as_ref()),
+                        Some(MatchValue::Text(text)) if text == "wireless headphones"
+                    )
+        ));
+
+        for invalid in [
+            serde_json::json!({"$text": ""}),
+            serde_json::json!({"$text": 5}),
+        ] {
+            assert!(
+                QdrantAdapter::build_text_match_condition(
+                    "description",
+                    invalid.as_object().unwrap()
+                )
+                .is_err(),
+                "{invalid} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_empty_filter_condition() {
+        use qdrant_client::qdrant::condition::ConditionOneOf;
+
+        let params = SearchParams::with_limit(10)
+            .with_filter("tags", serde_json::json!({"$is_empty": true}));
+        let filter = QdrantAdapter::build_filter(&params).unwrap();
+        assert!(matches!(
+            &filter.must[0].condition_one_of,
+            Some(ConditionOneOf::IsEmpty(condition)) if condition.key == "tags"
+        ));
+
+        let params = SearchParams::with_limit(10)
+            .with_filter("tags", serde_json::json!({"$is_empty": false}));
+        let filter = QdrantAdapter::build_filter(&params).unwrap();
+        assert!(matches!(
+            &filter.must[0].condition_one_of,
+            Some(ConditionOneOf::Filter(nested)) if nested.must_not.len() == 1
+        ));
+    }
+
+    #[test]
+    fn test_any_of_filter_conditions() {
+        use qdrant_client::qdrant::condition::ConditionOneOf;
+
+        let should_len = |filter: &Filter, key: &str| {
+            filter
+                .must
+                .iter()
+                .find_map(|condition| match &condition.condition_one_of {
+                    Some(ConditionOneOf::Filter(nested))
+                        if nested.should.iter().all(|c| {
+                            matches!(
+                                &c.condition_one_of,
+                                Some(ConditionOneOf::Field(field)) if field.key == key
+                            )
+                        }) =>
+                    {
+                        Some(nested.should.len())
+                    }
+                    _ => None,
+                })
+        };
+
+        // Typed multi-value filter is AND-ed with the other keys
+        let params = SearchParams::with_limit(10)
+            .with_any_of("status", ["published", "review"])
+            .with_filter("lang", serde_json::json!("en"));
+        let filter = QdrantAdapter::build_filter(&params).unwrap();
+        assert_eq!(filter.must.len(), 2);
+        assert_eq!(should_len(&filter, "status"), Some(2));
+
+        // $in now uses every listed value, not just the first
+        let params = SearchParams::with_limit(10)
+            .with_filter("year", serde_json::json!({"$in": [2022, 2023, 2024]}));
+        let filter = QdrantAdapter::build_filter(&params).unwrap();
+        assert_eq!(should_len(&filter, "year"), Some(3));
+
+        assert!(QdrantAdapter::build_any_of_condition("status", &[]).is_err());
+    }
+
+    #[test]
+    fn test_validate_filter_reports_untranslatable_entries() {
+        let valid = SearchParams::with_limit(10)
+            .with_filter("lang", serde_json::json!("en"))
+            .with_filter("year", serde_json::json!({"$gte": 2020, "$lt": 2025}))
+            .with_any_of("status", ["published", "review"]);
+        assert!(QdrantAdapter::validate_filter(&valid).is_ok());
+
+        let malformed = SearchParams::with_limit(10)
+            .with_filter("lang", serde_json::json!("en"))
+            .with_filter("year", serde_json::json!({"$gte": "2020"}))
+            .with_filter("status", serde_json::json!({"$in": []}))
+            .with_filter("title", serde_json::json!({"$regex": "^Intro"}))
+            .with_filter("owner", serde_json::json!(null));
+        let message = QdrantAdapter::validate_filter(&malformed)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("4 filter entries"), "{message}");
+        assert!(message.contains("range bound $gte on 'year'"), "{message}");
+        assert!(message.contains("filter on 'status'"), "{message}");
+        assert!(message.contains("$regex"), "{message}");
+        assert!(message.contains("'owner' is null"), "{message}");
+        assert!(!message.contains("'lang'"), "{message}");
+
+        // The valid entry is still applied when the filter is built
+        let filter = QdrantAdapter::build_filter(&malformed).unwrap();
+        assert_eq!(filter.must.len(), 1);
+    }
+
+    #[test]
+    fn test_strict_filter_rejects_unknown_operators() {
+        let typo = SearchParams::with_limit(10)
+            .with_filter("price", serde_json::json!({"$gte": 10, "$ltee": 20}))
+            .with_filter("lang", serde_json::json!("en"));
+        assert_eq!(
+            QdrantAdapter::unknown_filter_operators(&typo),
+            vec!["price.$ltee".to_string()]
+        );
+        // The typo'd bound is silently dropped from the built filter, so only strict mode catches it
+        assert!(QdrantAdapter::validate_filter(&typo).is_ok());
+        let message = QdrantAdapter::check_strict_filter(&typo)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("price.$ltee"), "{message}");
+
+        let skipped =
+            SearchParams::with_limit(10).with_filter("status", serde_json::json!({"$ne": "draft"}));
+        assert!(QdrantAdapter::unknown_filter_operators(&skipped).is_empty());
+        assert!(QdrantAdapter::check_strict_filter(&skipped).is_err());
+
+        let valid = SearchParams::with_limit(10)
+            .with_filter("price", serde_json::json!({"$gte": 10, "$lt": 20}))
+            .with_filter("tags", serde_json::json!({"$in": ["a", "b"]}));
+        assert!(QdrantAdapter::check_strict_filter(&valid).is_ok());
+    }
+
+    #[test]
+    fn test_filter_matches_evaluates_each_operator() {
+        let mut vector = Vector::new("doc".to_string(), vec![1.0, 0.0]);
+        vector.add_metadata("lang", serde_json::json!("en"));
+        vector.add_metadata("year", serde_json::json!(2023));
+        vector.add_metadata("published", serde_json::json!(true));
+        vector.add_metadata("tags", serde_json::json!(["rust", "search"]));
+        vector.add_metadata("deleted_at", serde_json::json!(null));
+
+        let matches = |field: &str, value: serde_json::Value| {
+            let params = SearchParams::with_limit(10).with_filter(field, value);
+            QdrantAdapter::filter_matches(&params, &vector)
+        };
+
+        // Exact match, including any element of an array payload
+        assert!(matches("lang", serde_json::json!("en")));
+        assert!(!matches("lang", serde_json::json!("de")));
+        assert!(matches("year", serde_json::json!(2023)));
+        assert!(matches("year", serde_json::json!(2023.7)));
+        assert!(!matches("year", serde_json::json!(2024)));
+        assert!(matches("published", serde_json::json!(true)));
+        assert!(!matches("published", serde_json::json!(false)));
+        assert!(matches("tags", serde_json::json!("rust")));
+        assert!(!matches("tags", serde_json::json!("python")));
+        assert!(!matches("missing", serde_json::json!("en")));
+
+        // Ranges
+        assert!(matches(
+            "year",
+            serde_json::json!({"$gte": 2023, "$lt": 2024})
+        ));
+        assert!(!matches("year", serde_json::json!({"$gt": 2023})));
+        assert!(matches("year", serde_json::json!({"$lte": 2023})));
+        assert!(!matches("year", serde_json::json!({"$lt": 2023})));
+        assert!(!matches("lang", serde_json::json!({"$gte": 0})));
+
+        // $in and array values
+        assert!(matches("lang", serde_json::json!({"$in": ["en", "de"]})));
+        assert!(!matches("lang", serde_json::json!({"$in": ["fr", "de"]})));
+        assert!(matches("tags", serde_json::json!(["python", "search"])));
+        assert!(!matches("tags", serde_json::json!(["python", "go"])));
+
+        // $is_null and $exists
+        assert!(matches("deleted_at", serde_json::json!({"$is_null": true})));
+        assert!(!matches("lang", serde_json::json!({"$is_null": true})));
+        assert!(matches("lang", serde_json::json!({"$is_null": false})));
+        assert!(matches("lang", serde_json::json!({"$exists": true})));
+        assert!(!matches("deleted_at", serde_json::json!({"$exists": true})));
+        assert!(!matches("missing", serde_json::json!({"$exists": true})));
+        assert!(matches("missing", serde_json::json!({"$exists": false})));
+
+        // $is_empty
+        assert!(matches("missing", serde_json::json!({"$is_empty": true})));
+        assert!(matches(
+            "deleted_at",
+            serde_json::json!({"$is_empty": true})
+        ));
+        assert!(!matches("tags", serde_json::json!({"$is_empty": true})));
+        assert!(matches("tags", serde_json::json!({"$is_empty": false})));
+
+        // $text needs every query word, case-insensitively
+        assert!(matches("lang", serde_json::json!({"$text": "EN"})));
+        assert!(matches("tags", serde_json::json!({"$text": "search"})));
+        assert!(!matches(
+            "tags",
+            serde_json::json!({"$text": "rust search"})
+        ));
+        assert!(!matches("year", serde_json::json!({"$text": "2023"})));
+
+        // $has_id checks the vector's ID, whatever the field name
+        assert!(matches(
+            "id",
+            serde_json::json!({"$has_id": ["doc", "other"]})
+        ));
+        assert!(!matches("id", serde_json::json!({"$has_id": ["other"]})));
+
+        // Entries Qdrant cannot apply are skipped, as they are left out of the built filter
+        assert!(matches("lang", serde_json::json!({"$ne": "en"})));
+        assert!(matches("lang", serde_json::json!({"$regex": "^d"})));
+
+        // Every entry must match
+        let params = SearchParams::with_limit(10)
+            .with_filter("lang", serde_json::json!("en"))
+            .with_filter("year", serde_json::json!({"$gte": 2024}));
+        assert!(!QdrantAdapter::filter_matches(&params, &vector));
+        assert!(QdrantAdapter::filter_matches(
+            &SearchParams::with_limit(10),
+            &vector
+        ));
+    }
+
+    #[test]
+    fn test_malformed_range_filter_is_recorded_as_skipped() {
+        let params = SearchParams::with_limit(10)
+            .with_filter("lang", serde_json::json!("en"))
+            .with_filter("price", serde_json::json!({"$lte": "cheap"}));
+
+        let skipped = QdrantAdapter::skipped_filters(&params);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].0, "price");
+        assert!(skipped[0].1.to_string().contains("must be a number"));
+
+        let filter = QdrantAdapter::build_filter(&params).unwrap();
+        assert_eq!(filter.must.len(), 1);
+        assert!(QdrantAdapter::skipped_filters(&SearchParams::with_limit(10)).is_empty());
+    }
+
+    #[test]
+    fn test_facet_counts() {
+        let values = [
+            serde_json::json!("news"),
+            serde_json::json!("sports"),
+            serde_json::json!(["news", "tech"]),
+            serde_json::json!(null),
+            serde_json::json!("news"),
+            serde_json::json!(3),
+        ];
+
+        let mut counts = FacetCounts::default();
+        values.iter().for_each(|value| counts.add(value));
+        let facets = counts.top(10);
+        assert_eq!(facets[0], (serde_json::json!("news"), 3));
+        assert_eq!(facets.len(), 4);
+        assert!(facets.contains(&(serde_json::json!("tech"), 1)));
+        assert!(facets.contains(&(serde_json::json!(3), 1)));
+
+        let mut counts = FacetCounts::default();
+        values.iter().for_each(|value| counts.add(value));
+        assert_eq!(counts.top(1).len(), 1);
+    }
+
+    #[test]
+    fn test_facet_scroll_request_selects_only_the_field() {
+        let request = QdrantAdapter::build_facet_scroll_request("docs", "category", None, 50);
+        assert_eq!(request.limit, Some(50));
+        assert_eq!(
+            request.with_payload,
+            Some(WithPayloadSelector {
+                selector_options: Some(
+                    qdrant_client::qdrant::with_payload_selector::SelectorOptions::Include(
+                        qdrant_client::qdrant::PayloadIncludeSelector {
+                            fields: vec!["category".to_string()],
+                        },
+                    ),
+                ),
+            })
+        );
+        assert_eq!(
+            request.with_vectors,
+            Some(QdrantAdapter::vectors_selector(false))
+        );
+    }
+
+    #[test]
+    fn test_create_collection_request_overrides() {
+        let defaults = QdrantConfig::default();
+        let config = CollectionConfig::new("hot", 4, DistanceMetric::Cosine).unwrap();
+
+        let request = QdrantAdapter::build_create_collection_request(
+            &config,
+            &QdrantCollectionOptions::new(),
+            &defaults,
+        );
+        assert_eq!(request.shard_number, Some(defaults.default_shard_number));
+        assert_eq!(
+            request.replication_factor,
+            Some(defaults.default_replication_factor)
+        );
+
+        let options = QdrantCollectionOptions::new()
+            .with_shard_number(6)
+            .with_replication_factor(3);
+        let request = QdrantAdapter::build_create_collection_request(&config, &options, &defaults);
+        assert_eq!(request.shard_number, Some(6));
+        assert_eq!(request.replication_factor, Some(3));
+
+        assert!(options.validate().is_ok());
+        assert!(QdrantCollectionOptions::new()
+            .with_shard_number(0)
+            .validate()
+            .is_err());
+        assert!(QdrantCollectionOptions::new()
+            .with_replication_factor(0)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_create_collection_request_datatype() {
+        use qdrant_client::qdrant::{vectors_config::Config, Datatype};
+
+        let config = CollectionConfig::new("articles", 4, DistanceMetric::Cosine).unwrap();
+        let datatype_of = |options: &QdrantCollectionOptions| {
+            let request = QdrantAdapter::build_create_collection_request(
+                &config,
+                options,
+                &QdrantConfig::default(),
+            );
+            match request.vectors_config.and_then(|c| c.config) {
+                Some(Config::Params(params)) => params.datatype,
+                other => panic!("expected single vector params, got {other:?}"),
+            }
+        };
+
+        assert_eq!(
+            datatype_of(&QdrantCollectionOptions::new()),
+            Some(Datatype::Float32 as i32)
+        );
+        assert_eq!(
+            datatype_of(&QdrantCollectionOptions::new().with_datatype(VectorDatatype::Float16)),
+            Some(Datatype::Float16 as i32)
+        );
+        assert_eq!(
+            datatype_of(&QdrantCollectionOptions::new().with_datatype(VectorDatatype::Uint8)),
+            Some(Datatype::Uint8 as i32)
+        );
+    }
+
+    #[test]
+    fn test_create_collection_request_multivector() {
+        use qdrant_client::qdrant::vectors_config::Config;
+
+        let config = CollectionConfig::new("colbert", 4, DistanceMetric::Cosine).unwrap();
+        let multivector_of = |options: &QdrantCollectionOptions| {
+            let request = QdrantAdapter::build_create_collection_request(
+                &config,
+                options,
+                &QdrantConfig::default(),
+            );
+            match request.vectors_config.and_then(|c| c.config) {
+                Some(Config::Params(params)) => params.multivector_config,
+                other => panic!("expected single vector params, got {other:?}"),
+            }
+        };
+
+        assert!(multivector_of(&QdrantCollectionOptions::new()).is_none());
+        let multivector = multivector_of(
+            &QdrantCollectionOptions::new().with_multivector(MultiVectorComparator::MaxSim),
+        )
+        .unwrap();
+        assert_eq!(
+            multivector.comparator,
+            qdrant_client::qdrant::MultiVectorComparator::MaxSim as i32
+        );
+    }
+
+    #[test]
+    fn test_update_named_vector_request_targets_one_vector() {
+        let request = QdrantAdapter::build_update_named_vector_request(
+            "docs",
+            PointId::from(7u64),
+            "body",
+            vec![0.1, 0.2],
+        );
+        assert_eq!(request.collection_name, "docs");
+        assert_eq!(request.points.len(), 1);
+
+        let point = &request.points[0];
+        assert_eq!(point.id, Some(PointId::from(7u64)));
+        let expected: qdrant_client::qdrant::Vectors =
+            HashMap::from([("body".to_string(), vec![0.1, 0.2])]).into();
+        assert_eq!(point.vectors, Some(expected));
+        assert!(request.update_filter.is_none());
+    }
+
+    #[test]
+    fn test_multivector_point_carries_rows() {
+        let rows = vec![vec![0.1, 0.2], vec![0.3, 0.4], vec![0.5, 0.6]];
+        let mut vector = Vector::new("42", Vec::new());
+        vector.add_metadata("title", serde_json::json!("doc"));
+
+        let point =
+            QdrantAdapter::build_multivector_point(vector, rows.clone(), &QdrantConfig::default())
+                .unwrap();
+        assert_eq!(
+            point.vectors,
+            Some(qdrant_client::qdrant::Vector::new_multi(rows).into())
+        );
+        assert!(point.payload.contains_key("title"));
+
+        let config = QdrantConfig::default();
+        let ragged = vec![vec![0.1, 0.2], vec![0.3]];
+        assert!(QdrantAdapter::build_multivector_point(
+            Vector::new("1", Vec::new()),
+            ragged,
+            &config
+        )
+        .is_err());
+        assert!(QdrantAdapter::build_multivector_point(
+            Vector::new("1", Vec::new()),
+            vec![],
+            &config
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_create_collection_request_wal_config() {
+        let defaults = QdrantConfig::default();
+        let config = CollectionConfig::new("durable", 4, DistanceMetric::Cosine).unwrap();
+
+        let request = QdrantAdapter::build_create_collection_request(
+            &config,
+            &QdrantCollectionOptions::new(),
+            &defaults,
+        );
+        assert!(request.wal_config.is_none());
+
+        let options = QdrantCollectionOptions::new().with_wal(QdrantWalConfig {
+            capacity_mb: 64,
+            segments_ahead: 2,
+        });
+        let request = QdrantAdapter::build_create_collection_request(&config, &options, &defaults);
+        let wal = request.wal_config.unwrap();
+        assert_eq!(wal.wal_capacity_mb, Some(64));
+        assert_eq!(wal.wal_segments_ahead, Some(2));
+
+        let default_wal = QdrantWalConfig::default();
+        assert_eq!(default_wal.capacity_mb, 32);
+        assert_eq!(default_wal.segments_ahead, 0);
+
+        let empty_wal = QdrantCollectionOptions::new().with_wal(QdrantWalConfig {
+            capacity_mb: 0,
+            segments_ahead: 0,
+        });
+        assert!(empty_wal.validate().is_err());
+    }
+
+    #[test]
+    fn test_create_collection_request_hnsw_config() {
+        let defaults = QdrantConfig::default();
+        let config = CollectionConfig::new("indexed", 4, DistanceMetric::Cosine).unwrap();
+
+        let request = QdrantAdapter::build_create_collection_request(
+            &config,
+            &QdrantCollectionOptions::new(),
+            &defaults,
+        );
+        assert!(request.hnsw_config.is_none());
+
+        let options = QdrantCollectionOptions::new().with_hnsw(QdrantHnswConfig {
+            m: Some(32),
+            ef_construct: Some(200),
+            ..QdrantHnswConfig::default()
+        });
+        let request = QdrantAdapter::build_create_collection_request(&config, &options, &defaults);
+        let hnsw = request.hnsw_config.unwrap();
+        assert_eq!(hnsw.m, Some(32));
+        assert_eq!(hnsw.ef_construct, Some(200));
+        assert_eq!(hnsw.full_scan_threshold, None);
+        assert_eq!(
+            QdrantAdapter::hnsw_from_qdrant(&hnsw),
+            options.hnsw.unwrap()
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_create_collection_request_on_disk_payload() {
+        let config = CollectionConfig::new("articles", 4, DistanceMetric::Cosine).unwrap();
+
+        let request = QdrantAdapter::build_create_collection_request(
+            &config,
+            &QdrantCollectionOptions::new().with_on_disk_payload(true),
+            &QdrantConfig::default(),
+        );
+        assert_eq!(request.on_disk_payload, Some(true));
+
+        // Unset everywhere, the server default applies
+        let request = QdrantAdapter::build_create_collection_request(
+            &config,
+            &QdrantCollectionOptions::new(),
+            &QdrantConfig::default(),
+        );
+        assert_eq!(request.on_disk_payload, None);
+
+        let defaults = QdrantConfig {
+            default_on_disk_payload: Some(true),
+            ..QdrantConfig::default()
+        };
+        let request = QdrantAdapter::build_create_collection_request(
+            &config,
+            &QdrantCollectionOptions::new(),
+            &defaults,
+        );
+        assert_eq!(request.on_disk_payload, Some(true));
+
+        let request = QdrantAdapter::build_create_collection_request(
+            &config,
+            &QdrantCollectionOptions::new().with_on_disk_payload(false),
+            &defaults,
+        );
+        assert_eq!(request.on_disk_payload, Some(false));
+    }
+
+    #[cfg(feature = "raw-client")]
+    #[test]
+    fn test_raw_client_accessor_compiles() {
+        // Connecting needs a live server, so only check the accessor's signature
+        fn accessor(adapter: &QdrantAdapter) -> &Qdrant {
+            adapter.raw_client()
+        }
+        let _ = accessor;
+    }
+
+    #[cfg(feature = "raw-client")]
+    #[test]
+    fn test_raw_upsert_requests_are_batched() {
+        let points: Vec<PointStruct> = (0..5u64)
+            .map(|id| PointStruct::new(id, vec![id as f32, 1.0], Payload::new()))
+            .collect();
+
+        let requests = QdrantAdapter::build_raw_upsert_requests("raw", points, 2);
+        assert_eq!(requests.len(), 3);
+        assert_eq!(
+            requests
+                .iter()
+                .map(|request| request.points.len())
+                .collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        assert!(requests
+            .iter()
+            .all(|request| request.collection_name == "raw"));
+        assert_eq!(
+            requests[2].points[0].id,
+            Some(PointId::from(4u64)),
+            "points keep their order and IDs"
+        );
+
+        assert!(QdrantAdapter::build_raw_upsert_requests("raw", Vec::new(), 2).is_empty());
+    }
+
+    #[test]
+    fn test_payload_schema_validation() {
+        let schema = HashMap::from([
+            ("price".to_string(), ExpectedJsonType::Number),
+            ("stock".to_string(), ExpectedJsonType::Integer),
+        ]);
+
+        let mut valid = Vector::new("1", vec![0.1]);
+        valid.add_metadata("price", serde_json::json!(9.99));
+        valid.add_metadata("stock", serde_json::json!(3));
+        assert!(QdrantAdapter::validate_payload(&valid, &schema).is_ok());
+
+        let mut wrong_type = Vector::new("2", vec![0.1]);
+        wrong_type.add_metadata("price", serde_json::json!("9.99"));
+        wrong_type.add_metadata("stock", serde_json::json!(3));
+        let error = QdrantAdapter::validate_payload(&wrong_type, &schema).unwrap_err();
+        assert!(error.to_string().contains("price"));
+
+        let mut missing = Vector::new("3", vec![0.1]);
+        missing.add_metadata("price", serde_json::json!(1));
+        let error = QdrantAdapter::validate_payload(&missing, &schema).unwrap_err();
+        assert!(error.to_string().contains("stock"));
+
+        assert!(!ExpectedJsonType::Integer.matches(&serde_json::json!(1.5)));
+    }
+
+    #[test]
+    fn test_default_metadata_does_not_overwrite() {
+        let defaults = HashMap::from([
+            ("tenant".to_string(), serde_json::json!("acme")),
+            ("region".to_string(), serde_json::json!("eu")),
+        ]);
+
+        let mut vector = Vector::new("1", vec![0.1]);
+        vector.add_metadata("region", serde_json::json!("us"));
+        QdrantAdapter::merge_default_metadata(&mut vector, &defaults);
+
+        assert_eq!(vector.metadata["tenant"], serde_json::json!("acme"));
+        assert_eq!(vector.metadata["region"], serde_json::json!("us"));
+    }
+
+    #[test]
+    fn test_optimize_request_forces_indexing() {
+        let request = QdrantAdapter::build_optimize_request("documents");
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(
+            request.optimizers_config.and_then(|c| c.indexing_threshold),
+            Some(FORCE_INDEXING_THRESHOLD_KB)
+        );
+    }
+
+    #[test]
+    fn test_optimizer_update_request_carries_thresholds() {
+        let request =
+            QdrantAdapter::build_optimizer_update_request("documents", Some(20_000), Some(4));
+        assert_eq!(request.collection_name, "documents");
+        let optimizers = request.optimizers_config.unwrap();
+        assert_eq!(optimizers.indexing_threshold, Some(20_000));
+        assert_eq!(optimizers.default_segment_number, Some(4));
+
+        let request = QdrantAdapter::build_optimizer_update_request("documents", Some(0), None);
+        let optimizers = request.optimizers_config.unwrap();
+        assert_eq!(optimizers.indexing_threshold, Some(0));
+        assert_eq!(optimizers.default_segment_number, None);
+    }
+
+    #[test]
+    fn test_payload_get_request_skips_vectors() {
+        use qdrant_client::qdrant::{with_payload_selector, with_vectors_selector};
+
+        let request = QdrantAdapter::build_payload_get_request("documents", PointId::from(7u64));
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(request.ids, vec![PointId::from(7u64)]);
+        assert_eq!(
+            request.with_vectors.and_then(|s| s.selector_options),
+            Some(with_vectors_selector::SelectorOptions::Enable(false))
+        );
+        assert_eq!(
+            request.with_payload.and_then(|s| s.selector_options),
+            Some(with_payload_selector::SelectorOptions::Enable(true))
+        );
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_copy_collection_request_keeps_source_settings() {
+        use qdrant_client::qdrant::{
+            vectors_config::Config, CollectionParams, Datatype, HnswConfigDiff, MultiVectorConfig,
+            VectorParamsMap,
+        };
+
+        let source = |vectors: Config| qdrant_client::qdrant::CollectionConfig {
+            params: Some(CollectionParams {
+                shard_number: 3,
+                replication_factor: Some(2),
+                on_disk_payload: true,
+                vectors_config: Some(VectorsConfig {
+                    config: Some(vectors),
+                }),
+                ..Default::default()
+            }),
+            hnsw_config: Some(HnswConfigDiff {
+                m: Some(32),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let params = VectorParams {
+            size: 4,
+            distance: Distance::Euclid as i32,
+            datatype: Some(Datatype::Float16 as i32),
+            ..Default::default()
+        };
+
+        let config = source(Config::Params(params.clone()));
+        assert!(QdrantAdapter::check_copyable_points("docs", &config).is_ok());
+        let request = QdrantAdapter::build_copy_collection_request("docs_copy", &config);
+        assert_eq!(request.collection_name, "docs_copy");
+        assert_eq!(request.shard_number, Some(3));
+        assert_eq!(request.replication_factor, Some(2));
+        assert_eq!(request.on_disk_payload, Some(true));
+        assert_eq!(request.hnsw_config.and_then(|hnsw| hnsw.m), Some(32));
+        assert_eq!(
+            request.vectors_config,
+            Some(VectorsConfig {
+                config: Some(Config::Params(params.clone())),
+            })
+        );
+
+        // Points are copied through one unnamed vector, so other layouts are refused
+        let named = source(Config::ParamsMap(VectorParamsMap {
+            map: [("text".to_string(), params.clone())].into(),
+        }));
+        let error = QdrantAdapter::check_copyable_points("docs", &named).unwrap_err();
+        assert!(error.to_string().contains("named vectors"), "{error}");
+        let multivector = source(Config::Params(VectorParams {
+            multivector_config: Some(MultiVectorConfig::default()),
+            ..params
+        }));
+        assert!(QdrantAdapter::check_copyable_points("docs", &multivector).is_err());
+    }
+
+    #[test]
+    fn test_collection_details_report_each_named_vector() {
+        use qdrant_client::qdrant::{
+            vectors_config::Config, CollectionInfo, CollectionParams, VectorParamsMap,
+        };
+
+        let info = |config: Config| CollectionInfo {
+            config: Some(qdrant_client::qdrant::CollectionConfig {
+                params: Some(CollectionParams {
+                    vectors_config: Some(VectorsConfig {
+                        config: Some(config),
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let params = |size: u64, distance: Distance| VectorParams {
+            size,
+            distance: distance as i32,
+            ..Default::default()
+        };
+
+        let named = info(Config::ParamsMap(VectorParamsMap {
+            map: [
+                ("text".to_string(), params(384, Distance::Cosine)),
+                ("image".to_string(), params(512, Distance::Euclid)),
+            ]
+            .into(),
+        }));
+        let details = QdrantAdapter::collection_details_from_info("media", &named).unwrap();
+        assert_eq!(details.vectors.len(), 2);
+        assert_eq!(details.vectors["text"].dimension, 384);
+        assert!(matches!(
+            details.vectors["text"].distance_metric,
+            DistanceMetric::Cosine
+        ));
+        assert_eq!(details.vectors["image"].dimension, 512);
+        assert!(matches!(
+            details.vectors["image"].distance_metric,
+            DistanceMetric::Euclidean
+        ));
+        // The port config describes the first vector by name instead of a made-up default
+        assert_eq!(details.config.dimension, 512);
+        assert!(matches!(
+            details.config.distance_metric,
+            DistanceMetric::Euclidean
+        ));
+
+        let single = info(Config::Params(params(768, Distance::Dot)));
+        let details = QdrantAdapter::collection_details_from_info("docs", &single).unwrap();
+        assert_eq!(details.vectors.len(), 1);
+        assert_eq!(details.vectors[""].dimension, 768);
+        assert!(matches!(
+            details.config.distance_metric,
+            DistanceMetric::DotProduct
+        ));
+
+        assert!(
+            QdrantAdapter::collection_details_from_info("docs", &CollectionInfo::default())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_memory_estimate_accounts_for_datatype_quantization_and_on_disk() {
+        use qdrant_client::qdrant::{
+            quantization_config::Quantization, vectors_config::Config, BinaryQuantization,
+            CollectionInfo, CollectionParams, Datatype, QuantizationConfig, ScalarQuantization,
+        };
+
+        let info_on_disk = |datatype: Datatype,
+                            quantization: Option<Quantization>,
+                            on_disk: bool| CollectionInfo {
+            points_count: Some(1000),
+            config: Some(qdrant_client::qdrant::CollectionConfig {
+                params: Some(CollectionParams {
+                    vectors_config: Some(VectorsConfig {
+                        config: Some(Config::Params(VectorParams {
+                            size: 768,
+                            datatype: Some(datatype as i32),
+                            on_disk: Some(on_disk),
+                            ..Default::default()
+                        })),
+                    }),
+                    ..Default::default()
+                }),
+                quantization_config: quantization.map(|quantization| QuantizationConfig {
+                    quantization: Some(quantization),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let info = |datatype: Datatype, quantization: Option<Quantization>| {
+            info_on_disk(datatype, quantization, false)
+        };
+
+        // 1000 points x 768 float32 components, plus 50% overhead
+        let float32 = QdrantAdapter::estimate_collection_memory(&info(Datatype::Float32, None));
+        assert_eq!(float32, 1000 * 768 * 4 * 3 / 2);
+        let float16 = QdrantAdapter::estimate_collection_memory(&info(Datatype::Float16, None));
+        assert_eq!(float16, float32 / 2);
+
+        // Quantization adds its compressed copy
+        let scalar = QdrantAdapter::estimate_collection_memory(&info(
+            Datatype::Float32,
+            Some(Quantization::Scalar(ScalarQuantization::default())),
+        ));
+        assert_eq!(scalar, float32 + 1000 * 768);
+        let binary = QdrantAdapter::estimate_collection_memory(&info(
+            Datatype::Float32,
+            Some(Quantization::Binary(BinaryQuantization::default())),
+        ));
+        assert_eq!(binary, float32 + 1000 * 96);
+
+        // On-disk vectors keep only their index overhead and quantized copy in RAM
+        let on_disk =
+            QdrantAdapter::estimate_collection_memory(&info_on_disk(Datatype::Float32, None, true));
+        assert_eq!(on_disk, 1000 * 768 * 4 / 2);
+        let on_disk_scalar = QdrantAdapter::estimate_collection_memory(&info_on_disk(
+            Datatype::Float32,
+            Some(Quantization::Scalar(ScalarQuantization::default())),
+            true,
+        ));
+        assert_eq!(on_disk_scalar, on_disk + 1000 * 768);
+
+        assert_eq!(
+            QdrantAdapter::estimate_collection_memory(&CollectionInfo::default()),
+            0
+        );
+    }
+
+    #[test]
+    fn test_point_errors_report_missing_collection() {
+        assert_eq!(
+            QdrantAdapter::missing_collection_in_status(
+                NOT_FOUND_CODE,
+                "Not found: Collection `app_docs` doesn't exist!"
+            ),
+            Some("app_docs")
+        );
+        // Missing points and shard keys are NotFound too, but do not name a missing collection
+        assert_eq!(
+            QdrantAdapter::missing_collection_in_status(NOT_FOUND_CODE, "No point with id 7 found"),
+            None
+        );
+        assert_eq!(
+            QdrantAdapter::missing_collection_in_status(
+                NOT_FOUND_CODE,
+                "Shard key `eu` not found in collection `docs`"
+            ),
+            None
+        );
+        assert_eq!(
+            QdrantAdapter::missing_collection_in_status(14, "Collection `docs` doesn't exist!"),
+            None
+        );
+
+        // Only qdrant-client errors are classified; the message alone proves nothing
+        let message = QdrantAdapter::map_point_error::<(), _>(
+            Err("status: NotFound, message: \"Not found: Collection `docs` doesn't exist!\""),
+            "docs",
+            "Search failed",
+        )
+        .unwrap_err();
+        assert!(message.to_string().contains("Search failed"), "{message}");
+
+        let other = QdrantAdapter::map_point_error::<(), _>(
+            Err("status: Unavailable, message: \"connection refused\""),
+            "docs",
+            "Search failed",
+        )
+        .unwrap_err();
+        assert!(other.to_string().contains("Search failed"), "{other}");
+        assert!(other.to_string().contains("connection refused"), "{other}");
+
+        assert_eq!(
+            QdrantAdapter::map_point_error::<_, &str>(Ok(3), "docs", "Search failed").unwrap(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_shard_key_selector_is_set_on_requests() {
+        let selector: Option<qdrant_client::qdrant::ShardKeySelector> =
+            Some("tenant-a".to_string().into());
+
+        let options = QdrantSearchOptions::new().with_shard_key("tenant-a");
+        let request = QdrantAdapter::build_search_request(
+            "docs",
+            vec![1.0, 0.0],
+            &SearchParams::with_limit(5),
+            &options,
+            &QdrantConfig::default(),
+        );
+        assert_eq!(request.shard_key_selector, selector);
+
+        let point = QdrantAdapter::vector_to_point_struct(
+            Vector::new("1".to_string(), vec![1.0, 0.0]),
+            &QdrantConfig::default(),
+        )
+        .unwrap();
+        let upsert = QdrantAdapter::build_upsert_request("docs", vec![point], Some("tenant-a"));
+        assert_eq!(upsert.shard_key_selector, selector);
+
+        let get = QdrantAdapter::build_get_request("docs", PointId::from(1u64), Some("tenant-a"));
+        assert_eq!(get.shard_key_selector, selector);
+
+        // Without a shard key every shard is used
+        let request = QdrantAdapter::build_search_request(
+            "docs",
+            vec![1.0, 0.0],
+            &SearchParams::with_limit(5),
+            &QdrantSearchOptions::default(),
+            &QdrantConfig::default(),
+        );
+        assert!(request.shard_key_selector.is_none());
+        assert!(
+            QdrantAdapter::build_upsert_request("docs", Vec::new(), None)
+                .shard_key_selector
+                .is_none()
+        );
+        assert!(
+            QdrantAdapter::build_get_request("docs", PointId::from(1u64), None)
+                .shard_key_selector
+                .is_none()
+        );
+    }
 
-        self.with_telemetry("qdrant_store_vector", &context, async {
-            let point = Self::vector_to_point_struct(vector);
+    #[test]
+    fn test_existence_request_skips_payload_and_vectors() {
+        use qdrant_client::qdrant::{with_payload_selector, with_vectors_selector};
 
-            let response = Self::map_qdrant_error(
-                self.client
-                    .upsert_points(UpsertPoints {
-                        collection_name: collection.to_string(),
-                        points: vec![point],
-                        ..Default::default()
-                    })
-                    .await,
-                "Failed to store vector",
-            )?;
+        let ids = vec![PointId::from(1u64), PointId::from(2u64)];
+        let request = QdrantAdapter::build_existence_request("documents", ids.clone());
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(request.ids, ids);
+        assert_eq!(
+            request.with_vectors.and_then(|s| s.selector_options),
+            Some(with_vectors_selector::SelectorOptions::Enable(false))
+        );
+        assert_eq!(
+            request.with_payload.and_then(|s| s.selector_options),
+            Some(with_payload_selector::SelectorOptions::Enable(false))
+        );
+    }
 
-            if response.result.is_none() {
-                return Err(vector_errors::storage_failed("No response from Qdrant"));
-            }
+    #[test]
+    fn test_versioned_get_request_filters_by_id() {
+        use qdrant_client::qdrant::condition::ConditionOneOf;
+
+        let point_ids = vec![PointId::from(7u64), PointId::from(42u64)];
+        let request = QdrantAdapter::build_versioned_get_request("documents", &point_ids);
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(request.limit, Some(2));
+        assert!(request.query.is_none());
+
+        let filter = request.filter.unwrap();
+        match filter.must[0].condition_one_of.as_ref() {
+            Some(ConditionOneOf::HasId(has_id)) => assert_eq!(has_id.has_id, point_ids),
+            other => panic!("expected has_id condition, got {other:?}"),
+        }
+    }
 
-            Ok(())
-        })
-        .await
+    #[test]
+    fn test_scroll_request_uses_filter() {
+        let params =
+            SearchParams::with_limit(5).with_filter("external_id", serde_json::json!("ext-42"));
+        let request = QdrantAdapter::build_scroll_request("documents", &params, 20);
+
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(request.limit, Some(20));
+        assert_eq!(request.filter.map(|f| f.must.len()), Some(1));
     }
 
-    /// Store multiple vectors in batch
-    async fn store_vectors_batch(
-        &self,
-        collection: &str,
-        vectors: Vec<Vector>,
-    ) -> TylResult<Vec<TylResult<()>>> {
-        if vectors.len() > self.config.max_batch_size {
-            return Err(TylError::validation(
-                "batch_size",
-                format!(
-                    "Batch size {} exceeds maximum {}",
-                    vectors.len(),
-                    self.config.max_batch_size
-                ),
-            ));
-        }
+    #[test]
+    fn test_ordered_scroll_request() {
+        use qdrant_client::qdrant::Direction;
 
-        let points: Vec<PointStruct> = vectors
-            .into_iter()
-            .map(Self::vector_to_point_struct)
-            .collect();
+        let request =
+            QdrantAdapter::build_ordered_scroll_request("documents", "created_ts", false, 10);
 
-        let point_count = points.len();
-        let response = self
-            .client
-            .upsert_points(qdrant_client::qdrant::UpsertPoints {
-                collection_name: collection.to_string(),
-                points,
-                ..Default::default()
-            })
-            .await
-            .map_err(|e| vector_errors::storage_failed(format!("Failed to store vectors: {e}")))?;
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(request.limit, Some(10));
+        assert!(request.filter.is_none());
+        let order_by = request.order_by.unwrap();
+        assert_eq!(order_by.key, "created_ts");
+        assert_eq!(order_by.direction, Some(Direction::Desc as i32));
 
-        // Qdrant returns success for all or fails for all
-        match response.result {
-            Some(_) => Ok(vec![Ok(()); point_count]),
-            None => {
-                let error = vector_errors::storage_failed("Batch storage failed");
-                Ok(vec![Err(error); point_count])
-            }
-        }
+        let ascending =
+            QdrantAdapter::build_ordered_scroll_request("documents", "created_ts", true, 10);
+        assert_eq!(
+            ascending.order_by.and_then(|o| o.direction),
+            Some(Direction::Asc as i32)
+        );
     }
 
-    /// Retrieve a vector by ID
-    async fn get_vector(&self, collection: &str, id: &str) -> TylResult<Option<Vector>> {
-        let get_points = GetPoints {
-            collection_name: collection.to_string(),
-            ids: vec![qdrant_client::qdrant::PointId::from(id.to_string())],
-            with_payload: Some(WithPayloadSelector {
-                selector_options: Some(
-                    qdrant_client::qdrant::with_payload_selector::SelectorOptions::Enable(true),
-                ),
-            }),
-            with_vectors: Some(WithVectorsSelector {
-                selector_options: Some(
-                    qdrant_client::qdrant::with_vectors_selector::SelectorOptions::Enable(true),
-                ),
-            }),
-            read_consistency: None,
-            shard_key_selector: None,
-            timeout: None,
-        };
+    #[test]
+    fn test_missing_index_requests_for_new_filter_fields() {
+        use qdrant_client::qdrant::FieldType;
 
-        let points =
-            self.client.get_points(get_points).await.map_err(|e| {
-                vector_errors::vector_not_found(format!("Failed to get vector: {e}"))
-            })?;
+        let config = QdrantConfig {
+            auto_index_filter_fields: UnindexedFilterPolicy::Create,
+            ..QdrantConfig::default()
+        };
+        assert_eq!(
+            config.auto_index_filter_fields,
+            UnindexedFilterPolicy::Create
+        );
+        assert_eq!(
+            "warn".parse::<UnindexedFilterPolicy>().unwrap(),
+            UnindexedFilterPolicy::Warn
+        );
+        assert!("always".parse::<UnindexedFilterPolicy>().is_err());
+        assert_eq!(
+            " Datetime".parse::<PayloadIndexType>().unwrap(),
+            PayloadIndexType::Datetime
+        );
+        assert!("geo".parse::<PayloadIndexType>().is_err());
 
-        if let Some(point) = points.result.into_iter().next() {
-            let scored_point = qdrant_client::qdrant::ScoredPoint {
-                id: point.id,
-                payload: point.payload,
-                score: 1.0, // Not used for retrieval
-                vectors: point.vectors,
-                shard_key: None,
-                order_value: None,
-                version: 0,
-            };
-            Ok(Some(Self::point_to_vector(scored_point)?))
-        } else {
-            Ok(None)
-        }
-    }
+        let params = SearchParams::with_limit(10)
+            .with_filter("category", serde_json::json!("news"))
+            .with_filter("year", serde_json::json!({"$gte": 2020}))
+            .with_filter("deleted_at", serde_json::json!({"$is_null": true}));
+        let indexed = std::collections::HashSet::from(["category".to_string()]);
+        assert_eq!(
+            QdrantAdapter::unindexed_filter_fields(&params, &indexed),
+            vec!["deleted_at", "year"]
+        );
 
-    /// Search for similar vectors
-    async fn search_similar(
-        &self,
-        collection: &str,
-        query_vector: Vec<f32>,
-        params: SearchParams,
-    ) -> TylResult<Vec<VectorSearchResult>> {
-        let context = format!(
-            "Searching similar vectors in collection '{collection}' with limit {}",
-            params.limit
+        // The index type comes from config, not from the filter value, and is built in the
+        // background
+        let request =
+            QdrantAdapter::build_field_index_request("documents", "year", PayloadIndexType::Float);
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(request.field_name, "year");
+        assert_eq!(request.field_type, Some(FieldType::Float as i32));
+        assert_eq!(request.wait, Some(false));
+
+        let request = QdrantAdapter::build_field_index_request(
+            "documents",
+            "description",
+            PayloadIndexType::Text,
         );
+        assert_eq!(request.field_type, Some(FieldType::Text as i32));
+    }
 
-        self.with_telemetry("qdrant_search_similar", &context, async {
-            let filter = Self::build_filter(&params);
+    #[test]
+    fn test_collection_name_patterns() {
+        assert!(QdrantAdapter::collection_name_matches("docs_en", "docs_"));
+        assert!(!QdrantAdapter::collection_name_matches(
+            "my_docs_en",
+            "docs_"
+        ));
+        assert!(QdrantAdapter::collection_name_matches("docs_en", "docs_*"));
+        assert!(QdrantAdapter::collection_name_matches(
+            "my_docs_en",
+            "*docs*"
+        ));
+        assert!(QdrantAdapter::collection_name_matches("docs_en", "docs_??"));
+        assert!(!QdrantAdapter::collection_name_matches(
+            "docs_eng", "docs_??"
+        ));
+        assert!(QdrantAdapter::collection_name_matches("a_b_c", "a*c"));
+        assert!(!QdrantAdapter::collection_name_matches("a_b_cd", "a*c"));
+        assert!(QdrantAdapter::collection_name_matches("anything", ""));
+    }
 
-            let search_points = qdrant_client::qdrant::SearchPoints {
-                collection_name: collection.to_string(),
-                vector: query_vector,
-                limit: params.limit as u64,
-                score_threshold: params.threshold,
-                filter,
-                with_payload: Some(qdrant_client::qdrant::WithPayloadSelector {
-                    selector_options: Some(
-                        qdrant_client::qdrant::with_payload_selector::SelectorOptions::Enable(true),
-                    ),
-                }),
-                with_vectors: Some(qdrant_client::qdrant::WithVectorsSelector {
-                    selector_options: Some(
-                        qdrant_client::qdrant::with_vectors_selector::SelectorOptions::Enable(
-                            params.include_vectors,
-                        ),
-                    ),
-                }),
-                ..Default::default()
-            };
+    #[test]
+    fn test_merge_metadata_request() {
+        use qdrant_client::qdrant::points_update_operation::Operation;
 
-            let response = Self::map_qdrant_error(
-                self.client.search_points(search_points).await,
-                "Search failed",
-            )?;
+        let config = QdrantConfig::default();
+        let mut vector = Vector::new("7".to_string(), vec![0.5, 0.5]);
+        vector.add_metadata("status", serde_json::json!("archived"));
+        let request =
+            QdrantAdapter::build_merge_metadata_request("documents", &vector, &config).unwrap();
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(request.wait, Some(true));
+
+        let operations: Vec<_> = request
+            .operations
+            .into_iter()
+            .map(|op| op.operation.unwrap())
+            .collect();
+        let [Operation::UpdateVectors(update), Operation::SetPayload(set)] = &operations[..] else {
+            panic!("expected a vector update followed by a payload merge: {operations:?}");
+        };
+        assert_eq!(update.points[0].id, Some(PointId::from(7u64)));
+        assert_eq!(set.payload.len(), 1);
+        assert!(set.payload.contains_key("status"));
+
+        let schema = HashMap::from([
+            ("status".to_string(), ExpectedJsonType::String),
+            ("title".to_string(), ExpectedJsonType::String),
+        ]);
+        // Schema keys the update does not set are already stored
+        let config = QdrantConfig::default();
+        assert!(QdrantAdapter::validate_metadata_update(&vector, Some(&schema), &config).is_ok());
+        let mut wrong = Vector::new("7".to_string(), vec![0.5, 0.5]);
+        wrong.add_metadata("status", serde_json::json!(3));
+        assert!(QdrantAdapter::validate_metadata_update(&wrong, Some(&schema), &config).is_err());
+    }
 
-            let mut results = Vec::new();
-            for point in response.result {
-                let vector = Self::point_to_vector(point.clone())?;
-                let result = VectorSearchResult::new(vector, point.score);
-                results.push(result);
+    #[test]
+    fn test_delete_by_filter_requests() {
+        let params =
+            SearchParams::with_limit(10).with_filter("status", serde_json::json!("archived"));
+
+        let count = QdrantAdapter::build_count_request("documents", &params);
+        assert_eq!(count.exact, Some(true));
+        assert_eq!(count.filter.map(|f| f.must.len()), Some(1));
+
+        let delete = QdrantAdapter::build_delete_by_filter_request("documents", &params).unwrap();
+        assert_eq!(delete.wait, Some(true));
+        match delete.points.and_then(|p| p.points_selector_one_of) {
+            Some(qdrant_client::qdrant::points_selector::PointsSelectorOneOf::Filter(filter)) => {
+                assert_eq!(filter.must.len(), 1)
             }
+            other => panic!("expected a filter selector, got {other:?}"),
+        }
 
-            Ok(results)
-        })
-        .await
+        assert!(QdrantAdapter::build_delete_by_filter_request(
+            "documents",
+            &SearchParams::with_limit(10)
+        )
+        .is_err());
+
+        // An untranslatable entry fails the delete instead of widening it
+        let unsupported = params.with_filter("tag", serde_json::json!({"$ne": "keep"}));
+        assert!(QdrantAdapter::build_delete_by_filter_request("documents", &unsupported).is_err());
     }
 
-    /// Delete a vector by ID
-    async fn delete_vector(&self, collection: &str, id: &str) -> TylResult<()> {
-        let points_selector = PointsSelector {
-            points_selector_one_of: Some(
-                qdrant_client::qdrant::points_selector::PointsSelectorOneOf::Points(
-                    PointsIdsList {
-                        ids: vec![PointId::from(id.to_string())],
-                    },
-                ),
-            ),
-        };
+    fn scored_results(scores: &[f32]) -> Vec<VectorSearchResult> {
+        scores
+            .iter()
+            .enumerate()
+            .map(|(i, score)| {
+                VectorSearchResult::new(Vector::new(format!("{i}"), vec![0.1, 0.2]), *score)
+            })
+            .collect()
+    }
 
-        let delete_points = DeletePoints {
-            collection_name: collection.to_string(),
-            points: Some(points_selector),
-            wait: None,
-            shard_key_selector: None,
-            ordering: None,
+    #[test]
+    fn test_relative_threshold_cutoff() {
+        let scores = [1.0, 0.95, 0.8, 0.5];
+
+        let cosine = DistanceMetric::Cosine;
+        let relative = |scores: &[f32], threshold: f32, metric: &DistanceMetric| {
+            QdrantAdapter::apply_relative_threshold(scored_results(scores), threshold, metric)
         };
+        let kept_scores =
+            |kept: Vec<VectorSearchResult>| kept.iter().map(|r| r.score).collect::<Vec<f32>>();
 
-        let response = self
-            .client
-            .delete_points(delete_points)
-            .await
-            .map_err(|e| vector_errors::storage_failed(format!("Failed to delete vector: {e}")))?;
+        let kept = relative(&scores, 0.9, &cosine).unwrap();
+        assert_eq!(kept_scores(kept), vec![1.0, 0.95]);
 
-        if response.result.is_none() {
-            return Err(vector_errors::storage_failed("No response from Qdrant"));
-        }
-        Ok(())
+        let kept = relative(&scores, 0.5, &cosine).unwrap();
+        assert_eq!(kept.len(), 4);
+
+        // Negative dot product scores keep the best one and its close neighbours
+        let kept = relative(&[-1.0, -1.05, -2.0], 0.9, &DistanceMetric::DotProduct).unwrap();
+        assert_eq!(kept_scores(kept), vec![-1.0, -1.05]);
+
+        // Distances keep the nearest results, not the farthest
+        let kept = relative(&[0.5, 0.52, 0.6, 1.0], 0.9, &DistanceMetric::Euclidean).unwrap();
+        assert_eq!(kept_scores(kept), vec![0.5, 0.52]);
+
+        assert!(relative(&scores, 0.0, &cosine).is_err());
+        assert!(relative(&scores, 1.5, &cosine).is_err());
+        assert!(relative(&[], 0.9, &cosine).unwrap().is_empty());
     }
 
-    /// Delete multiple vectors by IDs
-    async fn delete_vectors_batch(&self, collection: &str, ids: Vec<String>) -> TylResult<()> {
-        let point_ids: Vec<PointId> = ids.into_iter().map(PointId::from).collect();
+    #[test]
+    fn test_restrict_to_ids_keeps_other_filters() {
+        use qdrant_client::qdrant::condition::ConditionOneOf;
+
+        let params = SearchParams::with_limit(5).with_filter("category", serde_json::json!("docs"));
+        let allowed = vec!["1".to_string(), "2".to_string()];
+        let restricted = QdrantAdapter::restrict_to_ids(params, &allowed);
+
+        let filter = QdrantAdapter::build_filter(&restricted).unwrap();
+        assert_eq!(filter.must.len(), 2);
+        assert!(filter.must.iter().any(|condition| matches!(
+            &condition.condition_one_of,
+            Some(ConditionOneOf::HasId(condition)) if condition.has_id.len() == 2
+        )));
+    }
 
-        let points_selector = PointsSelector {
-            points_selector_one_of: Some(
-                qdrant_client::qdrant::points_selector::PointsSelectorOneOf::Points(
-                    PointsIdsList { ids: point_ids },
-                ),
-            ),
-        };
+    #[test]
+    fn test_score_threshold_is_metric_aware() {
+        assert!(is_higher_better(&DistanceMetric::Cosine));
+        assert!(is_higher_better(&DistanceMetric::DotProduct));
+        assert!(!is_higher_better(&DistanceMetric::Euclidean));
+        assert!(!is_higher_better(&DistanceMetric::Manhattan));
+
+        // Similarity metrics keep scores at or above the threshold
+        assert!(passes_score_threshold(&DistanceMetric::Cosine, 0.9, 0.8));
+        assert!(!passes_score_threshold(&DistanceMetric::Cosine, 0.7, 0.8));
+
+        // Distance metrics keep scores at or below it
+        assert!(passes_score_threshold(&DistanceMetric::Euclidean, 0.7, 0.8));
+        assert!(!passes_score_threshold(
+            &DistanceMetric::Euclidean,
+            0.9,
+            0.8
+        ));
+        assert!(passes_score_threshold(&DistanceMetric::Manhattan, 0.8, 0.8));
+    }
 
-        let delete_points = DeletePoints {
-            collection_name: collection.to_string(),
-            points: Some(points_selector),
-            wait: None,
-            shard_key_selector: None,
-            ordering: None,
+    #[test]
+    fn test_relevance_gate() {
+        let gate = |scores: &[f32], metric: DistanceMetric, error_if_irrelevant: bool| {
+            QdrantAdapter::apply_relevance_gate(
+                "docs",
+                scored_results(scores),
+                &metric,
+                0.8,
+                error_if_irrelevant,
+            )
         };
 
-        let response = self
-            .client
-            .delete_points(delete_points)
-            .await
-            .map_err(|e| vector_errors::storage_failed(format!("Failed to delete vectors: {e}")))?;
-
-        if response.result.is_none() {
-            return Err(vector_errors::storage_failed("No response from Qdrant"));
-        }
-        Ok(())
+        // A relevant best result keeps every result, even those below the floor
+        let kept = gate(&[0.9, 0.4, 0.2], DistanceMetric::Cosine, true).unwrap();
+        assert_eq!(kept.len(), 3);
+
+        let low = [0.3, 0.2];
+        let dropped = gate(&low, DistanceMetric::Cosine, false).unwrap();
+        assert!(dropped.is_empty());
+        let error = gate(&low, DistanceMetric::Cosine, true).unwrap_err();
+        assert!(error.to_string().contains("best score 0.3"), "{error}");
+
+        // On distance metrics the nearest result must be within the maximum distance
+        let kept = gate(&[0.3, 1.5], DistanceMetric::Euclidean, true).unwrap();
+        assert_eq!(kept.len(), 2);
+        let error = gate(&[0.9, 1.5], DistanceMetric::Euclidean, true).unwrap_err();
+        assert!(error.to_string().contains("best score 0.9"), "{error}");
+
+        assert!(gate(&[], DistanceMetric::Cosine, false).unwrap().is_empty());
+        let error = gate(&[], DistanceMetric::Cosine, true).unwrap_err();
+        assert!(error.to_string().contains("no results"), "{error}");
     }
-}
 
-#[async_trait]
-impl VectorCollectionManager for QdrantAdapter {
-    /// Create a new collection in Qdrant
-    async fn create_collection(&self, config: CollectionConfig) -> TylResult<()> {
-        config.validate()?;
+    #[test]
+    fn test_search_groups_request() {
+        let params = SearchParams::with_limit(3)
+            .with_threshold(0.5)
+            .with_filter("lang", serde_json::json!("en"));
+        let request = QdrantAdapter::build_search_groups_request(
+            "chunks",
+            vec![0.1, 0.2],
+            "document_id",
+            5,
+            1,
+            &params,
+        );
 
-        let vectors_config = VectorsConfig {
-            config: Some(qdrant_client::qdrant::vectors_config::Config::Params(
-                VectorParams {
-                    size: config.dimension as u64,
-                    distance: Self::distance_metric_to_qdrant(&config.distance_metric) as i32,
-                    hnsw_config: None,
-                    quantization_config: None,
-                    on_disk: None,
-                    datatype: None,
-                    multivector_config: None,
-                },
-            )),
-        };
+        assert_eq!(request.group_by, "document_id");
+        assert_eq!(request.limit, 5);
+        assert_eq!(request.group_size, 1);
+        assert_eq!(request.score_threshold, Some(0.5));
+        assert!(request.filter.is_some());
 
-        let create_collection = CreateCollection {
-            collection_name: config.name.clone(),
-            vectors_config: Some(vectors_config),
-            shard_number: Some(self.config.default_shard_number),
-            replication_factor: Some(self.config.default_replication_factor),
-            ..Default::default()
+        let group_id = qdrant_client::qdrant::GroupId {
+            kind: Some(qdrant_client::qdrant::group_id::Kind::StringValue(
+                "doc-1".to_string(),
+            )),
         };
+        assert_eq!(
+            QdrantAdapter::group_id_to_json(Some(group_id)),
+            serde_json::json!("doc-1")
+        );
+    }
 
-        let response = self
-            .client
-            .create_collection(create_collection)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("already exists") {
-                    vector_errors::storage_failed(format!(
-                        "Collection '{}' already exists",
-                        config.name
-                    ))
-                } else {
-                    vector_errors::storage_failed(format!("Failed to create collection: {e}"))
-                }
-            })?;
+    #[test]
+    fn test_discover_request() {
+        use qdrant_client::qdrant::target_vector::Target;
+
+        let params = SearchParams::with_limit(7).with_filter("lang", serde_json::json!("en"));
+        let request = QdrantAdapter::build_discover_request(
+            "documents",
+            Some(vec![0.1, 0.2]),
+            vec![
+                (vec![1.0, 0.0], vec![0.0, 1.0]),
+                (vec![0.5, 0.5], vec![0.2, 0.8]),
+            ],
+            &params,
+        );
 
-        if !response.result {
-            return Err(vector_errors::storage_failed("Failed to create collection"));
-        }
-        Ok(())
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(request.limit, 7);
+        assert!(request.filter.is_some());
+        assert!(matches!(
+            request.target.and_then(|t| t.target),
+            Some(Target::Single(_))
+        ));
+        assert_eq!(request.context.len(), 2);
+        assert!(request
+            .context
+            .iter()
+            .all(|pair| pair.positive.is_some() && pair.negative.is_some()));
+
+        let context_only =
+            QdrantAdapter::build_discover_request("documents", None, Vec::new(), &params);
+        assert!(context_only.target.is_none());
+        assert!(QdrantAdapter::validate_discover_inputs(&None, &[]).is_err());
     }
 
-    /// Delete a collection
-    async fn delete_collection(&self, collection_name: &str) -> TylResult<()> {
-        let response = self
-            .client
-            .delete_collection(collection_name)
-            .await
-            .map_err(|e| {
-                vector_errors::storage_failed(format!("Failed to delete collection: {e}"))
-            })?;
+    #[cfg(feature = "logging")]
+    #[tokio::test]
+    async fn test_debug_summaries_follow_log_level() {
+        // Summaries are logged from inside a telemetry-wrapped operation, as searches do
+        async fn run_search_like_operation(adapter: &QdrantAdapter) {
+            adapter
+                .with_telemetry("qdrant_search_similar", "Searching 'docs'", async {
+                    let results = scored_results(&[0.9, 0.5]);
+                    adapter.log_debug(|| QdrantAdapter::search_summary("docs", &results));
+                    Ok(())
+                })
+                .await
+                .unwrap();
+        }
 
-        if !response.result {
-            return Err(vector_errors::collection_not_found(collection_name));
+        let (adapter, records) = capturing_adapter(QdrantConfig::default());
+        run_search_like_operation(&adapter).await;
+        {
+            let records = records.lock().unwrap();
+            assert!(
+                records
+                    .iter()
+                    .any(|r| r.contains("Completed qdrant_search_similar")),
+                "{records:?}"
+            );
+            assert!(
+                !records.iter().any(|r| r.contains("returned 2 results")),
+                "{records:?}"
+            );
         }
-        Ok(())
+
+        let (adapter, records) = capturing_adapter(QdrantConfig {
+            log_level: QdrantLogLevel::Debug,
+            ..QdrantConfig::default()
+        });
+        run_search_like_operation(&adapter).await;
+        let records = records.lock().unwrap();
+        assert!(
+            records.iter().any(|r| r.contains(
+                "Search in collection 'docs' returned 2 results (max score 0.9000, min score 0.5000)"
+            )),
+            "{records:?}"
+        );
+        // Info-level telemetry is unchanged by the debug summary
+        assert_eq!(records.len(), 3, "{records:?}");
     }
 
-    /// List all collections
-    async fn list_collections(&self) -> TylResult<Vec<CollectionConfig>> {
-        let response = self.client.list_collections().await.map_err(|e| {
-            vector_errors::storage_failed(format!("Failed to list collections: {e}"))
-        })?;
+    #[test]
+    fn test_debug_summaries() {
+        let summary = QdrantAdapter::search_summary("docs", &scored_results(&[0.9, 0.5, 0.7]));
+        assert!(summary.contains("returned 3 results"));
+        assert!(summary.contains("max score 0.9000"));
+        assert!(summary.contains("min score 0.5000"));
 
-        let mut configs = Vec::new();
-        for collection_description in response.collections {
-            if let Ok(Some(config)) = self.get_collection_info(&collection_description.name).await {
-                configs.push(config);
-            }
-        }
-        Ok(configs)
+        let empty = QdrantAdapter::search_summary("docs", &[]);
+        assert!(empty.contains("returned 0 results"));
+
+        assert_eq!(
+            QdrantAdapter::batch_store_summary("docs", 42),
+            "Wrote 42 points to collection 'docs'"
+        );
     }
 
-    /// Get collection information
-    async fn get_collection_info(
-        &self,
-        collection_name: &str,
-    ) -> TylResult<Option<CollectionConfig>> {
-        let info = self
-            .client
-            .collection_info(collection_name)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("Not found") {
-                    return vector_errors::collection_not_found(collection_name);
-                }
-                vector_errors::storage_failed(format!("Failed to get collection info: {e}"))
-            })?;
+    #[test]
+    fn test_search_pages() {
+        assert_eq!(QdrantAdapter::search_pages(10, 100), vec![(0, 10)]);
+        assert_eq!(QdrantAdapter::search_pages(100, 100), vec![(0, 100)]);
+        assert_eq!(
+            QdrantAdapter::search_pages(250, 100),
+            vec![(0, 100), (100, 100), (200, 50)]
+        );
+        assert!(QdrantAdapter::search_pages(0, 100).is_empty());
+
+        // A short page ends the search after reporting its results
+        let mut progress = Vec::new();
+        let mut results = Vec::new();
+        let mut on_batch = |count| progress.push(count);
+        assert!(QdrantAdapter::accept_search_page(
+            &mut results,
+            scored_results(&[0.9, 0.8]),
+            2,
+            &mut on_batch
+        ));
+        assert!(!QdrantAdapter::accept_search_page(
+            &mut results,
+            scored_results(&[0.7]),
+            2,
+            &mut on_batch
+        ));
+        assert_eq!(results.len(), 3);
+        assert_eq!(progress, vec![2, 3]);
+    }
 
-        if let Some(config_info) = info.result {
-            if let Some(vector_config) = config_info.config.and_then(|c| c.params) {
-                let (distance_metric, dimension) = match vector_config.vectors_config {
-                    Some(vc) => match vc.config {
-                        Some(qdrant_client::qdrant::vectors_config::Config::Params(params)) => {
-                            let distance = match Distance::try_from(params.distance) {
-                                Ok(Distance::Cosine) => DistanceMetric::Cosine,
-                                Ok(Distance::Euclid) => DistanceMetric::Euclidean,
-                                Ok(Distance::Dot) => DistanceMetric::DotProduct,
-                                Ok(Distance::Manhattan) => DistanceMetric::Manhattan,
-                                _ => DistanceMetric::Cosine,
-                            };
-                            (distance, params.size as usize)
-                        }
-                        _ => (DistanceMetric::Cosine, 768),
-                    },
-                    _ => (DistanceMetric::Cosine, 768),
-                };
+    #[test]
+    fn test_search_request_selectors() {
+        use qdrant_client::qdrant::{with_payload_selector, with_vectors_selector};
+
+        let params = SearchParams::with_limit(5).include_vectors();
+        let options = QdrantSearchOptions::new().include_payload(false);
+        let request = QdrantAdapter::build_search_request(
+            "documents",
+            vec![0.1, 0.2],
+            &params,
+            &options,
+            &QdrantConfig::default(),
+        );
 
-                let config = CollectionConfig::new_unchecked(
-                    collection_name.to_string(),
-                    dimension,
-                    distance_metric,
-                );
-                return Ok(Some(config));
-            }
-        }
-        Ok(None)
+        assert_eq!(
+            request.with_payload.and_then(|s| s.selector_options),
+            Some(with_payload_selector::SelectorOptions::Enable(false))
+        );
+        assert_eq!(
+            request.with_vectors.and_then(|s| s.selector_options),
+            Some(with_vectors_selector::SelectorOptions::Enable(true))
+        );
+
+        // Payload stays on by default for compatibility
+        let request = QdrantAdapter::build_search_request(
+            "documents",
+            vec![0.1, 0.2],
+            &SearchParams::with_limit(5),
+            &QdrantSearchOptions::default(),
+            &QdrantConfig::default(),
+        );
+        assert_eq!(
+            request.with_payload.and_then(|s| s.selector_options),
+            Some(with_payload_selector::SelectorOptions::Enable(true))
+        );
+        assert_eq!(
+            request.with_vectors.and_then(|s| s.selector_options),
+            Some(with_vectors_selector::SelectorOptions::Enable(false))
+        );
     }
 
-    /// Get collection statistics
-    async fn get_collection_stats(
-        &self,
-        collection_name: &str,
-    ) -> TylResult<HashMap<String, serde_json::Value>> {
-        let info = self
-            .client
-            .collection_info(collection_name)
-            .await
-            .map_err(|e| {
-                vector_errors::collection_not_found(format!("Collection info failed: {e}"))
-            })?;
+    #[test]
+    fn test_search_request_runtime_params() {
+        let params = SearchParams::with_limit(5);
+        let request = QdrantAdapter::build_search_request(
+            "documents",
+            vec![0.1, 0.2],
+            &params,
+            &QdrantSearchOptions::default(),
+            &QdrantConfig::default(),
+        );
+        assert!(request.params.is_none());
+
+        let options = QdrantSearchOptions::new().with_hnsw_ef(256).exact(true);
+        let request = QdrantAdapter::build_search_request(
+            "documents",
+            vec![0.1, 0.2],
+            &params,
+            &options,
+            &QdrantConfig::default(),
+        );
+        let runtime = request.params.unwrap();
+        assert_eq!(runtime.hnsw_ef, Some(256));
+        assert_eq!(runtime.exact, Some(true));
+
+        let options = QdrantSearchOptions::new().with_hnsw_ef(64);
+        let request = QdrantAdapter::build_search_request(
+            "documents",
+            vec![0.1, 0.2],
+            &params,
+            &options,
+            &QdrantConfig::default(),
+        );
+        let runtime = request.params.unwrap();
+        assert_eq!(runtime.hnsw_ef, Some(64));
+        assert_eq!(runtime.exact, None);
+    }
 
-        let mut stats = HashMap::new();
-        if let Some(result) = info.result {
-            stats.insert("status".to_string(), serde_json::json!(result.status));
-            if let Some(vectors_count) = result.vectors_count {
-                stats.insert(
-                    "vectors_count".to_string(),
-                    serde_json::json!(vectors_count),
-                );
+    #[test]
+    fn test_dedup_results_keeps_top_scorer_per_key() {
+        let chunk = |id: &str, doc_id: Option<&str>, score: f32| {
+            let mut vector = Vector::new(id.to_string(), vec![1.0, 0.0]);
+            if let Some(doc_id) = doc_id {
+                vector.add_metadata("doc_id", serde_json::json!(doc_id));
             }
-            stats.insert(
-                "segments_count".to_string(),
-                serde_json::json!(result.segments_count),
-            );
+            VectorSearchResult::new(vector, score)
+        };
+        let results = || {
+            vec![
+                chunk("a-1", Some("a"), 0.7),
+                chunk("b-1", Some("b"), 0.8),
+                chunk("a-2", Some("a"), 0.9),
+                chunk("loose", None, 0.5),
+                chunk("b-2", Some("b"), 0.6),
+            ]
+        };
+
+        let deduped = QdrantAdapter::dedup_results(results(), "doc_id", &DistanceMetric::Cosine);
+        let ids: Vec<_> = deduped.iter().map(|r| r.vector.id.as_str()).collect();
+        assert_eq!(ids, vec!["a-2", "b-1", "loose"]);
+
+        // Distances: the nearest chunk per document wins and the nearest comes first
+        let deduped = QdrantAdapter::dedup_results(results(), "doc_id", &DistanceMetric::Euclidean);
+        let ids: Vec<_> = deduped.iter().map(|r| r.vector.id.as_str()).collect();
+        assert_eq!(ids, vec!["loose", "b-2", "a-1"]);
+
+        let options = QdrantSearchOptions::new()
+            .with_payload_fields(["title"])
+            .with_dedup_by("doc_id");
+        match QdrantAdapter::search_payload_selector(&options, &QdrantConfig::default())
+            .selector_options
+        {
+            Some(qdrant_client::qdrant::with_payload_selector::SelectorOptions::Include(
+                selector,
+            )) => assert!(selector.fields.contains(&"doc_id".to_string())),
+            other => panic!("expected include selector, got {other:?}"),
         }
-        Ok(stats)
     }
-}
 
-#[async_trait]
-impl VectorStoreHealth for QdrantAdapter {
-    /// Check if Qdrant is healthy
-    async fn is_healthy(&self) -> TylResult<bool> {
-        match self.client.health_check().await {
-            Ok(_) => Ok(true),
-            Err(_) => Ok(false),
+    #[test]
+    fn test_lookup_search_request_references_stored_vector() {
+        use qdrant_client::qdrant::{query, vector_input};
+
+        let params = SearchParams::with_limit(5)
+            .with_threshold(0.3)
+            .with_filter("lang", serde_json::json!("en"));
+        let request = QdrantAdapter::build_lookup_search_request(
+            "documents",
+            "queries",
+            PointId::from(7u64),
+            &params,
+        );
+
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(
+            request.lookup_from.map(|l| l.collection_name),
+            Some("queries".to_string())
+        );
+        match request.query.and_then(|q| q.variant) {
+            Some(query::Variant::Nearest(input)) => assert_eq!(
+                input.variant,
+                Some(vector_input::Variant::Id(PointId::from(7u64)))
+            ),
+            other => panic!("expected nearest query by ID, got {other:?}"),
         }
+        assert_eq!(request.limit, Some(5));
+        assert_eq!(request.score_threshold, Some(0.3));
+        assert_eq!(request.filter.map(|f| f.must.len()), Some(1));
     }
 
-    /// Get detailed health information
-    async fn health_check(&self) -> TylResult<HashMap<String, serde_json::Value>> {
-        let mut health_data = HashMap::new();
+    #[test]
+    fn test_search_request_payload_fields() {
+        use qdrant_client::qdrant::with_payload_selector::SelectorOptions;
+
+        let options = QdrantSearchOptions::new().with_payload_fields(["title", "url"]);
+        let request = QdrantAdapter::build_search_request(
+            "documents",
+            vec![0.1, 0.2],
+            &SearchParams::with_limit(5),
+            &options,
+            &QdrantConfig::default(),
+        );
 
-        match self.client.health_check().await {
-            Ok(_) => {
-                health_data.insert("status".to_string(), serde_json::json!("healthy"));
-                health_data.insert("qdrant_url".to_string(), serde_json::json!(self.config.url));
-                Ok(health_data)
-            }
-            Err(e) => {
-                health_data.insert("status".to_string(), serde_json::json!("unhealthy"));
-                health_data.insert("error".to_string(), serde_json::json!(e.to_string()));
-                Ok(health_data)
+        match request.with_payload.and_then(|s| s.selector_options) {
+            Some(SelectorOptions::Include(selector)) => {
+                assert!(selector.fields.contains(&"title".to_string()));
+                assert!(selector.fields.contains(&"url".to_string()));
+                assert!(selector
+                    .fields
+                    .contains(&ORIGINAL_ID_PAYLOAD_KEY.to_string()));
             }
+            other => panic!("expected include selector, got {other:?}"),
         }
+
+        // Disabling payload wins over a field list
+        let options = options.include_payload(false);
+        assert_eq!(
+            QdrantAdapter::search_payload_selector(&options, &QdrantConfig::default())
+                .selector_options,
+            Some(SelectorOptions::Enable(false))
+        );
     }
-}
 
-#[async_trait]
-impl VectorDatabase for QdrantAdapter {
-    type Config = QdrantConfig;
+    #[test]
+    fn test_distance_metric_check() {
+        assert!(QdrantAdapter::check_distance_metric(
+            "docs",
+            &DistanceMetric::Cosine,
+            &DistanceMetric::Cosine
+        )
+        .is_ok());
 
-    /// Connect to Qdrant database
-    async fn connect(config: Self::Config) -> VectorResult<Self>
-    where
-        Self: Sized,
-    {
-        Self::new(config).await
+        let err = QdrantAdapter::check_distance_metric(
+            "docs",
+            &DistanceMetric::DotProduct,
+            &DistanceMetric::Cosine,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Cosine"));
+        assert!(err.to_string().contains("Dot"));
     }
 
-    /// Get connection information
-    fn connection_info(&self) -> String {
-        format!("Qdrant at {}", self.config.url)
+    #[test]
+    fn test_clear_request_matches_all_points() {
+        use qdrant_client::qdrant::points_selector::PointsSelectorOneOf;
+
+        let request = QdrantAdapter::build_clear_request("documents");
+        assert_eq!(request.collection_name, "documents");
+        assert_eq!(request.wait, Some(true));
+        match request.points.and_then(|p| p.points_selector_one_of) {
+            Some(PointsSelectorOneOf::Filter(filter)) => {
+                assert!(filter.must.is_empty());
+                assert!(filter.should.is_empty());
+                assert!(filter.must_not.is_empty());
+            }
+            other => panic!("expected match-all filter, got {other:?}"),
+        }
     }
 
-    /// Close the connection
-    async fn close(&mut self) -> VectorResult<()> {
-        // Qdrant client doesn't require explicit closing
-        Ok(())
-    }
+    #[test]
+    fn test_config_env_loading() {
+        // Test environment variable loading
+        std::env::set_var("TYL_QDRANT_URL", "http://test:6333");
+        std::env::set_var("TYL_QDRANT_TIMEOUT_SECONDS", "60");
+        std::env::set_var("TYL_QDRANT_MAX_BATCH_SIZE", "200");
+        std::env::set_var(
+            "TYL_QDRANT_FILTER_INDEX_TYPES",
+            "category:keyword, price:float",
+        );
 
-    /// Check feature support
-    fn supports_feature(&self, feature: &str) -> bool {
-        matches!(
-            feature,
-            "collections" | "health_check" | "batch_operations" | "filtering" | "payload"
-        )
-    }
-}
+        let mut config = QdrantConfig::default();
+        config.merge_env().unwrap();
 
-/// Qdrant-specific error helpers following TYL framework patterns
-pub mod qdrant_errors {
-    use super::*;
+        assert_eq!(config.url, "http://test:6333");
+        assert_eq!(config.timeout_seconds, 60);
+        assert_eq!(config.max_batch_size, 200);
+        assert_eq!(
+            config.filter_index_types,
+            HashMap::from([
+                ("category".to_string(), PayloadIndexType::Keyword),
+                ("price".to_string(), PayloadIndexType::Float),
+            ])
+        );
 
-    /// Create a Qdrant connection error
-    pub fn connection_failed(message: impl Into<String>) -> TylError {
-        let message = message.into();
-        vector_errors::connection_failed(format!("Qdrant: {message}"))
-    }
+        std::env::set_var("TYL_QDRANT_FILTER_INDEX_TYPES", "category");
+        assert!(QdrantConfig::default().merge_env().is_err());
 
-    /// Create a Qdrant API error
-    pub fn api_error(message: impl Into<String>) -> TylError {
-        let message = message.into();
-        TylError::network(format!("Qdrant API error: {message}"))
+        // Cleanup
+        std::env::remove_var("TYL_QDRANT_URL");
+        std::env::remove_var("TYL_QDRANT_TIMEOUT_SECONDS");
+        std::env::remove_var("TYL_QDRANT_MAX_BATCH_SIZE");
+        std::env::remove_var("TYL_QDRANT_FILTER_INDEX_TYPES");
     }
 
-    /// Collection creation failed with specific reason
-    pub fn collection_creation_failed(name: &str, reason: impl Into<String>) -> TylError {
-        let reason = reason.into();
-        TylError::database(format!(
-            "Failed to create Qdrant collection '{name}': {reason}"
-        ))
+    #[cfg(feature = "logging")]
+    #[tokio::test]
+    async fn test_operations_log_through_injected_logger_at_configured_level() {
+        let vector = || Vector::new("1".to_string(), vec![1.0, 0.0]);
+
+        let (adapter, records) = capturing_adapter(QdrantConfig::default());
+        assert!(adapter.store_vector("docs", vector()).await.is_err());
+        {
+            let records = records.lock().unwrap();
+            assert_eq!(records.len(), 2, "{records:?}");
+            assert!(records[0].contains("qdrant_store_vector - Storing vector '1'"));
+            assert!(records[1].contains("Failed qdrant_store_vector"));
+        }
+
+        // At error level only the failure is logged
+        let (adapter, records) = capturing_adapter(QdrantConfig {
+            log_level: QdrantLogLevel::Error,
+            ..QdrantConfig::default()
+        });
+        assert!(adapter.store_vector("docs", vector()).await.is_err());
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 1, "{records:?}");
+        assert!(records[0].contains("Failed qdrant_store_vector"));
     }
 
-    /// Vector dimension mismatch error
-    pub fn vector_dimension_mismatch(expected: usize, actual: usize) -> TylError {
-        TylError::validation(
-            "vector_dimension",
-            format!("Expected {expected}, got {actual}"),
-        )
+    #[cfg(all(feature = "logging", not(feature = "tracing")))]
+    #[tokio::test]
+    async fn test_telemetry_logs_without_tracing() {
+        let (adapter, records) = capturing_adapter(QdrantConfig::default());
+        let result = adapter
+            .with_telemetry("qdrant_test_operation", "No spans", async { Ok(7) })
+            .await;
+        assert_eq!(result.unwrap(), 7);
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 2, "{records:?}");
+        assert!(records[0].contains("qdrant_test_operation - No spans"));
+        assert!(records[1].contains("Completed qdrant_test_operation"));
     }
 
-    /// Index optimization failed
-    pub fn index_optimization_failed(collection: &str, reason: impl Into<String>) -> TylError {
-        let reason = reason.into();
-        TylError::database(format!(
-            "Index optimization failed for collection '{collection}': {reason}"
-        ))
+    #[cfg(not(feature = "logging"))]
+    #[tokio::test]
+    async fn test_telemetry_without_logging() {
+        let client = Qdrant::from_url("http://127.0.0.1:1")
+            .skip_compatibility_check()
+            .build()
+            .unwrap();
+        let adapter = QdrantAdapter::from_client(client, QdrantConfig::default());
+        let result = adapter
+            .with_telemetry("qdrant_test_operation", "No logger", async { Ok(7) })
+            .await;
+        assert_eq!(result.unwrap(), 7);
     }
 
-    /// Collection not ready for operations
-    pub fn collection_not_ready(collection: &str, status: &str) -> TylError {
-        TylError::database(format!(
-            "Collection '{collection}' is not ready (status: {status})"
-        ))
+    #[test]
+    fn test_log_level_filtering() {
+        // Debug records are suppressed at the default Info level
+        let level = QdrantConfig::default().log_level;
+        assert_eq!(level, QdrantLogLevel::Info);
+        assert!(!level.enables(QdrantLogLevel::Debug));
+        assert!(level.enables(QdrantLogLevel::Info));
+        assert!(level.enables(QdrantLogLevel::Error));
+
+        assert!(QdrantLogLevel::Debug.enables(QdrantLogLevel::Debug));
+        assert!(!QdrantLogLevel::Error.enables(QdrantLogLevel::Warn));
     }
 
-    /// Batch operation validation error
-    pub fn batch_size_exceeded(size: usize, max_size: usize) -> TylError {
-        TylError::validation(
-            "batch_size",
-            format!("Size {size} exceeds maximum {max_size}"),
-        )
+    #[test]
+    fn test_log_settings_parsing() {
+        assert_eq!(
+            "DEBUG".parse::<QdrantLogLevel>().unwrap(),
+            QdrantLogLevel::Debug
+        );
+        assert_eq!(
+            "warning".parse::<QdrantLogLevel>().unwrap(),
+            QdrantLogLevel::Warn
+        );
+        assert!("verbose".parse::<QdrantLogLevel>().is_err());
+
+        assert_eq!(
+            "text".parse::<QdrantLogFormat>().unwrap(),
+            QdrantLogFormat::Text
+        );
+        assert_eq!(QdrantConfig::default().log_format, QdrantLogFormat::Json);
+        assert!("yaml".parse::<QdrantLogFormat>().is_err());
+
+        let config: QdrantConfig = serde_json::from_value(serde_json::json!({
+            "url": "http://localhost:6334",
+            "api_key": null,
+            "timeout_seconds": 30,
+            "max_batch_size": 100,
+            "enable_compression": true,
+            "retry_attempts": 3,
+            "retry_delay_ms": 1000,
+            "default_shard_number": 1,
+            "default_replication_factor": 1,
+            "log_level": "error",
+            "log_format": "text"
+        }))
+        .unwrap();
+        assert_eq!(config.log_level, QdrantLogLevel::Error);
+        assert_eq!(config.log_format, QdrantLogFormat::Text);
     }
 
-    /// Point ID conversion error
-    pub fn invalid_point_id(id: &str, reason: impl Into<String>) -> TylError {
-        let reason = reason.into();
-        TylError::validation("point_id", format!("Invalid ID '{id}': {reason}"))
+    #[test]
+    fn test_circuit_breaker_transitions() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(10));
+        let start = Instant::now();
+        assert_eq!(breaker.state(start), CircuitState::Closed);
+
+        // A success resets the consecutive failure count
+        breaker.record_failure(start);
+        breaker.record_failure(start);
+        breaker.record_success();
+        breaker.record_failure(start);
+        breaker.record_failure(start);
+        assert_eq!(breaker.state(start), CircuitState::Closed);
+        assert!(breaker.try_admit(start).is_some());
+
+        // Closed -> open at the threshold
+        breaker.record_failure(start);
+        assert_eq!(breaker.state(start), CircuitState::Open);
+        assert!(breaker.try_admit(start + Duration::from_secs(9)).is_none());
+
+        // Open -> half-open after the cooldown, admitting a single probe at a time
+        let half_open_at = start + Duration::from_secs(10);
+        assert_eq!(breaker.state(half_open_at), CircuitState::HalfOpen);
+        let probe = breaker.try_admit(half_open_at).unwrap();
+        assert!(breaker.try_admit(half_open_at).is_none());
+        drop(probe);
+        let probe = breaker.try_admit(half_open_at).unwrap();
+
+        // A probe that fails to reach Qdrant re-opens
+        probe.finish(false, true);
+        assert_eq!(
+            breaker.state(half_open_at + Duration::from_secs(5)),
+            CircuitState::Open
+        );
+
+        // An error that is not a transport failure only frees the probe slot
+        let retry_at = Instant::now() + Duration::from_secs(10);
+        assert_eq!(breaker.state(retry_at), CircuitState::HalfOpen);
+        breaker.try_admit(retry_at).unwrap().finish(false, false);
+        assert_eq!(breaker.state(retry_at), CircuitState::HalfOpen);
+
+        // Half-open -> closed on a successful probe
+        breaker.try_admit(retry_at).unwrap().finish(true, false);
+        assert_eq!(breaker.state(retry_at), CircuitState::Closed);
     }
 
-    /// Search parameter validation error
-    pub fn invalid_search_params(reason: impl Into<String>) -> TylError {
-        TylError::validation("search_params", reason.into())
+    #[test]
+    fn test_transport_failure_classification() {
+        let io = qdrant_client::QdrantError::Io(std::io::Error::from(
+            std::io::ErrorKind::ConnectionRefused,
+        ));
+        assert!(circuit_breaker::is_transport_failure(&io));
+
+        let conversion = qdrant_client::QdrantError::ConversionError("bad vector".to_string());
+        assert!(!circuit_breaker::is_transport_failure(&conversion));
+        assert!(!circuit_breaker::is_transport_failure(
+            &qdrant_errors::invalid_search_params("bad filter")
+        ));
     }
-}
 
-// Mock implementation for testing
-#[cfg(feature = "mock")]
-pub mod mock;
+    #[tokio::test]
+    async fn test_circuit_breaker_counts_only_transport_failures_once_per_operation() {
+        // Nothing listens on port 1, so every request fails to connect
+        let config = QdrantConfig {
+            url: "http://127.0.0.1:1".to_string(),
+            timeout_seconds: 1,
+            circuit_breaker_threshold: 2,
+            ..QdrantConfig::default()
+        };
+        let client = Qdrant::from_url(&config.url)
+            .skip_compatibility_check()
+            .build()
+            .unwrap();
+        let adapter = QdrantAdapter::from_client(client, config);
+        let archived =
+            || SearchParams::with_limit(10).with_filter("status", serde_json::json!("archived"));
+
+        // Invalid input never reaches Qdrant and is not counted
+        for _ in 0..3 {
+            assert!(adapter
+                .delete_by_filter("docs", SearchParams::with_limit(10), false)
+                .await
+                .is_err());
+        }
+        assert_eq!(adapter.circuit_state(), CircuitState::Closed);
 
-#[cfg(feature = "mock")]
-pub use mock::MockQdrantAdapter;
+        // delete_by_filter fails inside its nested count_matching; that is one failure
+        assert!(adapter
+            .delete_by_filter("docs", archived(), false)
+            .await
+            .is_err());
+        assert_eq!(adapter.circuit_state(), CircuitState::Closed);
 
-// Schema migration tools with Pact.io validation
-#[cfg(feature = "schema-migration")]
-pub mod migration;
+        assert!(adapter.count_matching("docs", archived()).await.is_err());
+        assert_eq!(adapter.circuit_state(), CircuitState::Open);
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[tokio::test]
+    async fn test_wait_until_ready_reports_transport_failures() {
+        // Nothing listens on port 1, so the status poll fails to connect
+        let config = QdrantConfig {
+            url: "http://127.0.0.1:1".to_string(),
+            timeout_seconds: 1,
+            ..QdrantConfig::default()
+        };
+        let client = Qdrant::from_url(&config.url)
+            .skip_compatibility_check()
+            .build()
+            .unwrap();
+        let adapter = QdrantAdapter::from_client(client, config);
+
+        let error = adapter
+            .wait_until_ready("docs", Duration::from_millis(50))
+            .await
+            .unwrap_err();
+        assert_ne!(
+            error.to_string(),
+            vector_errors::collection_not_found("docs").to_string()
+        );
+        assert!(
+            error.to_string().contains("Collection info failed"),
+            "{error}"
+        );
+    }
 
-    #[test]
-    fn test_qdrant_config_creation() {
-        let config = QdrantConfig::default();
-        assert_eq!(config.url, "http://localhost:6333");
-        assert_eq!(config.timeout_seconds, 30);
-        assert_eq!(config.max_batch_size, 100);
-        assert!(config.enable_compression);
-        assert!(config.validate().is_ok());
+    #[tokio::test]
+    async fn test_circuit_breaker_covers_port_operations() {
+        // Nothing listens on port 1, so every request fails to connect
+        let config = QdrantConfig {
+            url: "http://127.0.0.1:1".to_string(),
+            timeout_seconds: 1,
+            circuit_breaker_threshold: 1,
+            circuit_breaker_cooldown_ms: 60_000,
+            ..QdrantConfig::default()
+        };
+        let client = Qdrant::from_url(&config.url)
+            .skip_compatibility_check()
+            .build()
+            .unwrap();
+        let adapter = QdrantAdapter::from_client(client, config);
+
+        // A port method's transport failure counts towards opening the breaker
+        assert!(adapter.get_collection_info("docs").await.is_err());
+        assert_eq!(adapter.circuit_state(), CircuitState::Open);
+
+        // While open, port methods fail fast instead of reaching for the network
+        let error = adapter
+            .store_vectors_batch("docs", vec![Vector::new("1".to_string(), vec![1.0])])
+            .await
+            .unwrap_err();
+        assert!(
+            error.to_string().contains("Circuit breaker open"),
+            "{error}"
+        );
+        let error = adapter.get_vector("docs", "1").await.unwrap_err();
+        assert!(
+            error.to_string().contains("Circuit breaker open"),
+            "{error}"
+        );
     }
 
-    #[test]
-    fn test_qdrant_config_validation() {
-        let mut config = QdrantConfig::default();
-        assert!(config.validate().is_ok());
+    #[tokio::test]
+    async fn test_connection_diagnostics_bypass_circuit_breaker() {
+        // Nothing listens on port 1, so every request fails to connect
+        let config = QdrantConfig {
+            url: "http://127.0.0.1:1".to_string(),
+            timeout_seconds: 1,
+            circuit_breaker_threshold: 1,
+            circuit_breaker_cooldown_ms: 1,
+            ..QdrantConfig::default()
+        };
+        let client = Qdrant::from_url(&config.url)
+            .skip_compatibility_check()
+            .build()
+            .unwrap();
+        let adapter = QdrantAdapter::from_client(client, config);
+
+        assert!(adapter.get_collection_info("docs").await.is_err());
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(adapter.circuit_state(), CircuitState::HalfOpen);
+
+        // Diagnostics report the outage without taking the probe or closing the breaker
+        let diagnostics = adapter.connection_diagnostics().await.unwrap();
+        assert!(!diagnostics.healthy);
+        assert_eq!(diagnostics.server_version, None);
+        assert_eq!(diagnostics.collection_count, None);
+        assert_eq!(adapter.circuit_state(), CircuitState::HalfOpen);
+    }
 
-        // Test empty URL
-        config.url = String::new();
-        assert!(config.validate().is_err());
+    #[test]
+    fn test_circuit_breaker_config() {
+        let config = QdrantConfig::default();
+        assert!(CircuitBreaker::from_config(&config).is_none());
 
-        // Test zero timeout
-        config.url = "http://localhost:6333".to_string();
-        config.timeout_seconds = 0;
-        assert!(config.validate().is_err());
+        let mut config = QdrantConfig {
+            circuit_breaker_threshold: 5,
+            ..QdrantConfig::default()
+        };
+        assert!(CircuitBreaker::from_config(&config).is_some());
+        assert!(config.validate().is_ok());
 
-        // Test zero batch size
-        config.timeout_seconds = 30;
-        config.max_batch_size = 0;
+        config.circuit_breaker_cooldown_ms = 0;
         assert!(config.validate().is_err());
     }
 
     #[test]
-    fn test_distance_metric_conversion() {
+    fn test_reserved_payload_keys() {
+        // Every internal key lives in the default reserved namespace
+        let config = QdrantConfig::default();
+        assert_eq!(config.original_id_key(), ORIGINAL_ID_PAYLOAD_KEY);
+        assert_eq!(config.content_type_key(), CONTENT_TYPE_PAYLOAD_KEY);
+        assert_eq!(config.embedding_model_key(), EMBEDDING_MODEL_PAYLOAD_KEY);
+        assert_eq!(config.reserved_key("checksum"), "__tyl_checksum");
+        assert!(config.is_reserved_key(ALLOWED_IDS_FILTER_KEY));
+        assert!(!config.is_reserved_key("title"));
+
+        let mut vector = Vector::new("doc".to_string(), vec![1.0]);
+        vector.add_metadata("title", serde_json::json!("Intro"));
+        vector.add_metadata(CONTENT_TYPE_PAYLOAD_KEY, serde_json::json!("text"));
+        assert!(QdrantAdapter::validate_reserved_keys(&vector, &config).is_ok());
+
+        vector.add_metadata("__tyl_secret", serde_json::json!(1));
+        let error = QdrantAdapter::validate_reserved_keys(&vector, &config).unwrap_err();
+        assert!(error.to_string().contains("'__tyl_secret'"));
+
+        let mut vector = Vector::new("doc".to_string(), vec![1.0]);
+        vector.add_metadata(ORIGINAL_ID_PAYLOAD_KEY, serde_json::json!("other"));
+        assert!(QdrantAdapter::validate_reserved_keys(&vector, &config).is_err());
+
+        // A custom prefix moves every internal key, and frees the default namespace
+        let custom = QdrantConfig {
+            reserved_payload_prefix: "_internal_".to_string(),
+            hash_string_ids: true,
+            ..QdrantConfig::default()
+        };
+        assert_eq!(custom.original_id_key(), "_internal_original_id");
+        assert_eq!(custom.content_type_key(), "_internal_content_type");
+        assert_eq!(custom.embedding_model_key(), "_internal_embedding_model");
+        assert!(!custom.is_reserved_key("__tyl_secret"));
+
+        let mut vector = Vector::new("doc".to_string(), vec![1.0]);
+        vector.add_metadata("__tyl_secret", serde_json::json!(1));
+        vector.add_metadata("_internal_content_type", serde_json::json!("text"));
+        assert!(QdrantAdapter::validate_reserved_keys(&vector, &custom).is_ok());
+        vector.add_metadata("_internal_flag", serde_json::json!(true));
+        let error = QdrantAdapter::validate_reserved_keys(&vector, &custom).unwrap_err();
+        assert!(error.to_string().contains("'_internal_'"));
+
+        let point = QdrantAdapter::vector_to_point_struct(Vector::new("doc_1", vec![1.0]), &custom)
+            .unwrap();
+        assert!(point.payload.contains_key("_internal_original_id"));
+        assert!(!point.payload.contains_key(ORIGINAL_ID_PAYLOAD_KEY));
         assert_eq!(
-            QdrantAdapter::distance_metric_to_qdrant(&DistanceMetric::Cosine),
-            qdrant_client::qdrant::Distance::Cosine
+            round_trip(Vector::new("doc_1", vec![1.0]), &custom).id,
+            "doc_1"
         );
-        assert_eq!(
-            QdrantAdapter::distance_metric_to_qdrant(&DistanceMetric::Euclidean),
-            qdrant_client::qdrant::Distance::Euclid
+
+        let empty = QdrantConfig {
+            reserved_payload_prefix: String::new(),
+            ..QdrantConfig::default()
+        };
+        assert!(empty.validate().is_err());
+        let dotted = QdrantConfig {
+            reserved_payload_prefix: "tyl.".to_string(),
+            ..QdrantConfig::default()
+        };
+        assert!(dotted.validate().is_err());
+    }
+
+    #[test]
+    fn test_vector_from_embedding_records_model_and_content_type() {
+        let embedding = Embedding::new(
+            vec![0.25, 0.5, 0.75],
+            "stub-model".to_string(),
+            ContentType::Code,
         );
+        let metadata = HashMap::from([("title".to_string(), serde_json::json!("Intro"))]);
+
+        let config = QdrantConfig::default();
+        let vector = vector_from_embedding("doc-1", embedding, metadata, &config);
+        assert_eq!(vector.id, "doc-1");
+        assert_eq!(vector.embedding, vec![0.25, 0.5, 0.75]);
+        assert_eq!(vector.metadata.len(), 3);
+        assert_eq!(vector.metadata["title"], serde_json::json!("Intro"));
         assert_eq!(
-            QdrantAdapter::distance_metric_to_qdrant(&DistanceMetric::DotProduct),
-            qdrant_client::qdrant::Distance::Dot
+            vector.metadata[EMBEDDING_MODEL_PAYLOAD_KEY],
+            serde_json::json!("stub-model")
         );
         assert_eq!(
-            QdrantAdapter::distance_metric_to_qdrant(&DistanceMetric::Manhattan),
-            qdrant_client::qdrant::Distance::Manhattan
+            vector.metadata[CONTENT_TYPE_PAYLOAD_KEY],
+            serde_json::json!("code")
         );
-    }
 
-    #[test]
-    fn test_vector_to_point_conversion() {
-        let mut vector = Vector::new("test-id", vec![0.1, 0.2, 0.3]);
-        vector.add_metadata("category", serde_json::json!("test"));
-
-        let point = QdrantAdapter::vector_to_point_struct(vector.clone());
+        // The recorded keys are reserved but may be stored
+        assert!(QdrantAdapter::validate_reserved_keys(&vector, &config).is_ok());
 
-        // Verify the conversion worked (basic checks without deep inspection)
-        assert!(!point.payload.is_empty());
-        assert!(point.payload.contains_key("category"));
+        // Stored labels are fixed strings, independent of how `ContentType` formats
+        assert_eq!(content_type_label(&ContentType::Text), "text");
+        assert_eq!(content_type_label(&ContentType::Code), "code");
     }
 
     #[test]
-    fn test_config_env_loading() {
-        // Test environment variable loading
-        std::env::set_var("TYL_QDRANT_URL", "http://test:6333");
-        std::env::set_var("TYL_QDRANT_TIMEOUT_SECONDS", "60");
-        std::env::set_var("TYL_QDRANT_MAX_BATCH_SIZE", "200");
+    fn test_default_search_params() {
+        let params = QdrantConfig::default().default_search_params();
+        assert_eq!(params.limit, 10);
+        assert_eq!(params.threshold, None);
+
+        let config = QdrantConfig {
+            default_search_limit: 3,
+            default_search_threshold: Some(0.75),
+            ..QdrantConfig::default()
+        };
+        let params = config.default_search_params();
+        assert_eq!(params.limit, 3);
+        assert_eq!(params.threshold, Some(0.75));
+        assert!(params.filters.is_empty());
+
+        let invalid = QdrantConfig {
+            default_search_limit: 0,
+            ..QdrantConfig::default()
+        };
+        assert!(invalid.validate().is_err());
+    }
 
-        let mut config = QdrantConfig::default();
-        config.merge_env().unwrap();
+    #[test]
+    fn test_collection_prefix() {
+        let config = QdrantConfig::default();
+        assert_eq!(config.qualified_collection_name("docs"), "docs");
+        assert_eq!(config.logical_collection_name("docs"), Some("docs"));
 
-        assert_eq!(config.url, "http://test:6333");
-        assert_eq!(config.timeout_seconds, 60);
-        assert_eq!(config.max_batch_size, 200);
+        let config = QdrantConfig {
+            collection_prefix: Some("billing_".to_string()),
+            ..QdrantConfig::default()
+        };
+        assert!(config.validate().is_ok());
+        assert_eq!(config.qualified_collection_name("docs"), "billing_docs");
+        assert_eq!(config.logical_collection_name("billing_docs"), Some("docs"));
+        assert_eq!(config.logical_collection_name("search_docs"), None);
+
+        // Requests target the prefixed name
+        let collection = CollectionConfig::new("docs", 4, DistanceMetric::Cosine).unwrap();
+        let request = QdrantAdapter::build_create_collection_request(
+            &collection,
+            &QdrantCollectionOptions::new(),
+            &config,
+        );
+        assert_eq!(request.collection_name, "billing_docs");
 
-        // Cleanup
-        std::env::remove_var("TYL_QDRANT_URL");
-        std::env::remove_var("TYL_QDRANT_TIMEOUT_SECONDS");
-        std::env::remove_var("TYL_QDRANT_MAX_BATCH_SIZE");
+        let empty = QdrantConfig {
+            collection_prefix: Some(String::new()),
+            ..QdrantConfig::default()
+        };
+        assert!(empty.validate().is_err());
     }
 
     #[test]
@@ -1505,6 +10108,13 @@ mod tests {
             .to_string()
             .contains("Size 1000 exceeds maximum 100"));
 
+        // Oversized store_vectors_batch calls report the same format
+        assert!(QdrantAdapter::check_batch_size(100, 100).is_ok());
+        let oversized = QdrantAdapter::check_batch_size(101, 100).unwrap_err();
+        assert!(oversized
+            .to_string()
+            .contains("Size 101 exceeds maximum 100"));
+
         let collection_error =
             qdrant_errors::collection_creation_failed("docs", "Permission denied");
         assert!(collection_error
@@ -216,7 +216,22 @@ where
     }
 
     /// Apply migration with Pact validation
+    ///
+    /// Progress is recorded after each change, so a migration that fails partway can be
+    /// finished with [`resume_migration`](Self::resume_migration) once the cause is fixed.
+    /// Until then it is left out of the history and does not satisfy dependencies, and applying
+    /// it again is rejected so its progress is not lost.
     pub async fn apply_migration(&self, migration: SchemaMigration) -> TylResult<MigrationResult> {
+        if let Some((_, Some(applied))) = self.load_migration_record(&migration.version).await? {
+            return Err(TylError::validation(
+                "migration",
+                format!(
+                    "Migration {} stopped after {applied} changes; use resume_migration",
+                    migration.version
+                ),
+            ));
+        }
+
         // 1. Validate Pact contracts first
         self.validate_pact_contracts(&migration.pact_contracts)
             .await?;
@@ -224,12 +239,9 @@ where
         // 2. Check migration dependencies
         self.validate_dependencies(&migration).await?;
 
-        // 3. Apply collection changes
-        let mut results = Vec::new();
-        for change in &migration.collection_changes {
-            let result = self.apply_collection_change(change).await?;
-            results.push(result);
-        }
+        // 3. Apply collection changes, recording progress after each one
+        self.record_progress(&migration, 0).await?;
+        let results = self.apply_changes_from(&migration, 0).await?;
 
         // 4. Record migration in tracking collection
         self.record_migration(&migration).await?;
@@ -241,9 +253,34 @@ where
         })
     }
 
+    /// Finish a migration that failed partway, skipping the changes already applied
+    ///
+    /// Only the changes applied by this call are returned, so resuming a completed migration
+    /// returns none. Pact contracts and dependencies were validated by `apply_migration`.
+    pub async fn resume_migration(&self, version: semver::Version) -> TylResult<MigrationResult> {
+        let (migration, progress) = self.get_migration_progress(&version).await?;
+
+        let results = match progress {
+            Some(applied) => {
+                let results = self.apply_changes_from(&migration, applied).await?;
+                self.record_migration(&migration).await?;
+                results
+            }
+            None => Vec::new(),
+        };
+
+        Ok(MigrationResult {
+            version: migration.version,
+            applied_changes: results,
+            pact_validation_passed: true,
+        })
+    }
+
     /// Rollback migration if reversible
+    ///
+    /// A migration that failed partway only has its applied changes reversed.
     pub async fn rollback_migration(&self, version: semver::Version) -> TylResult<()> {
-        let migration = self.get_migration_record(&version).await?;
+        let (migration, progress) = self.get_migration_progress(&version).await?;
 
         if !migration.metadata.reversible {
             return Err(TylError::validation(
@@ -253,7 +290,8 @@ where
         }
 
         // Apply reverse changes
-        for change in migration.collection_changes.iter().rev() {
+        let applied = progress.unwrap_or(migration.collection_changes.len());
+        for change in migration.collection_changes[..applied].iter().rev() {
             self.apply_reverse_change(change).await?;
         }
 
@@ -264,6 +302,8 @@ where
     }
 
     /// Get migration history
+    ///
+    /// Migrations that failed partway are not included until they are resumed.
     pub async fn get_migration_history(&self) -> TylResult<Vec<SchemaMigration>> {
         // Query migration collection for all applied migrations
         let search_params = SearchParams::with_limit(1000); // Large limit to get all
@@ -278,6 +318,9 @@ where
 
         let mut migrations = Vec::new();
         for result in results {
+            if result.vector.metadata.contains_key("applied_changes") {
+                continue;
+            }
             if let Some(migration_data) = result.vector.metadata.get("migration") {
                 if let Ok(migration) =
                     serde_json::from_value::<SchemaMigration>(migration_data.clone())
@@ -367,7 +410,16 @@ where
             VectorOperation::CreateCollection => {
                 // Test collection creation with unique name to avoid conflicts
                 let test_name = format!("test_migration_{}", uuid::Uuid::new_v4().simple());
-                let test_config = CollectionConfig::new(&test_name, 128, DistanceMetric::Cosine)?;
+                let distance_metric = match interaction
+                    .request
+                    .parameters
+                    .get("distance_metric")
+                    .and_then(|metric| metric.as_str())
+                {
+                    Some(metric) => QdrantAdapter::distance_metric_from_str(metric)?,
+                    None => DistanceMetric::Cosine,
+                };
+                let test_config = CollectionConfig::new(&test_name, 128, distance_metric)?;
                 let result = self.adapter.create_collection(test_config).await;
                 self.validate_operation_result(result, &interaction.response)?;
 
@@ -559,10 +611,41 @@ where
         }
     }
 
+    /// Apply the changes of `migration` from index `start`, recording progress after each one
+    async fn apply_changes_from(
+        &self,
+        migration: &SchemaMigration,
+        start: usize,
+    ) -> TylResult<Vec<ChangeResult>> {
+        let mut results = Vec::new();
+        for (index, change) in migration.collection_changes.iter().enumerate().skip(start) {
+            results.push(self.apply_collection_change(change).await?);
+            self.record_progress(migration, index + 1).await?;
+        }
+        Ok(results)
+    }
+
     async fn record_migration(&self, migration: &SchemaMigration) -> TylResult<()> {
+        self.store_migration_record(migration, None).await
+    }
+
+    /// Record that the first `applied` changes of `migration` have been applied
+    async fn record_progress(&self, migration: &SchemaMigration, applied: usize) -> TylResult<()> {
+        self.store_migration_record(migration, Some(applied)).await
+    }
+
+    /// Store the record of `migration`; `applied` marks it in progress after that many changes
+    async fn store_migration_record(
+        &self,
+        migration: &SchemaMigration,
+        applied: Option<usize>,
+    ) -> TylResult<()> {
         let mut metadata = HashMap::new();
         metadata.insert("migration".to_string(), serde_json::to_value(migration)?);
         metadata.insert("type".to_string(), serde_json::json!("schema_migration"));
+        if let Some(applied) = applied {
+            metadata.insert("applied_changes".to_string(), serde_json::json!(applied));
+        }
 
         let migration_vector = Vector::with_metadata(
             migration.version.to_string(),
@@ -575,7 +658,21 @@ where
             .await
     }
 
-    async fn get_migration_record(&self, version: &semver::Version) -> TylResult<SchemaMigration> {
+    /// The recorded migration, with the number of applied changes if it is still in progress
+    async fn get_migration_progress(
+        &self,
+        version: &semver::Version,
+    ) -> TylResult<(SchemaMigration, Option<usize>)> {
+        self.load_migration_record(version)
+            .await?
+            .ok_or_else(|| TylError::not_found("migration", version.to_string()))
+    }
+
+    /// Like `get_migration_progress`, with `None` if `version` has no record
+    async fn load_migration_record(
+        &self,
+        version: &semver::Version,
+    ) -> TylResult<Option<(SchemaMigration, Option<usize>)>> {
         let vector = self
             .adapter
             .get_vector(&self.migration_collection, &version.to_string())
@@ -583,12 +680,18 @@ where
 
         if let Some(v) = vector {
             if let Some(migration_data) = v.metadata.get("migration") {
-                return serde_json::from_value(migration_data.clone())
-                    .map_err(|e| TylError::database(format!("Invalid migration data: {e}")));
+                let migration: SchemaMigration = serde_json::from_value(migration_data.clone())
+                    .map_err(|e| TylError::database(format!("Invalid migration data: {e}")))?;
+                let applied = v
+                    .metadata
+                    .get("applied_changes")
+                    .and_then(|applied| applied.as_u64())
+                    .map(|applied| (applied as usize).min(migration.collection_changes.len()));
+                return Ok(Some((migration, applied)));
             }
         }
 
-        Err(TylError::not_found("migration", version.to_string()))
+        Ok(None)
     }
 
     async fn remove_migration_record(&self, version: &semver::Version) -> TylResult<()> {
@@ -1,6 +1,8 @@
 //! Mock implementation for testing
 
 use super::*;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
 /// Mock Qdrant adapter for testing
@@ -8,6 +10,17 @@ use std::sync::{Arc, Mutex};
 pub struct MockQdrantAdapter {
     collections: Arc<Mutex<HashMap<String, CollectionConfig>>>,
     vectors: Arc<Mutex<HashMap<String, HashMap<String, Vector>>>>, // collection -> id -> vector
+    statuses: Arc<Mutex<HashMap<String, String>>>, // collection -> status, "green" if unset
+    defaults: Arc<Mutex<HashMap<String, HashMap<String, serde_json::Value>>>>, // collection -> metadata
+    payload_schemas: Arc<Mutex<HashMap<String, HashMap<String, ExpectedJsonType>>>>,
+    versions: Arc<Mutex<HashMap<String, HashMap<String, u64>>>>, // collection -> id -> version
+    last_version: Arc<AtomicU64>,
+    multivector_collections: Arc<Mutex<HashSet<String>>>,
+    hnsw_configs: Arc<Mutex<HashMap<String, QdrantHnswConfig>>>,
+    named_vectors: Arc<Mutex<HashMap<(String, String), HashMap<String, Vec<f32>>>>>, // (collection, id) -> name -> embedding
+    healthy: Arc<AtomicBool>,
+    injected_store_failures: Arc<Mutex<HashSet<(String, String)>>>, // (collection, id)
+    config: QdrantConfig,
 }
 
 impl MockQdrantAdapter {
@@ -16,15 +29,1273 @@ impl MockQdrantAdapter {
         Self {
             collections: Arc::new(Mutex::new(HashMap::new())),
             vectors: Arc::new(Mutex::new(HashMap::new())),
+            statuses: Arc::new(Mutex::new(HashMap::new())),
+            defaults: Arc::new(Mutex::new(HashMap::new())),
+            payload_schemas: Arc::new(Mutex::new(HashMap::new())),
+            versions: Arc::new(Mutex::new(HashMap::new())),
+            last_version: Arc::new(AtomicU64::new(0)),
+            multivector_collections: Arc::new(Mutex::new(HashSet::new())),
+            hnsw_configs: Arc::new(Mutex::new(HashMap::new())),
+            named_vectors: Arc::new(Mutex::new(HashMap::new())),
+            healthy: Arc::new(AtomicBool::new(true)),
+            injected_store_failures: Arc::new(Mutex::new(HashSet::new())),
+            config: QdrantConfig::default(),
         }
     }
 
-    /// Create mock adapter with custom config (for compatibility)
-    pub fn with_config(_config: QdrantConfig) -> Self {
-        Self::new()
+    /// Create a mock holding one cosine collection with `vectors` stored, for test setup
+    ///
+    /// Fails like `create_collection` and `store_vector` would, e.g. on a dimension mismatch.
+    pub async fn seeded(
+        collection: &str,
+        dimension: usize,
+        vectors: Vec<Vector>,
+    ) -> TylResult<Self> {
+        let adapter = Self::new();
+        let config = CollectionConfig::new(collection, dimension, DistanceMetric::Cosine)?;
+        adapter.create_collection(config).await?;
+        for result in adapter.store_vectors_batch(collection, vectors).await? {
+            result?;
+        }
+        Ok(adapter)
+    }
+
+    /// Give a freshly written point a new version, mirroring Qdrant's per-write versions
+    fn record_write(&self, collection: &str, id: &str) {
+        let version = self.last_version.fetch_add(1, Ordering::SeqCst) + 1;
+        self.versions
+            .lock()
+            .unwrap()
+            .entry(collection.to_string())
+            .or_default()
+            .insert(id.to_string(), version);
+    }
+
+    /// Create mock adapter with custom config; only search defaults, `strict_filters` and
+    /// `reserved_payload_prefix` are used
+    pub fn with_config(config: QdrantConfig) -> Self {
+        Self {
+            config,
+            ..Self::new()
+        }
+    }
+
+    /// Configuration the mock was created with
+    pub fn config(&self) -> &QdrantConfig {
+        &self.config
+    }
+
+    /// Replace the configured API key; stored data is kept
+    pub async fn reconnect_with_api_key(&mut self, api_key: Option<String>) -> VectorResult<()> {
+        self.config.api_key = api_key;
+        Ok(())
+    }
+
+    /// Register payload fields merged into every vector stored in `collection`
+    pub fn set_collection_defaults(
+        &self,
+        collection: &str,
+        defaults: HashMap<String, serde_json::Value>,
+    ) {
+        let mut collection_defaults = self.defaults.lock().unwrap();
+        if defaults.is_empty() {
+            collection_defaults.remove(collection);
+        } else {
+            collection_defaults.insert(collection.to_string(), defaults);
+        }
+    }
+
+    /// Register the payload types every vector stored in `collection` must have
+    pub fn set_payload_schema(&self, collection: &str, schema: HashMap<String, ExpectedJsonType>) {
+        let mut payload_schemas = self.payload_schemas.lock().unwrap();
+        if schema.is_empty() {
+            payload_schemas.remove(collection);
+        } else {
+            payload_schemas.insert(collection.to_string(), schema);
+        }
+    }
+
+    /// Optimize a collection (no-op in the mock, which has no index to build)
+    pub async fn optimize_collection(&self, collection: &str) -> TylResult<()> {
+        if !self.collections.lock().unwrap().contains_key(collection) {
+            return Err(vector_errors::collection_not_found(collection));
+        }
+        Ok(())
+    }
+
+    /// Update optimizer thresholds (no-op in the mock, which has no optimizer)
+    pub async fn update_optimizer_config(
+        &self,
+        collection: &str,
+        _indexing_threshold: Option<u64>,
+        _default_segment_number: Option<u64>,
+    ) -> TylResult<()> {
+        if !self.collections.lock().unwrap().contains_key(collection) {
+            return Err(vector_errors::collection_not_found(collection));
+        }
+        Ok(())
+    }
+
+    /// Search for similar vectors, returning at most `per_group` hits per value of `group_by`
+    pub async fn search_groups(
+        &self,
+        collection: &str,
+        _query_vector: Vec<f32>,
+        group_by: &str,
+        groups_limit: usize,
+        per_group: usize,
+        params: SearchParams,
+    ) -> TylResult<Vec<VectorGroup>> {
+        let vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+
+        let mut groups: Vec<VectorGroup> = Vec::new();
+        for vector in collection_vectors.values() {
+            if !matches_filters(vector, &params) {
+                continue;
+            }
+            // Like Qdrant, points without the group_by field are skipped
+            let Some(group_id) = vector.metadata.get(group_by) else {
+                continue;
+            };
+
+            let hit = VectorSearchResult::new(vector.clone(), 0.9); // Mock score
+            match groups.iter_mut().find(|g| &g.group_id == group_id) {
+                Some(group) if group.hits.len() < per_group => group.hits.push(hit),
+                Some(_) => {}
+                None if groups.len() < groups_limit => groups.push(VectorGroup {
+                    group_id: group_id.clone(),
+                    hits: vec![hit],
+                }),
+                None => {}
+            }
+        }
+
+        Ok(groups)
+    }
+
+    /// Discover points (falls back to plain similarity on the target in the mock)
+    pub async fn discover(
+        &self,
+        collection: &str,
+        target: Option<Vec<f32>>,
+        context: Vec<(Vec<f32>, Vec<f32>)>,
+        params: SearchParams,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        QdrantAdapter::validate_discover_inputs(&target, &context)?;
+        self.search_similar(collection, target.unwrap_or_default(), params)
+            .await
+    }
+
+    /// Fetch vectors matching the filters in `filter` without similarity ranking
+    pub async fn find_by_filter(
+        &self,
+        collection: &str,
+        filter: SearchParams,
+        limit: usize,
+    ) -> TylResult<Vec<Vector>> {
+        let vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+
+        Ok(collection_vectors
+            .values()
+            .filter(|vector| matches_filters(vector, &filter))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch up to `limit` vectors ordered by the numeric metadata field `order_key`
+    ///
+    /// Vectors without a numeric value under `order_key` are left out, as on Qdrant.
+    pub async fn scroll_ordered(
+        &self,
+        collection: &str,
+        order_key: &str,
+        ascending: bool,
+        limit: usize,
+    ) -> TylResult<Vec<Vector>> {
+        let vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+
+        let mut ordered: Vec<(f64, &Vector)> = collection_vectors
+            .values()
+            .filter_map(|vector| {
+                let value = vector.metadata.get(order_key)?.as_f64()?;
+                Some((value, vector))
+            })
+            .collect();
+        ordered.sort_by(|(a, _), (b, _)| {
+            let ordering = a.total_cmp(b);
+            if ascending {
+                ordering
+            } else {
+                ordering.reverse()
+            }
+        });
+
+        Ok(ordered
+            .into_iter()
+            .take(limit)
+            .map(|(_, vector)| vector.clone())
+            .collect())
+    }
+
+    /// Search for similar vectors with Qdrant-specific options such as payload inclusion
+    ///
+    /// `hnsw_ef` and `exact` have no effect: the mock always searches exhaustively.
+    pub async fn search_similar_with_options(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+        options: QdrantSearchOptions,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        if let Some(expected) = &options.expected_metric {
+            let actual = self
+                .get_collection_info(collection)
+                .await?
+                .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+            QdrantAdapter::check_distance_metric(collection, expected, &actual.distance_metric)?;
+        }
+
+        let mut results = self
+            .search_similar(collection, query_vector, params)
+            .await?;
+        if let Some(key) = &options.dedup_by {
+            results = QdrantAdapter::dedup_results(results, key, &self.metric(collection));
+        }
+        for result in &mut results {
+            match &options.payload_fields {
+                _ if !options.include_payload => result.vector.metadata.clear(),
+                Some(fields) => result.vector.metadata.retain(|key, _| fields.contains(key)),
+                None => {}
+            }
+        }
+        Ok(results)
+    }
+
+    /// Search for similar vectors after checking the collection uses `expected_metric`
+    pub async fn search_similar_checked(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+        expected_metric: DistanceMetric,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let options = QdrantSearchOptions::new().expect_metric(expected_metric);
+        self.search_similar_with_options(collection, query_vector, params, options)
+            .await
+    }
+
+    /// Search with the configured `default_search_limit` and `default_search_threshold`
+    pub async fn search_with_defaults(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        self.search_similar(
+            collection,
+            query_vector,
+            self.config.default_search_params(),
+        )
+        .await
+    }
+
+    /// Store a vector; the mock does not log, so `request_id` is ignored
+    pub async fn store_vector_with_request_id(
+        &self,
+        collection: &str,
+        vector: Vector,
+        _request_id: &str,
+    ) -> TylResult<()> {
+        self.store_vector(collection, vector).await
+    }
+
+    /// Store a vector; the mock has a single shard, so `shard_key` is ignored
+    pub async fn store_vector_with_shard_key(
+        &self,
+        collection: &str,
+        vector: Vector,
+        _shard_key: &str,
+    ) -> TylResult<()> {
+        self.store_vector(collection, vector).await
+    }
+
+    /// Retrieve a vector by ID; the mock has a single shard, so `shard_key` is ignored
+    pub async fn get_vector_with_shard_key(
+        &self,
+        collection: &str,
+        id: &str,
+        _shard_key: &str,
+    ) -> TylResult<Option<Vector>> {
+        self.get_vector(collection, id).await
+    }
+
+    /// Search for candidates, then reorder them with a caller-supplied re-ranker
+    pub async fn search_similar_then_rerank(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+        reranker: impl Fn(&[VectorSearchResult]) -> Vec<VectorSearchResult>,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let candidates = self
+            .search_similar(collection, query_vector, params)
+            .await?;
+        Ok(reranker(&candidates))
+    }
+
+    /// Search `collection` using the vector stored under `source_id` in `source_collection`
+    pub async fn search_using_stored_vector(
+        &self,
+        collection: &str,
+        source_collection: &str,
+        source_id: &str,
+        params: SearchParams,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let source = self
+            .get_vector(source_collection, source_id)
+            .await?
+            .ok_or_else(|| vector_errors::vector_not_found(source_id))?;
+        self.search_similar(collection, source.embedding, params)
+            .await
+    }
+
+    /// Search only among `allowed_ids`, e.g. the points a user is permitted to see
+    pub async fn search_within_ids(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        allowed_ids: &[String],
+        params: SearchParams,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        if allowed_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.search_similar(
+            collection,
+            query_vector,
+            QdrantAdapter::restrict_to_ids(params, allowed_ids),
+        )
+        .await
+    }
+
+    /// Search, treating results as "no good answer" unless the best one is relevant enough
+    pub async fn search_similar_require_relevance(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+        min_best_score: f32,
+        error_if_irrelevant: bool,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let results = self
+            .search_similar(collection, query_vector, params)
+            .await?;
+        QdrantAdapter::apply_relevance_gate(
+            collection,
+            results,
+            &self.metric(collection),
+            min_best_score,
+            error_if_irrelevant,
+        )
+    }
+
+    /// Search for similar vectors, reporting cumulative progress per `max_batch_size` page
+    pub async fn search_similar_with_progress(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+        mut on_batch: impl FnMut(usize),
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        if params.limit == 0 {
+            on_batch(0);
+            return Ok(Vec::new());
+        }
+        let pages = QdrantAdapter::search_pages(params.limit, self.config.max_batch_size);
+        // The mock ranks every match at once, then hands them out in the adapter's pages
+        let mut ranked = self
+            .search_similar(collection, query_vector, params)
+            .await?
+            .into_iter();
+        let mut results = Vec::new();
+        for (_, limit) in pages {
+            let page = ranked.by_ref().take(limit).collect();
+            if !QdrantAdapter::accept_search_page(&mut results, page, limit, &mut on_batch) {
+                break;
+            }
+        }
+        Ok(results)
+    }
+
+    /// Search for the top results that score within a fraction of the best match
+    pub async fn search_top_relative(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        limit: usize,
+        relative_threshold: f32,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let results = self
+            .search_similar(collection, query_vector, SearchParams::with_limit(limit))
+            .await?;
+        QdrantAdapter::apply_relative_threshold(
+            results,
+            relative_threshold,
+            &self.metric(collection),
+        )
+    }
+
+    /// Replace one named vector of a point; the empty name is the point's default embedding
+    ///
+    /// The mock accepts any vector name, while Qdrant requires the collection to define it.
+    pub async fn update_named_vector(
+        &self,
+        collection: &str,
+        id: &str,
+        vector_name: &str,
+        embedding: Vec<f32>,
+    ) -> TylResult<()> {
+        let mut vectors = self.vectors.lock().unwrap();
+        let vector = vectors
+            .get_mut(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?
+            .get_mut(id)
+            .ok_or_else(|| vector_errors::vector_not_found(id))?;
+
+        if vector_name.is_empty() {
+            vector.embedding = embedding;
+        } else {
+            self.named_vectors
+                .lock()
+                .unwrap()
+                .entry((collection.to_string(), id.to_string()))
+                .or_default()
+                .insert(vector_name.to_string(), embedding);
+        }
+        self.record_write(collection, id);
+        Ok(())
+    }
+
+    /// A named vector of a point as set by `update_named_vector`; the empty name is the
+    /// default embedding
+    pub fn named_vector(&self, collection: &str, id: &str, vector_name: &str) -> Option<Vec<f32>> {
+        if vector_name.is_empty() {
+            let vectors = self.vectors.lock().unwrap();
+            return vectors
+                .get(collection)?
+                .get(id)
+                .map(|v| v.embedding.clone());
+        }
+        self.named_vectors
+            .lock()
+            .unwrap()
+            .get(&(collection.to_string(), id.to_string()))?
+            .get(vector_name)
+            .cloned()
+    }
+
+    /// Delete every vector in a collection while keeping the collection itself
+    pub async fn clear_collection(&self, collection: &str) -> TylResult<()> {
+        let mut vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors
+            .get_mut(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+        collection_vectors.clear();
+        self.named_vectors
+            .lock()
+            .unwrap()
+            .retain(|(name, _), _| name != collection);
+        Ok(())
+    }
+
+    /// Re-embed every vector in a collection with a new embedding service
+    pub async fn reembed_collection(
+        &self,
+        collection: &str,
+        new_service: &impl EmbeddingService,
+        text_payload_key: &str,
+        content_type: ContentType,
+    ) -> TylResult<usize> {
+        self.validate_embedding_compatibility(collection, new_service)
+            .await?;
+
+        let vectors: Vec<Vector> = {
+            let vectors = self.vectors.lock().unwrap();
+            vectors
+                .get(collection)
+                .ok_or_else(|| vector_errors::collection_not_found(collection))?
+                .values()
+                .cloned()
+                .collect()
+        };
+
+        let batch = QdrantAdapter::reembed_vectors(
+            vectors,
+            new_service,
+            text_payload_key,
+            &content_type,
+            &self.config,
+        )
+        .await?;
+        let reembedded = batch.len();
+        for vector in batch {
+            self.store_vector(collection, vector).await?;
+        }
+        Ok(reembedded)
+    }
+
+    /// Embed `text` with `service` and store it, recording the model and content type in the
+    /// payload
+    pub async fn store_text(
+        &self,
+        collection: &str,
+        id: &str,
+        text: &str,
+        content_type: ContentType,
+        service: &impl EmbeddingService,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> TylResult<()> {
+        let vector =
+            QdrantAdapter::embed_text(id, text, content_type, service, metadata, &self.config)
+                .await?;
+        self.store_vector(collection, vector).await
+    }
+
+    /// Check that `service` produces embeddings matching the dimension of `collection`
+    pub async fn validate_embedding_compatibility(
+        &self,
+        collection: &str,
+        service: &impl EmbeddingService,
+    ) -> TylResult<()> {
+        let config = self
+            .get_collection_info(collection)
+            .await?
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+        QdrantAdapter::check_embedding_dimension(config.dimension, service).await
+    }
+
+    /// Create a collection sized for the embeddings `service` produces
+    pub async fn create_collection_for_service(
+        &self,
+        name: &str,
+        service: &impl EmbeddingService,
+        metric: DistanceMetric,
+    ) -> TylResult<()> {
+        let dimension = QdrantAdapter::probe_embedding_dimension(service).await?;
+        self.create_collection(CollectionConfig::new(name, dimension, metric)?)
+            .await
+    }
+
+    /// Create `config.name` unless it exists, reporting whether an existing one matches
+    pub async fn ensure_collection(&self, config: CollectionConfig) -> TylResult<EnsureOutcome> {
+        if self.create_collection_if_not_exists(config.clone()).await? {
+            return Ok(EnsureOutcome::Created);
+        }
+        let actual = self
+            .get_collection_info(&config.name)
+            .await?
+            .ok_or_else(|| vector_errors::collection_not_found(&config.name))?;
+        Ok(QdrantAdapter::existing_collection_outcome(&config, &actual))
+    }
+
+    /// Check that collection `name` matches the dimension and metric of `expected`
+    pub async fn assert_collection_schema(
+        &self,
+        name: &str,
+        expected: &CollectionConfig,
+    ) -> TylResult<()> {
+        self.assert_collection_schema_with_options(
+            name,
+            expected,
+            &QdrantCollectionOptions::default(),
+        )
+        .await
+    }
+
+    /// Like `assert_collection_schema`, also comparing the HNSW settings the collection was
+    /// created with; unset settings are reported as unset rather than as Qdrant's defaults
+    pub async fn assert_collection_schema_with_options(
+        &self,
+        name: &str,
+        expected: &CollectionConfig,
+        options: &QdrantCollectionOptions,
+    ) -> TylResult<()> {
+        let actual = self
+            .get_collection_info(name)
+            .await?
+            .ok_or_else(|| vector_errors::collection_not_found(name))?;
+        let actual_hnsw = self
+            .hnsw_configs
+            .lock()
+            .unwrap()
+            .get(name)
+            .cloned()
+            .unwrap_or_default();
+        QdrantAdapter::check_collection_schema(name, expected, &actual, options, &actual_hnsw)
+    }
+
+    /// Copy a collection's configuration and vectors under a new name
+    ///
+    /// Like the real adapter, collections with named vectors or multivectors are rejected
+    /// before `dest` is created.
+    pub async fn copy_collection(&self, source: &str, dest: &str) -> TylResult<usize> {
+        let source_config = self
+            .get_collection_info(source)
+            .await?
+            .ok_or_else(|| vector_errors::collection_not_found(source))?;
+        if self
+            .multivector_collections
+            .lock()
+            .unwrap()
+            .contains(source)
+        {
+            return Err(qdrant_errors::collection_not_copyable(
+                source,
+                "it is a multivector collection",
+            ));
+        }
+        if self
+            .named_vectors
+            .lock()
+            .unwrap()
+            .keys()
+            .any(|(collection, _)| collection == source)
+        {
+            return Err(qdrant_errors::collection_not_copyable(
+                source,
+                "it has named vectors",
+            ));
+        }
+
+        let dest_config =
+            CollectionConfig::new(dest, source_config.dimension, source_config.distance_metric)?;
+        self.create_collection(dest_config).await?;
+        let hnsw = self.hnsw_configs.lock().unwrap().get(source).cloned();
+        if let Some(hnsw) = hnsw {
+            self.hnsw_configs
+                .lock()
+                .unwrap()
+                .insert(dest.to_string(), hnsw);
+        }
+
+        let copied: HashMap<String, Vector> = self
+            .vectors
+            .lock()
+            .unwrap()
+            .get(source)
+            .cloned()
+            .unwrap_or_default();
+        let count = copied.len();
+        for id in copied.keys() {
+            self.record_write(dest, id);
+        }
+        self.vectors
+            .lock()
+            .unwrap()
+            .insert(dest.to_string(), copied);
+        Ok(count)
+    }
+
+    /// Collect connection diagnostics for the in-memory store
+    pub async fn connection_diagnostics(&self) -> TylResult<ConnectionDiagnostics> {
+        Ok(ConnectionDiagnostics {
+            url: "mock://in-memory".to_string(),
+            server_version: Some("mock".to_string()),
+            healthy: true,
+            collection_count: Some(self.collections.lock().unwrap().len()),
+            timeout: Duration::from_secs(QdrantConfig::default().timeout_seconds),
+        })
+    }
+
+    /// Cluster info of a single node without distributed mode
+    ///
+    /// Disk usage is the size of the stored embeddings.
+    pub async fn cluster_info(&self) -> TylResult<ClusterInfo> {
+        let embedding_bytes = self
+            .vectors
+            .lock()
+            .unwrap()
+            .values()
+            .flat_map(|vectors| vectors.values())
+            .map(|vector| (vector.embedding.len() * std::mem::size_of::<f32>()) as u64)
+            .sum();
+        Ok(ClusterInfo {
+            disk_usage_bytes: Some(embedding_bytes),
+            ..ClusterInfo::default()
+        })
+    }
+
+    /// Create a collection with Qdrant-specific settings; the mock only validates them
+    pub async fn create_collection_with_options(
+        &self,
+        config: CollectionConfig,
+        options: QdrantCollectionOptions,
+    ) -> TylResult<()> {
+        options.validate()?;
+        let name = config.name.clone();
+        self.create_collection(config).await?;
+        if let Some(hnsw) = options.hnsw {
+            self.hnsw_configs.lock().unwrap().insert(name.clone(), hnsw);
+        }
+        if options.multivector.is_some() {
+            self.multivector_collections.lock().unwrap().insert(name);
+        }
+        Ok(())
+    }
+
+    /// Store a point holding several vectors; the mock keeps the rows flattened in order
+    pub async fn store_multivector(
+        &self,
+        collection: &str,
+        id: &str,
+        rows: Vec<Vec<f32>>,
+        metadata: HashMap<String, serde_json::Value>,
+    ) -> TylResult<()> {
+        let dimension = self
+            .collections
+            .lock()
+            .unwrap()
+            .get(collection)
+            .map(|config| config.dimension)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+        if !self
+            .multivector_collections
+            .lock()
+            .unwrap()
+            .contains(collection)
+        {
+            return Err(qdrant_errors::invalid_multivector(format!(
+                "collection '{collection}' is not a multivector collection"
+            )));
+        }
+        QdrantAdapter::validate_multivector_rows(&rows)?;
+        if rows[0].len() != dimension {
+            return Err(qdrant_errors::invalid_multivector(format!(
+                "rows have {} components but collection '{collection}' has dimension {dimension}",
+                rows[0].len()
+            )));
+        }
+
+        let mut vector = Vector::new(id, rows.concat());
+        vector.metadata = metadata;
+        self.store_vector(collection, vector).await
+    }
+
+    /// Count the vectors matching the metadata filters in `filter`
+    pub async fn count_matching(&self, collection: &str, filter: SearchParams) -> TylResult<u64> {
+        let vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+
+        Ok(collection_vectors
+            .values()
+            .filter(|vector| matches_filters(vector, &filter))
+            .count() as u64)
+    }
+
+    /// Delete every vector matching the metadata filters in `filter`, or only count with `dry_run`
+    pub async fn delete_by_filter(
+        &self,
+        collection: &str,
+        filter: SearchParams,
+        dry_run: bool,
+    ) -> TylResult<u64> {
+        if filter.filters.is_empty() {
+            return Err(qdrant_errors::invalid_search_params(
+                "delete_by_filter requires at least one filter; use clear_collection to delete all",
+            ));
+        }
+        QdrantAdapter::check_strict_filter(&filter)?;
+
+        let mut vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors
+            .get_mut(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+
+        let before = collection_vectors.len();
+        if dry_run {
+            return Ok(collection_vectors
+                .values()
+                .filter(|vector| matches_filters(vector, &filter))
+                .count() as u64);
+        }
+        collection_vectors.retain(|_, vector| !matches_filters(vector, &filter));
+        Ok((before - collection_vectors.len()) as u64)
+    }
+
+    /// Distinct values of a metadata field with their counts, most frequent first
+    pub async fn distinct_values(
+        &self,
+        collection: &str,
+        field: &str,
+        limit: usize,
+        max_points: usize,
+    ) -> TylResult<Vec<(serde_json::Value, u64)>> {
+        let vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+        // Qdrant scrolls in point ID order, so the cap keeps the lowest IDs
+        let mut scanned: Vec<&Vector> = collection_vectors.values().collect();
+        scanned.sort_by(|a, b| a.id.cmp(&b.id));
+        let mut counts = FacetCounts::default();
+        scanned
+            .into_iter()
+            .take(max_points)
+            .filter_map(|vector| vector.metadata.get(field))
+            .for_each(|value| counts.add(value));
+        Ok(counts.top(limit))
+    }
+
+    /// Export every vector in a collection as JSONL, ordered by ID
+    pub async fn export_collection(
+        &self,
+        collection: &str,
+        mut writer: impl tokio::io::AsyncWrite + Unpin,
+    ) -> TylResult<usize> {
+        let mut vectors: Vec<Vector> = self
+            .vectors
+            .lock()
+            .unwrap()
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?
+            .values()
+            .cloned()
+            .collect();
+        vectors.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let exported = vectors.len();
+        for vector in vectors {
+            QdrantAdapter::write_export_line(&mut writer, vector).await?;
+        }
+        QdrantAdapter::flush_export(&mut writer).await?;
+        Ok(exported)
+    }
+
+    /// Estimate the RAM a collection's vectors take; the mock stores every vector as float32
+    pub async fn estimate_memory_bytes(&self, collection: &str) -> TylResult<u64> {
+        let dimension = self
+            .collections
+            .lock()
+            .unwrap()
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?
+            .dimension;
+        let points = self
+            .vectors
+            .lock()
+            .unwrap()
+            .get(collection)
+            .map_or(0, |vectors| vectors.len());
+        Ok(QdrantAdapter::estimate_vector_memory(
+            points as u64,
+            dimension as u64,
+            VectorDatatype::Float32,
+            false,
+            0,
+        ))
+    }
+
+    /// SHA-256 checksum of every vector's ID and payload, folded in ID order
+    pub async fn collection_checksum(&self, collection: &str) -> TylResult<String> {
+        self.checksum_collection(collection, false)
+    }
+
+    /// Like `collection_checksum`, but the embeddings are part of the checksum too
+    pub async fn collection_checksum_with_embeddings(&self, collection: &str) -> TylResult<String> {
+        self.checksum_collection(collection, true)
+    }
+
+    fn checksum_collection(&self, collection: &str, include_embeddings: bool) -> TylResult<String> {
+        use sha2::{Digest, Sha256};
+
+        let vectors = self.vectors.lock().unwrap();
+        let mut collection_vectors: Vec<&Vector> = vectors
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?
+            .values()
+            .collect();
+        collection_vectors.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut hasher = Sha256::new();
+        for vector in collection_vectors {
+            QdrantAdapter::update_checksum(&mut hasher, vector, include_embeddings);
+        }
+        Ok(format!("{:x}", hasher.finalize()))
+    }
+
+    /// Get a collection's configuration; mock collections have a single unnamed vector
+    pub async fn get_collection_details(
+        &self,
+        collection: &str,
+    ) -> TylResult<Option<DetailedCollectionConfig>> {
+        let Some(config) = self.collections.lock().unwrap().get(collection).cloned() else {
+            return Ok(None);
+        };
+        let vector = NamedVectorConfig {
+            dimension: config.dimension,
+            distance_metric: config.distance_metric.clone(),
+        };
+        Ok(Some(DetailedCollectionConfig {
+            config,
+            vectors: [(String::new(), vector)].into(),
+        }))
+    }
+
+    /// Store any number of vectors in `max_batch_size` batches, up to `concurrency` in parallel
+    pub async fn store_vectors_concurrent(
+        &self,
+        collection: &str,
+        vectors: Vec<Vector>,
+        concurrency: usize,
+    ) -> TylResult<Vec<TylResult<()>>> {
+        QdrantAdapter::store_batches_concurrently(
+            self,
+            collection,
+            vectors,
+            self.config.max_batch_size,
+            concurrency,
+        )
+        .await
+    }
+
+    /// Re-submit the vectors whose entry in a `store_vectors_batch` result failed
+    pub async fn retry_failed(
+        &self,
+        collection: &str,
+        vectors: &[Vector],
+        results: &[TylResult<()>],
+    ) -> TylResult<Vec<TylResult<()>>> {
+        QdrantAdapter::retry_failed_vectors(
+            self,
+            collection,
+            vectors,
+            results,
+            self.config.max_batch_size,
+        )
+        .await
+    }
+
+    /// Store embeddings that have no IDs yet under freshly generated UUIDs
+    pub async fn store_embeddings_batch(
+        &self,
+        collection: &str,
+        embeddings: Vec<(Vec<f32>, HashMap<String, serde_json::Value>)>,
+    ) -> TylResult<Vec<(String, TylResult<()>)>> {
+        QdrantAdapter::store_with_generated_ids(
+            self,
+            collection,
+            embeddings,
+            self.config.max_batch_size,
+        )
+        .await
+    }
+
+    /// Import a JSONL export produced by `export_collection`
+    pub async fn import_collection(
+        &self,
+        collection: &str,
+        reader: impl tokio::io::AsyncRead + Unpin,
+    ) -> TylResult<usize> {
+        QdrantAdapter::import_export_lines(self, collection, reader, self.config.max_batch_size)
+            .await
+    }
+
+    /// Bulk load vectors from CSV with one column per embedding dimension
+    #[cfg(feature = "import")]
+    pub async fn import_csv(
+        &self,
+        collection: &str,
+        reader: impl std::io::Read,
+        options: &CsvImportOptions,
+    ) -> TylResult<usize> {
+        let config = self
+            .get_collection_info(collection)
+            .await?
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+
+        QdrantAdapter::import_csv_rows(
+            self,
+            collection,
+            reader,
+            options,
+            config.dimension,
+            self.config.max_batch_size,
+        )
+        .await
+    }
+
+    /// List the collections whose names match a glob or prefix `pattern`
+    pub async fn list_collections_matching(
+        &self,
+        pattern: &str,
+    ) -> TylResult<Vec<CollectionConfig>> {
+        let mut collections = self.list_collections().await?;
+        collections.retain(|config| QdrantAdapter::collection_name_matches(&config.name, pattern));
+        Ok(collections)
+    }
+
+    /// Create a collection unless one with the same name already exists
+    pub async fn create_collection_if_not_exists(
+        &self,
+        config: CollectionConfig,
+    ) -> TylResult<bool> {
+        if self.collections.lock().unwrap().contains_key(&config.name) {
+            return Ok(false);
+        }
+        self.create_collection(config).await?;
+        Ok(true)
+    }
+
+    /// Every collection with its stats
+    pub async fn collections_with_stats(
+        &self,
+    ) -> TylResult<Vec<(CollectionConfig, HashMap<String, serde_json::Value>)>> {
+        let mut collections = Vec::new();
+        for config in self.list_collections().await? {
+            let stats = self.get_collection_stats(&config.name).await?;
+            collections.push((config, stats));
+        }
+        Ok(collections)
+    }
+
+    /// Delete `config.name` if it exists and create it fresh from `config`
+    pub async fn recreate_collection(&self, config: CollectionConfig) -> TylResult<()> {
+        self.delete_collection(&config.name).await?;
+        self.create_collection(config).await
+    }
+
+    /// Search for similar vectors, also returning how long the search took
+    pub async fn search_detailed(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+    ) -> TylResult<SearchOutcome> {
+        let start_time = Instant::now();
+        let threshold_applied = params.threshold.is_some();
+        let results = self
+            .search_similar(collection, query_vector, params)
+            .await?;
+        let higher_is_better = self
+            .collections
+            .lock()
+            .unwrap()
+            .get(collection)
+            .map_or(true, |config| is_higher_better(&config.distance_metric));
+        Ok(SearchOutcome {
+            returned_count: results.len(),
+            results,
+            elapsed: start_time.elapsed(),
+            threshold_applied,
+            higher_is_better,
+        })
+    }
+
+    /// Store a vector, returning its ID and how long the store took
+    pub async fn store_vector_detailed(
+        &self,
+        collection: &str,
+        vector: Vector,
+    ) -> TylResult<StoreOutcome> {
+        let start_time = Instant::now();
+        let id = vector.id.clone();
+        self.store_vector(collection, vector).await?;
+        Ok(StoreOutcome {
+            id,
+            collection: collection.to_string(),
+            duration: start_time.elapsed(),
+        })
+    }
+
+    /// Store a vector only if no vector with the same ID exists yet
+    pub async fn store_vector_if_absent(
+        &self,
+        collection: &str,
+        vector: Vector,
+    ) -> TylResult<bool> {
+        let mut vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors.entry(collection.to_string()).or_default();
+        if collection_vectors.contains_key(&vector.id) {
+            return Ok(false);
+        }
+        self.record_write(collection, &vector.id);
+        collection_vectors.insert(vector.id.clone(), vector);
+        Ok(true)
+    }
+
+    /// Store a vector, keeping stored metadata keys that `vector.metadata` does not set
+    ///
+    /// Like the real adapter, an existing point gets the new embedding and the metadata
+    /// merged in without collection defaults or a full schema check.
+    pub async fn store_vector_merge_metadata(
+        &self,
+        collection: &str,
+        vector: Vector,
+    ) -> TylResult<()> {
+        if self.get_vector(collection, &vector.id).await?.is_none() {
+            return self.store_vector(collection, vector).await;
+        }
+        QdrantAdapter::validate_metadata_update(
+            &vector,
+            self.payload_schemas.lock().unwrap().get(collection),
+            &self.config,
+        )?;
+
+        let mut vectors = self.vectors.lock().unwrap();
+        let stored = vectors
+            .get_mut(collection)
+            .and_then(|collection_vectors| collection_vectors.get_mut(&vector.id))
+            .ok_or_else(|| vector_errors::vector_not_found(&vector.id))?;
+        stored.embedding = vector.embedding;
+        stored.metadata.extend(vector.metadata);
+        self.record_write(collection, &vector.id);
+        Ok(())
+    }
+
+    /// Fetch many vectors together with their versions, in the order of `ids`
+    pub async fn get_vectors_with_versions(
+        &self,
+        collection: &str,
+        ids: Vec<String>,
+    ) -> TylResult<Vec<Option<(Vector, u64)>>> {
+        let vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+        let versions = self.versions.lock().unwrap();
+        let collection_versions = versions.get(collection);
+
+        Ok(ids
+            .iter()
+            .map(|id| {
+                let vector = collection_vectors.get(id)?.clone();
+                let version = collection_versions
+                    .and_then(|versions| versions.get(id))
+                    .copied()
+                    .unwrap_or_default();
+                Some((vector, version))
+            })
+            .collect())
+    }
+
+    /// The subset of `ids` stored in a collection
+    pub async fn existing_ids(
+        &self,
+        collection: &str,
+        ids: Vec<String>,
+    ) -> TylResult<HashSet<String>> {
+        let vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+        Ok(ids
+            .into_iter()
+            .filter(|id| collection_vectors.contains_key(id))
+            .collect())
+    }
+
+    /// Fetch a vector's metadata without its embedding
+    pub async fn get_payload(
+        &self,
+        collection: &str,
+        id: &str,
+    ) -> TylResult<Option<HashMap<String, serde_json::Value>>> {
+        let vectors = self.vectors.lock().unwrap();
+        let collection_vectors = vectors
+            .get(collection)
+            .ok_or_else(|| vector_errors::collection_not_found(collection))?;
+        Ok(collection_vectors
+            .get(id)
+            .map(|vector| vector.metadata.clone()))
+    }
+
+    /// Override the status reported for a collection (e.g. "yellow" to simulate optimization)
+    pub fn set_collection_status(&self, collection: &str, status: impl Into<String>) {
+        self.statuses
+            .lock()
+            .unwrap()
+            .insert(collection.to_string(), status.into());
+    }
+
+    /// Override the health reported by `is_healthy` and `health_check` (e.g. to simulate an outage)
+    pub fn set_healthy(&self, healthy: bool) {
+        self.healthy.store(healthy, Ordering::SeqCst);
+    }
+
+    /// Make the next store of `id` in `collection` fail once with a storage error
+    pub fn fail_next_store(&self, collection: &str, id: &str) {
+        self.injected_store_failures
+            .lock()
+            .unwrap()
+            .insert((collection.to_string(), id.to_string()));
+    }
+
+    /// Distance metric of `collection`, cosine if it does not exist
+    fn metric(&self, collection: &str) -> DistanceMetric {
+        self.collections
+            .lock()
+            .unwrap()
+            .get(collection)
+            .map_or(DistanceMetric::Cosine, |config| {
+                config.distance_metric.clone()
+            })
+    }
+
+    fn collection_status(&self, collection: &str) -> String {
+        self.statuses
+            .lock()
+            .unwrap()
+            .get(collection)
+            .cloned()
+            .unwrap_or_else(|| "green".to_string())
+    }
+
+    /// Wait until a collection reports "green" status
+    pub async fn wait_until_ready(&self, collection: &str, timeout: Duration) -> TylResult<()> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if !self.collections.lock().unwrap().contains_key(collection) {
+                return Err(vector_errors::collection_not_found(collection));
+            }
+
+            let status = self.collection_status(collection);
+            if status == "green" {
+                return Ok(());
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(qdrant_errors::collection_not_ready(collection, &status));
+            }
+            tokio::time::sleep(READINESS_POLL_INTERVAL.min(deadline - now)).await;
+        }
+    }
+
+    /// Check that a collection exists and reports "green" status
+    pub async fn is_collection_healthy(&self, collection: &str) -> TylResult<bool> {
+        if !self.collections.lock().unwrap().contains_key(collection) {
+            return Ok(false);
+        }
+        Ok(self.collection_status(collection) == "green")
     }
 }
 
+/// Check whether a vector's metadata satisfies every filter entry in the search params
+fn matches_filters(vector: &Vector, params: &SearchParams) -> bool {
+    QdrantAdapter::filter_matches(params, vector)
+}
+
 impl Default for MockQdrantAdapter {
     fn default() -> Self {
         Self::new()
@@ -34,8 +1305,29 @@ impl Default for MockQdrantAdapter {
 #[async_trait]
 impl VectorStore for MockQdrantAdapter {
     async fn store_vector(&self, collection: &str, vector: Vector) -> TylResult<()> {
+        let mut vector = vector;
+        if let Some(defaults) = self.defaults.lock().unwrap().get(collection) {
+            QdrantAdapter::merge_default_metadata(&mut vector, defaults);
+        }
+        QdrantAdapter::validate_reserved_keys(&vector, &self.config)?;
+        if let Some(schema) = self.payload_schemas.lock().unwrap().get(collection) {
+            QdrantAdapter::validate_payload(&vector, schema)?;
+        }
+        if self
+            .injected_store_failures
+            .lock()
+            .unwrap()
+            .remove(&(collection.to_string(), vector.id.clone()))
+        {
+            return Err(vector_errors::storage_failed(format!(
+                "Injected failure storing '{}'",
+                vector.id
+            )));
+        }
+
         let mut vectors = self.vectors.lock().unwrap();
         let collection_vectors = vectors.entry(collection.to_string()).or_default();
+        self.record_write(collection, &vector.id);
         collection_vectors.insert(vector.id.clone(), vector);
         Ok(())
     }
@@ -68,22 +1360,24 @@ impl VectorStore for MockQdrantAdapter {
         _query_vector: Vec<f32>,
         params: SearchParams,
     ) -> TylResult<Vec<VectorSearchResult>> {
+        if self.config.strict_filters {
+            QdrantAdapter::check_strict_filter(&params)?;
+        }
+        let metric = self.metric(collection);
         let vectors = self.vectors.lock().unwrap();
         if let Some(collection_vectors) = vectors.get(collection) {
             let mut results = Vec::new();
+            let score = 0.9; // Mock score, a similarity or a distance depending on the metric
+            if params
+                .threshold
+                .is_some_and(|threshold| !passes_score_threshold(&metric, score, threshold))
+            {
+                return Ok(results);
+            }
             for vector in collection_vectors.values() {
                 // Simple mock: return vectors that match filters
-                let matches_filter = if params.filters.is_empty() {
-                    true
-                } else {
-                    params
-                        .filters
-                        .iter()
-                        .all(|(key, value)| vector.metadata.get(key) == Some(value))
-                };
-
-                if matches_filter {
-                    let result = VectorSearchResult::new(vector.clone(), 0.9); // Mock score
+                if matches_filters(vector, &params) {
+                    let result = VectorSearchResult::new(vector.clone(), score);
                     results.push(result);
                 }
 
@@ -101,6 +1395,10 @@ impl VectorStore for MockQdrantAdapter {
         let mut vectors = self.vectors.lock().unwrap();
         if let Some(collection_vectors) = vectors.get_mut(collection) {
             collection_vectors.remove(id);
+            self.named_vectors
+                .lock()
+                .unwrap()
+                .remove(&(collection.to_string(), id.to_string()));
             Ok(())
         } else {
             Err(vector_errors::collection_not_found(collection))
@@ -141,6 +1439,15 @@ impl VectorCollectionManager for MockQdrantAdapter {
 
         collections.remove(collection_name);
         vectors.remove(collection_name);
+        self.multivector_collections
+            .lock()
+            .unwrap()
+            .remove(collection_name);
+        self.hnsw_configs.lock().unwrap().remove(collection_name);
+        self.named_vectors
+            .lock()
+            .unwrap()
+            .retain(|(collection, _), _| collection != collection_name);
 
         Ok(())
     }
@@ -170,7 +1477,10 @@ impl VectorCollectionManager for MockQdrantAdapter {
                 "vectors_count".to_string(),
                 serde_json::json!(collection_vectors.len()),
             );
-            stats.insert("status".to_string(), serde_json::json!("green"));
+            stats.insert(
+                "status".to_string(),
+                serde_json::json!(self.collection_status(collection_name)),
+            );
         } else {
             return Err(vector_errors::collection_not_found(collection_name));
         }
@@ -182,12 +1492,22 @@ impl VectorCollectionManager for MockQdrantAdapter {
 #[async_trait]
 impl VectorStoreHealth for MockQdrantAdapter {
     async fn is_healthy(&self) -> TylResult<bool> {
-        Ok(true)
+        Ok(self.healthy.load(Ordering::SeqCst))
     }
 
     async fn health_check(&self) -> TylResult<HashMap<String, serde_json::Value>> {
+        let start_time = Instant::now();
         let mut health = HashMap::new();
-        health.insert("status".to_string(), serde_json::json!("healthy"));
+        let status = if self.healthy.load(Ordering::SeqCst) {
+            "healthy"
+        } else {
+            "unhealthy"
+        };
+        health.insert("status".to_string(), serde_json::json!(status));
+        health.insert(
+            "latency_ms".to_string(),
+            serde_json::json!(start_time.elapsed().as_secs_f64() * 1000.0),
+        );
         health.insert("type".to_string(), serde_json::json!("mock"));
         Ok(health)
     }
@@ -0,0 +1,133 @@
+//! Tenant-scoped access for multitenant collections
+//!
+//! Wraps a store so every search and filtered delete is restricted to one tenant, enforcing
+//! isolation at the API layer instead of relying on callers to remember the filter.
+
+use super::*;
+use std::sync::Arc;
+
+/// Stores that can delete every point matching a set of metadata filters
+#[async_trait]
+pub trait VectorFilterDelete {
+    /// Delete points matching the filters in `filter`, or only count them with `dry_run`
+    async fn delete_by_filter(
+        &self,
+        collection: &str,
+        filter: SearchParams,
+        dry_run: bool,
+    ) -> TylResult<u64>;
+}
+
+#[async_trait]
+impl VectorFilterDelete for QdrantAdapter {
+    async fn delete_by_filter(
+        &self,
+        collection: &str,
+        filter: SearchParams,
+        dry_run: bool,
+    ) -> TylResult<u64> {
+        QdrantAdapter::delete_by_filter(self, collection, filter, dry_run).await
+    }
+}
+
+#[cfg(feature = "mock")]
+#[async_trait]
+impl VectorFilterDelete for MockQdrantAdapter {
+    async fn delete_by_filter(
+        &self,
+        collection: &str,
+        filter: SearchParams,
+        dry_run: bool,
+    ) -> TylResult<u64> {
+        MockQdrantAdapter::delete_by_filter(self, collection, filter, dry_run).await
+    }
+}
+
+/// Store wrapper that confines every operation to a single tenant
+///
+/// Searches and filtered deletes get a mandatory `tenant_key = tenant_id` filter, and stored
+/// vectors are tagged with it. Any attempt to filter on or store under another tenant is
+/// rejected with a validation error.
+pub struct TenantScopedStore<S> {
+    store: Arc<S>,
+    tenant_key: String,
+    tenant_id: String,
+}
+
+impl<S: VectorStore> TenantScopedStore<S> {
+    /// Scope `store` to the tenant whose ID is stored under the `tenant_key` payload field
+    pub fn new(store: Arc<S>, tenant_key: impl Into<String>, tenant_id: impl Into<String>) -> Self {
+        Self {
+            store,
+            tenant_key: tenant_key.into(),
+            tenant_id: tenant_id.into(),
+        }
+    }
+
+    /// The tenant this store is scoped to
+    pub fn tenant_id(&self) -> &str {
+        &self.tenant_id
+    }
+
+    /// Add the tenant filter to `params`, rejecting filters on a different tenant
+    pub fn scoped_params(&self, params: SearchParams) -> VectorResult<SearchParams> {
+        let tenant = serde_json::json!(self.tenant_id);
+        if let Some(requested) = params.filters.get(&self.tenant_key) {
+            if *requested != tenant {
+                return Err(qdrant_errors::tenant_scope_violation(
+                    &self.tenant_key,
+                    &self.tenant_id,
+                ));
+            }
+        }
+        Ok(params.with_filter(self.tenant_key.as_str(), tenant))
+    }
+
+    /// Store a vector tagged with this tenant
+    pub async fn store_vector(&self, collection: &str, mut vector: Vector) -> TylResult<()> {
+        let tenant = serde_json::json!(self.tenant_id);
+        match vector.metadata.get(&self.tenant_key) {
+            Some(existing) if *existing != tenant => {
+                return Err(qdrant_errors::tenant_scope_violation(
+                    &self.tenant_key,
+                    &self.tenant_id,
+                ));
+            }
+            Some(_) => {}
+            None => vector.add_metadata(self.tenant_key.as_str(), tenant),
+        }
+        self.store.store_vector(collection, vector).await
+    }
+
+    /// Search for similar vectors belonging to this tenant only
+    pub async fn search_similar(
+        &self,
+        collection: &str,
+        query_vector: Vec<f32>,
+        params: SearchParams,
+    ) -> TylResult<Vec<VectorSearchResult>> {
+        let params = self.scoped_params(params)?;
+        self.store
+            .search_similar(collection, query_vector, params)
+            .await
+    }
+}
+
+impl<S: VectorStore + VectorFilterDelete> TenantScopedStore<S> {
+    /// Delete this tenant's points matching `filter`, or only count them with `dry_run`
+    ///
+    /// Filters are checked strictly before reaching the store, so an entry that cannot be
+    /// translated fails the delete instead of widening it to the whole tenant.
+    pub async fn delete_by_filter(
+        &self,
+        collection: &str,
+        filter: SearchParams,
+        dry_run: bool,
+    ) -> TylResult<u64> {
+        QdrantAdapter::check_strict_filter(&filter)?;
+        let filter = self.scoped_params(filter)?;
+        self.store
+            .delete_by_filter(collection, filter, dry_run)
+            .await
+    }
+}
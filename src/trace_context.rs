@@ -0,0 +1,27 @@
+//! Parent span propagation so adapter spans join the caller's trace
+//!
+//! Adapter operations start a root span by default. Run them inside [`with_parent_span`] to
+//! start their spans as children of an incoming request's span instead, linking Qdrant calls
+//! into end-to-end traces without threading a parameter through every method.
+
+use std::future::Future;
+
+tokio::task_local! {
+    static PARENT_SPAN_ID: String;
+}
+
+/// Run `operation` with adapter spans started as children of `parent_span_id`
+///
+/// The parent applies to every adapter call awaited within `operation`, but not to tasks it
+/// spawns; wrap those separately. Has no effect without the `tracing` feature.
+pub async fn with_parent_span<F: Future>(
+    parent_span_id: impl Into<String>,
+    operation: F,
+) -> F::Output {
+    PARENT_SPAN_ID.scope(parent_span_id.into(), operation).await
+}
+
+/// The parent span set by the innermost enclosing [`with_parent_span`], if any
+pub fn current_parent_span() -> Option<String> {
+    PARENT_SPAN_ID.try_with(Clone::clone).ok()
+}
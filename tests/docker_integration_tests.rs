@@ -323,6 +323,82 @@ async fn test_real_qdrant_error_handling() {
     assert!(result.is_err(), "Should fail with empty collection name");
 }
 
+#[tokio::test]
+async fn test_real_qdrant_missing_collection_errors() {
+    skip_if_no_qdrant!();
+
+    let mut config = QdrantConfig::default();
+    config.url = "http://localhost:6334".to_string(); // Use gRPC port
+    let adapter = QdrantAdapter::connect(config).await.unwrap();
+
+    // Read paths report a missing collection like the mock does, not as a storage failure
+    let missing = format!("missing_collection_{}", Uuid::new_v4().simple());
+    let expected = tyl_qdrant_adapter::vector_errors::collection_not_found(&missing).to_string();
+    let id = Uuid::new_v4().to_string();
+
+    let error = adapter.get_payload(&missing, &id).await.unwrap_err();
+    assert_eq!(error.to_string(), expected);
+    let error = adapter
+        .existing_ids(&missing, vec![id.clone()])
+        .await
+        .unwrap_err();
+    assert_eq!(error.to_string(), expected);
+    let error = adapter
+        .get_vectors_with_versions(&missing, vec![id.clone()])
+        .await
+        .unwrap_err();
+    assert_eq!(error.to_string(), expected);
+    let error = adapter
+        .scroll_ordered(&missing, "created_ts", true, 10)
+        .await
+        .unwrap_err();
+    assert_eq!(error.to_string(), expected);
+    let error = adapter.collection_checksum(&missing).await.unwrap_err();
+    assert_eq!(error.to_string(), expected);
+    let error = adapter
+        .search_similar_with_progress(&missing, vec![1.0; 4], SearchParams::with_limit(5), |_| {})
+        .await
+        .unwrap_err();
+    assert_eq!(error.to_string(), expected);
+}
+
+#[tokio::test]
+async fn test_real_qdrant_metric_cache_follows_outside_recreate() {
+    skip_if_no_qdrant!();
+
+    let mut config = QdrantConfig::default();
+    config.url = "http://localhost:6334".to_string(); // Use gRPC port
+    let adapter = QdrantAdapter::connect(config.clone()).await.unwrap();
+    let other_client = QdrantAdapter::connect(config).await.unwrap();
+
+    let collection_name = format!("metric_cache_{}", Uuid::new_v4().simple());
+    let cosine = CollectionConfig::new(&collection_name, 4, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(cosine).await.unwrap();
+    let outcome = adapter
+        .search_detailed(&collection_name, vec![1.0; 4], SearchParams::with_limit(5))
+        .await
+        .unwrap();
+    assert!(outcome.higher_is_better);
+
+    // Another client swaps the collection for one with a distance metric
+    other_client
+        .delete_collection(&collection_name)
+        .await
+        .unwrap();
+    let euclidean = CollectionConfig::new(&collection_name, 4, DistanceMetric::Euclidean).unwrap();
+    other_client.create_collection(euclidean).await.unwrap();
+
+    // Reading the collection info refreshes the cached metric
+    adapter.get_collection_info(&collection_name).await.unwrap();
+    let outcome = adapter
+        .search_detailed(&collection_name, vec![1.0; 4], SearchParams::with_limit(5))
+        .await
+        .unwrap();
+    assert!(!outcome.higher_is_better);
+
+    adapter.delete_collection(&collection_name).await.unwrap();
+}
+
 #[tokio::test]
 async fn test_real_qdrant_configuration() {
     skip_if_no_qdrant!();
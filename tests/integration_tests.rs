@@ -3,9 +3,16 @@
 //! These tests verify the integration between the Qdrant adapter and the TYL framework,
 //! including vector operations, embedding services, and configuration management.
 
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
 use tyl_qdrant_adapter::{
-    CollectionConfig, ConfigPlugin, DistanceMetric, MockQdrantAdapter, QdrantConfig, SearchParams,
+    vector_from_embedding, BufferedVectorWriter, CollectionConfig, ConfigPlugin, ContentType,
+    DistanceMetric, Embedding, EmbeddingResult, EmbeddingService, EnsureOutcome, ExpectedJsonType,
+    HealthMonitor, MockQdrantAdapter, MultiVectorComparator, QdrantCollectionOptions, QdrantConfig,
+    QdrantHnswConfig, QdrantSearchOptions, SearchParams, SearchParamsExt, TenantScopedStore,
     Vector, VectorCollectionManager, VectorDatabase, VectorStore, VectorStoreHealth,
+    CONTENT_TYPE_PAYLOAD_KEY, EMBEDDING_MODEL_PAYLOAD_KEY,
 };
 
 #[tokio::test]
@@ -100,6 +107,12 @@ async fn test_health_check_integration() {
     let data = health_data.unwrap();
     assert!(data.contains_key("status"));
     assert_eq!(data["status"], serde_json::json!("healthy"));
+
+    // Health check latency is reported for SLO dashboards
+    let latency_ms = data["latency_ms"]
+        .as_f64()
+        .expect("latency_ms should be a number");
+    assert!(latency_ms >= 0.0);
 }
 
 #[tokio::test]
@@ -217,34 +230,20 @@ fn test_qdrant_config_serialization() {
 
 #[tokio::test]
 async fn test_advanced_filtering_range_queries() {
-    let adapter = MockQdrantAdapter::new();
-
-    // Create collection
-    let config = CollectionConfig::new("advanced_test", 3, DistanceMetric::Cosine).unwrap();
-    adapter.create_collection(config).await.unwrap();
-
-    // Store vectors with numeric metadata
+    // Vectors with numeric metadata
     let mut vector1 = Vector::new("item1".to_string(), vec![1.0, 0.0, 0.0]);
     vector1.add_metadata("price", serde_json::json!(100.0));
     vector1.add_metadata("rating", serde_json::json!(4.5));
-    adapter
-        .store_vector("advanced_test", vector1)
-        .await
-        .unwrap();
 
     let mut vector2 = Vector::new("item2".to_string(), vec![0.0, 1.0, 0.0]);
     vector2.add_metadata("price", serde_json::json!(200.0));
     vector2.add_metadata("rating", serde_json::json!(3.8));
-    adapter
-        .store_vector("advanced_test", vector2)
-        .await
-        .unwrap();
 
     let mut vector3 = Vector::new("item3".to_string(), vec![0.0, 0.0, 1.0]);
     vector3.add_metadata("price", serde_json::json!(150.0));
     vector3.add_metadata("rating", serde_json::json!(4.2));
-    adapter
-        .store_vector("advanced_test", vector3)
+
+    let adapter = MockQdrantAdapter::seeded("advanced_test", 3, vec![vector1, vector2, vector3])
         .await
         .unwrap();
 
@@ -395,3 +394,2798 @@ async fn test_backward_compatibility_simple_filters() {
     // Should work with mock adapter
     assert!(results.is_ok());
 }
+
+#[tokio::test]
+async fn test_array_and_nested_metadata_round_trip() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("nested_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let tags = serde_json::json!(["rust", "vectors"]);
+    let author = serde_json::json!({"name": "Ada", "stats": {"posts": 12}});
+
+    let mut vector = Vector::new("nested".to_string(), vec![1.0, 0.0]);
+    vector.add_metadata("tags", tags.clone());
+    vector.add_metadata("author", author.clone());
+    adapter.store_vector("nested_test", vector).await.unwrap();
+
+    let retrieved = adapter
+        .get_vector("nested_test", "nested")
+        .await
+        .unwrap()
+        .expect("vector should exist");
+    assert_eq!(retrieved.metadata.get("tags"), Some(&tags));
+    assert_eq!(retrieved.metadata.get("author"), Some(&author));
+}
+
+#[tokio::test]
+async fn test_null_metadata_is_null_filter() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("null_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let mut active = Vector::new("active".to_string(), vec![1.0, 0.0]);
+    active.add_metadata("deleted_at", serde_json::Value::Null);
+    adapter.store_vector("null_test", active).await.unwrap();
+
+    let mut deleted = Vector::new("deleted".to_string(), vec![0.0, 1.0]);
+    deleted.add_metadata("deleted_at", serde_json::json!("2024-01-01T00:00:00Z"));
+    adapter.store_vector("null_test", deleted).await.unwrap();
+
+    // The null key survives a store/retrieve round trip
+    let retrieved = adapter
+        .get_vector("null_test", "active")
+        .await
+        .unwrap()
+        .expect("vector should exist");
+    assert_eq!(
+        retrieved.metadata.get("deleted_at"),
+        Some(&serde_json::Value::Null)
+    );
+
+    let search_params = SearchParams::with_limit(10)
+        .with_filter("deleted_at", serde_json::json!({"$is_null": true}));
+    let results = adapter
+        .search_similar("null_test", vec![0.5, 0.5], search_params)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].vector.id, "active");
+}
+
+#[tokio::test]
+async fn test_optimize_collection() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("optimize_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    assert!(adapter.optimize_collection("optimize_test").await.is_ok());
+    assert!(adapter.optimize_collection("missing").await.is_err());
+}
+
+#[tokio::test]
+async fn test_update_optimizer_config() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("optimizer_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    assert!(adapter
+        .update_optimizer_config("optimizer_test", Some(0), Some(2))
+        .await
+        .is_ok());
+    assert!(adapter
+        .update_optimizer_config("missing", Some(0), None)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_wait_until_ready() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("ready_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    // Fresh collections are green
+    let result = adapter
+        .wait_until_ready("ready_test", Duration::from_millis(50))
+        .await;
+    assert!(result.is_ok());
+
+    // A collection that never goes green times out
+    adapter.set_collection_status("ready_test", "yellow");
+    let error = adapter
+        .wait_until_ready("ready_test", Duration::from_millis(50))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("not ready"));
+    assert!(error.to_string().contains("yellow"));
+}
+
+#[tokio::test]
+async fn test_find_by_filter_exact_match() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("find_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    for (id, external_id) in [("a", "ext-1"), ("b", "ext-2"), ("c", "ext-1")] {
+        let mut vector = Vector::new(id.to_string(), vec![1.0, 0.0]);
+        vector.add_metadata("external_id", serde_json::json!(external_id));
+        adapter.store_vector("find_test", vector).await.unwrap();
+    }
+
+    let by_external_id =
+        || SearchParams::with_limit(10).with_filter("external_id", serde_json::json!("ext-1"));
+    let mut found = adapter
+        .find_by_filter("find_test", by_external_id(), 10)
+        .await
+        .unwrap();
+    found.sort_by(|a, b| a.id.cmp(&b.id));
+    let ids: Vec<&str> = found.iter().map(|v| v.id.as_str()).collect();
+    assert_eq!(ids, vec!["a", "c"]);
+
+    // The limit caps the number of matches returned
+    let limited = adapter
+        .find_by_filter("find_test", by_external_id(), 1)
+        .await
+        .unwrap();
+    assert_eq!(limited.len(), 1);
+}
+
+#[tokio::test]
+async fn test_store_vector_if_absent() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("absent_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let mut original = Vector::new("doc".to_string(), vec![1.0, 0.0]);
+    original.add_metadata("version", serde_json::json!(1));
+    let inserted = adapter
+        .store_vector_if_absent("absent_test", original)
+        .await
+        .unwrap();
+    assert!(inserted);
+
+    let mut replacement = Vector::new("doc".to_string(), vec![0.0, 1.0]);
+    replacement.add_metadata("version", serde_json::json!(2));
+    let inserted = adapter
+        .store_vector_if_absent("absent_test", replacement)
+        .await
+        .unwrap();
+    assert!(!inserted);
+
+    // The existing vector was not overwritten
+    let stored = adapter
+        .get_vector("absent_test", "doc")
+        .await
+        .unwrap()
+        .expect("vector should exist");
+    assert_eq!(stored.metadata.get("version"), Some(&serde_json::json!(1)));
+}
+
+#[tokio::test]
+async fn test_search_groups_one_hit_per_group() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("groups_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    for (id, document_id) in [
+        ("chunk1", "doc-a"),
+        ("chunk2", "doc-a"),
+        ("chunk3", "doc-b"),
+    ] {
+        let mut vector = Vector::new(id.to_string(), vec![1.0, 0.0]);
+        vector.add_metadata("document_id", serde_json::json!(document_id));
+        adapter.store_vector("groups_test", vector).await.unwrap();
+    }
+
+    let groups = adapter
+        .search_groups(
+            "groups_test",
+            vec![1.0, 0.0],
+            "document_id",
+            10,
+            1,
+            SearchParams::with_limit(10),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(groups.len(), 2);
+    assert!(groups.iter().all(|group| group.hits.len() == 1));
+    for group in &groups {
+        assert_eq!(
+            group.hits[0].vector.metadata.get("document_id"),
+            Some(&group.group_id)
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_search_with_selected_payload_fields() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("fields_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let mut vector = Vector::new("doc".to_string(), vec![1.0, 0.0]);
+    vector.add_metadata("title", serde_json::json!("Title"));
+    vector.add_metadata("url", serde_json::json!("https://example.com"));
+    vector.add_metadata("body", serde_json::json!("A very long body"));
+    adapter.store_vector("fields_test", vector).await.unwrap();
+
+    let options = QdrantSearchOptions::new().with_payload_fields(["title", "url"]);
+    let results = adapter
+        .search_similar_with_options(
+            "fields_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(10),
+            options,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+    let metadata = &results[0].vector.metadata;
+    assert_eq!(metadata.len(), 2);
+    assert!(metadata.contains_key("title"));
+    assert!(metadata.contains_key("url"));
+    assert!(!metadata.contains_key("body"));
+}
+
+#[tokio::test]
+async fn test_clear_collection_keeps_collection() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("clear_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let vectors = vec![
+        Vector::new("a".to_string(), vec![1.0, 0.0]),
+        Vector::new("b".to_string(), vec![0.0, 1.0]),
+    ];
+    adapter
+        .store_vectors_batch("clear_test", vectors)
+        .await
+        .unwrap();
+
+    adapter.clear_collection("clear_test").await.unwrap();
+
+    let stats = adapter.get_collection_stats("clear_test").await.unwrap();
+    assert_eq!(stats["vectors_count"], serde_json::json!(0));
+    assert!(adapter
+        .get_collection_info("clear_test")
+        .await
+        .unwrap()
+        .is_some());
+
+    assert!(adapter.clear_collection("missing").await.is_err());
+}
+
+/// Embedder stub producing `[text length, 1.0, ...]` with `dim` components, so embeddings are
+/// predictable and sized for the collection under test
+struct FixedDimEmbedder {
+    dim: usize,
+}
+
+#[async_trait]
+impl EmbeddingService for FixedDimEmbedder {
+    async fn generate_embedding(
+        &self,
+        text: &str,
+        content_type: ContentType,
+    ) -> EmbeddingResult<Embedding> {
+        let mut vector = vec![1.0; self.dim];
+        vector[0] = text.len() as f32;
+        Ok(Embedding::new(
+            vector,
+            format!("fixed-{}-model", self.dim),
+            content_type,
+        ))
+    }
+
+    async fn generate_embeddings_batch(
+        &self,
+        texts: Vec<String>,
+        content_type: ContentType,
+    ) -> EmbeddingResult<Vec<Embedding>> {
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for text in &texts {
+            embeddings.push(self.generate_embedding(text, content_type.clone()).await?);
+        }
+        Ok(embeddings)
+    }
+}
+
+#[tokio::test]
+async fn test_reembed_collection_replaces_embeddings() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("reembed_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let with_text = vector_from_embedding(
+        "a",
+        Embedding::new(vec![0.0, 0.0], "old-model".to_string(), ContentType::Text),
+        std::collections::HashMap::from([("text".to_string(), serde_json::json!("hello"))]),
+        adapter.config(),
+    );
+    let without_text = Vector::new("b".to_string(), vec![0.5, 0.5]);
+    adapter
+        .store_vectors_batch("reembed_test", vec![with_text, without_text])
+        .await
+        .unwrap();
+
+    let reembedded = adapter
+        .reembed_collection(
+            "reembed_test",
+            &FixedDimEmbedder { dim: 2 },
+            "text",
+            ContentType::Text,
+        )
+        .await
+        .unwrap();
+    assert_eq!(reembedded, 1);
+
+    let updated = adapter
+        .get_vector("reembed_test", "a")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated.embedding, vec![5.0, 1.0]);
+    assert_eq!(updated.metadata["text"], serde_json::json!("hello"));
+    // The payload names the model that produced the new embedding
+    assert_eq!(
+        updated.metadata[EMBEDDING_MODEL_PAYLOAD_KEY],
+        serde_json::json!("fixed-2-model")
+    );
+
+    let skipped = adapter
+        .get_vector("reembed_test", "b")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(skipped.embedding, vec![0.5, 0.5]);
+}
+
+#[tokio::test]
+async fn test_validate_embedding_compatibility() {
+    let mut vector = Vector::new("a".to_string(), vec![0.0, 0.0]);
+    vector.add_metadata("text", serde_json::json!("hello"));
+    let adapter = MockQdrantAdapter::seeded("embedding_test", 2, vec![vector])
+        .await
+        .unwrap();
+
+    adapter
+        .validate_embedding_compatibility("embedding_test", &FixedDimEmbedder { dim: 2 })
+        .await
+        .unwrap();
+    assert!(adapter
+        .validate_embedding_compatibility("embedding_test", &FixedDimEmbedder { dim: 3 })
+        .await
+        .is_err());
+    assert!(adapter
+        .validate_embedding_compatibility("missing", &FixedDimEmbedder { dim: 2 })
+        .await
+        .is_err());
+
+    // Re-embedding with the wrong model fails before any vector is touched
+    assert!(adapter
+        .reembed_collection(
+            "embedding_test",
+            &FixedDimEmbedder { dim: 3 },
+            "text",
+            ContentType::Text
+        )
+        .await
+        .is_err());
+    let unchanged = adapter
+        .get_vector("embedding_test", "a")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unchanged.embedding, vec![0.0, 0.0]);
+}
+
+#[tokio::test]
+async fn test_copy_collection_is_independent() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("copy_source", 2, DistanceMetric::Euclidean).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    adapter
+        .store_vectors_batch(
+            "copy_source",
+            vec![
+                Vector::new("a".to_string(), vec![1.0, 0.0]),
+                Vector::new("b".to_string(), vec![0.0, 1.0]),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let copied = adapter
+        .copy_collection("copy_source", "copy_dest")
+        .await
+        .unwrap();
+    assert_eq!(copied, 2);
+
+    let dest_config = adapter
+        .get_collection_info("copy_dest")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(dest_config.dimension, 2);
+    assert!(matches!(
+        dest_config.distance_metric,
+        DistanceMetric::Euclidean
+    ));
+
+    // Changes to the copy must not leak back into the source
+    adapter.delete_vector("copy_dest", "a").await.unwrap();
+    assert!(adapter
+        .get_vector("copy_source", "a")
+        .await
+        .unwrap()
+        .is_some());
+    assert!(adapter
+        .get_vector("copy_dest", "b")
+        .await
+        .unwrap()
+        .is_some());
+
+    assert!(adapter
+        .copy_collection("copy_source", "copy_dest")
+        .await
+        .is_err());
+
+    // Named vectors cannot be carried over, so the copy is refused up front
+    adapter
+        .update_named_vector("copy_source", "a", "image", vec![0.5, 0.5])
+        .await
+        .unwrap();
+    let error = adapter
+        .copy_collection("copy_source", "named_dest")
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("named vectors"), "{error}");
+    assert!(adapter
+        .get_collection_info("named_dest")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_search_similar_checked_rejects_metric_mismatch() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("metric_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    adapter
+        .store_vector("metric_test", Vector::new("a".to_string(), vec![1.0, 0.0]))
+        .await
+        .unwrap();
+
+    let results = adapter
+        .search_similar_checked(
+            "metric_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(5),
+            DistanceMetric::Cosine,
+        )
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let mismatch = adapter
+        .search_similar_checked(
+            "metric_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(5),
+            DistanceMetric::DotProduct,
+        )
+        .await;
+    assert!(mismatch.is_err());
+}
+
+#[tokio::test]
+async fn test_connection_diagnostics_populated() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("diagnostics_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let diagnostics = adapter.connection_diagnostics().await.unwrap();
+    assert!(diagnostics.healthy);
+    assert!(!diagnostics.url.is_empty());
+    assert!(diagnostics.server_version.is_some());
+    assert_eq!(diagnostics.collection_count, Some(1));
+    assert_eq!(diagnostics.timeout, Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn test_is_collection_healthy() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("probe_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    assert!(adapter.is_collection_healthy("probe_test").await.unwrap());
+
+    assert!(!adapter.is_collection_healthy("missing").await.unwrap());
+
+    adapter.set_collection_status("probe_test", "red");
+    assert!(!adapter.is_collection_healthy("probe_test").await.unwrap());
+}
+
+#[tokio::test]
+async fn test_delete_by_filter_dry_run() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("delete_filter_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let mut vectors = Vec::new();
+    for (id, status) in [("a", "archived"), ("b", "archived"), ("c", "active")] {
+        let mut vector = Vector::new(id.to_string(), vec![1.0, 0.0]);
+        vector.add_metadata("status", serde_json::json!(status));
+        vectors.push(vector);
+    }
+    adapter
+        .store_vectors_batch("delete_filter_test", vectors)
+        .await
+        .unwrap();
+
+    let archived =
+        || SearchParams::with_limit(10).with_filter("status", serde_json::json!("archived"));
+
+    assert_eq!(
+        adapter
+            .count_matching("delete_filter_test", archived())
+            .await
+            .unwrap(),
+        2
+    );
+
+    let would_delete = adapter
+        .delete_by_filter("delete_filter_test", archived(), true)
+        .await
+        .unwrap();
+    assert_eq!(would_delete, 2);
+    assert!(adapter
+        .get_vector("delete_filter_test", "a")
+        .await
+        .unwrap()
+        .is_some());
+
+    let deleted = adapter
+        .delete_by_filter("delete_filter_test", archived(), false)
+        .await
+        .unwrap();
+    assert_eq!(deleted, 2);
+    assert!(adapter
+        .get_vector("delete_filter_test", "a")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(adapter
+        .get_vector("delete_filter_test", "c")
+        .await
+        .unwrap()
+        .is_some());
+
+    assert!(adapter
+        .delete_by_filter("delete_filter_test", SearchParams::with_limit(10), false)
+        .await
+        .is_err());
+}
+
+async fn buffered_test_store(collection: &str) -> Arc<MockQdrantAdapter> {
+    let adapter = Arc::new(MockQdrantAdapter::new());
+    let config = CollectionConfig::new(collection, 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    adapter
+}
+
+async fn stored_count(adapter: &MockQdrantAdapter, collection: &str) -> serde_json::Value {
+    adapter.get_collection_stats(collection).await.unwrap()["vectors_count"].clone()
+}
+
+#[tokio::test]
+async fn test_buffered_writer_flushes_at_batch_size() {
+    let adapter = buffered_test_store("buffered_batch").await;
+    let writer = BufferedVectorWriter::new(
+        adapter.clone(),
+        "buffered_batch",
+        3,
+        Duration::from_secs(3600),
+    )
+    .unwrap();
+
+    for id in ["a", "b"] {
+        writer
+            .store_vector(Vector::new(id.to_string(), vec![1.0, 0.0]))
+            .await
+            .unwrap();
+    }
+    assert_eq!(writer.buffered_len(), 2);
+    assert_eq!(
+        stored_count(&adapter, "buffered_batch").await,
+        serde_json::json!(0)
+    );
+
+    writer
+        .store_vector(Vector::new("c".to_string(), vec![0.0, 1.0]))
+        .await
+        .unwrap();
+    assert_eq!(writer.buffered_len(), 0);
+    assert_eq!(
+        stored_count(&adapter, "buffered_batch").await,
+        serde_json::json!(3)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_buffered_writer_explicit_and_interval_flush() {
+    let adapter = buffered_test_store("buffered_flush").await;
+    let writer = BufferedVectorWriter::new(
+        adapter.clone(),
+        "buffered_flush",
+        100,
+        Duration::from_millis(50),
+    )
+    .unwrap();
+
+    writer
+        .store_vector(Vector::new("a".to_string(), vec![1.0, 0.0]))
+        .await
+        .unwrap();
+    writer.flush().await.unwrap();
+    assert!(adapter
+        .get_vector("buffered_flush", "a")
+        .await
+        .unwrap()
+        .is_some());
+
+    writer
+        .store_vector(Vector::new("b".to_string(), vec![0.0, 1.0]))
+        .await
+        .unwrap();
+    // Time is paused: this advances the clock past exactly one interval
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert!(adapter
+        .get_vector("buffered_flush", "b")
+        .await
+        .unwrap()
+        .is_some());
+
+    assert!(
+        BufferedVectorWriter::new(adapter, "buffered_flush", 0, Duration::from_secs(1)).is_err()
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_buffered_writer_keeps_failed_background_batches() {
+    let adapter = buffered_test_store("buffered_retry").await;
+    let writer = BufferedVectorWriter::new(
+        adapter.clone(),
+        "buffered_retry",
+        100,
+        Duration::from_millis(50),
+    )
+    .unwrap();
+
+    adapter.fail_next_store("buffered_retry", "b");
+    for id in ["a", "b"] {
+        writer
+            .store_vector(Vector::new(id.to_string(), vec![1.0, 0.0]))
+            .await
+            .unwrap();
+    }
+    tokio::time::sleep(Duration::from_millis(60)).await;
+
+    // The interval write stored "a" and kept the rejected "b" for retry
+    assert!(adapter
+        .get_vector("buffered_retry", "a")
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(writer.buffered_len(), 1);
+
+    // The failure is reported, and the new vector is buffered regardless
+    assert!(writer
+        .store_vector(Vector::new("c".to_string(), vec![0.0, 1.0]))
+        .await
+        .is_err());
+    assert_eq!(writer.buffered_len(), 2);
+
+    writer.flush().await.unwrap();
+    assert_eq!(writer.buffered_len(), 0);
+    for id in ["b", "c"] {
+        assert!(adapter
+            .get_vector("buffered_retry", id)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    // Vectors the store keeps rejecting can be given up on
+    adapter.fail_next_store("buffered_retry", "d");
+    writer
+        .store_vector(Vector::new("d".to_string(), vec![1.0, 1.0]))
+        .await
+        .unwrap();
+    assert!(writer.flush().await.is_err());
+    let failed = writer.take_failed();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].id, "d");
+    writer.flush().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_collection_default_metadata() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("tenant_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    adapter.set_collection_defaults(
+        "tenant_test",
+        std::collections::HashMap::from([("tenant".to_string(), serde_json::json!("acme"))]),
+    );
+
+    let mut explicit = Vector::new("b".to_string(), vec![0.0, 1.0]);
+    explicit.add_metadata("tenant", serde_json::json!("globex"));
+    adapter
+        .store_vectors_batch(
+            "tenant_test",
+            vec![Vector::new("a".to_string(), vec![1.0, 0.0]), explicit],
+        )
+        .await
+        .unwrap();
+
+    let defaulted = adapter
+        .get_vector("tenant_test", "a")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(defaulted.metadata["tenant"], serde_json::json!("acme"));
+
+    let overridden = adapter
+        .get_vector("tenant_test", "b")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(overridden.metadata["tenant"], serde_json::json!("globex"));
+
+    adapter.set_collection_defaults("tenant_test", std::collections::HashMap::new());
+    adapter
+        .store_vector("tenant_test", Vector::new("c".to_string(), vec![1.0, 1.0]))
+        .await
+        .unwrap();
+    let plain = adapter
+        .get_vector("tenant_test", "c")
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!plain.metadata.contains_key("tenant"));
+}
+
+#[tokio::test]
+async fn test_tenant_scoped_store_isolation() {
+    let adapter = Arc::new(MockQdrantAdapter::new());
+    let config = CollectionConfig::new("tenant_scope_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let acme = TenantScopedStore::new(adapter.clone(), "tenant", "acme");
+    let globex = TenantScopedStore::new(adapter.clone(), "tenant", "globex");
+    acme.store_vector(
+        "tenant_scope_test",
+        Vector::new("a".to_string(), vec![1.0, 0.0]),
+    )
+    .await
+    .unwrap();
+    globex
+        .store_vector(
+            "tenant_scope_test",
+            Vector::new("g".to_string(), vec![1.0, 0.0]),
+        )
+        .await
+        .unwrap();
+
+    let params = acme.scoped_params(SearchParams::with_limit(10)).unwrap();
+    assert_eq!(params.filters["tenant"], serde_json::json!("acme"));
+
+    let results = acme
+        .search_similar(
+            "tenant_scope_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].vector.id, "a");
+
+    // Filtering on another tenant is rejected instead of leaking its data
+    let cross_tenant =
+        || SearchParams::with_limit(10).with_filter("tenant", serde_json::json!("globex"));
+    assert!(acme
+        .search_similar("tenant_scope_test", vec![1.0, 0.0], cross_tenant())
+        .await
+        .is_err());
+    assert!(acme
+        .delete_by_filter("tenant_scope_test", cross_tenant(), false)
+        .await
+        .is_err());
+
+    // An unsupported operator aborts the delete instead of deleting the whole tenant
+    let unsupported =
+        SearchParams::with_limit(10).with_filter("status", serde_json::json!({"$ne": "kept"}));
+    assert!(acme
+        .delete_by_filter("tenant_scope_test", unsupported, false)
+        .await
+        .is_err());
+    assert!(adapter
+        .get_vector("tenant_scope_test", "a")
+        .await
+        .unwrap()
+        .is_some());
+
+    let mut foreign = Vector::new("x".to_string(), vec![0.0, 1.0]);
+    foreign.add_metadata("tenant", serde_json::json!("globex"));
+    assert!(acme
+        .store_vector("tenant_scope_test", foreign)
+        .await
+        .is_err());
+
+    // Deletes only reach the scoped tenant's points
+    let deleted = acme
+        .delete_by_filter("tenant_scope_test", SearchParams::with_limit(10), false)
+        .await
+        .unwrap();
+    assert_eq!(deleted, 1);
+    assert!(adapter
+        .get_vector("tenant_scope_test", "g")
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_export_import_round_trip() {
+    let adapter = MockQdrantAdapter::new();
+    for name in ["export_source", "import_dest"] {
+        let config = CollectionConfig::new(name, 2, DistanceMetric::Cosine).unwrap();
+        adapter.create_collection(config).await.unwrap();
+    }
+
+    let mut tagged = Vector::new("a".to_string(), vec![1.0, 0.0]);
+    tagged.add_metadata("title", serde_json::json!("First"));
+    tagged.add_metadata("tags", serde_json::json!(["x", "y"]));
+    adapter
+        .store_vectors_batch(
+            "export_source",
+            vec![tagged, Vector::new("b".to_string(), vec![0.0, 1.0])],
+        )
+        .await
+        .unwrap();
+
+    let mut buffer = Vec::new();
+    let exported = adapter
+        .export_collection("export_source", &mut buffer)
+        .await
+        .unwrap();
+    assert_eq!(exported, 2);
+    assert_eq!(
+        String::from_utf8(buffer.clone()).unwrap().lines().count(),
+        2
+    );
+
+    let imported = adapter
+        .import_collection("import_dest", buffer.as_slice())
+        .await
+        .unwrap();
+    assert_eq!(imported, 2);
+
+    let restored = adapter
+        .get_vector("import_dest", "a")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(restored.embedding, vec![1.0, 0.0]);
+    assert_eq!(restored.metadata["title"], serde_json::json!("First"));
+    assert_eq!(restored.metadata["tags"], serde_json::json!(["x", "y"]));
+    assert!(adapter
+        .get_vector("import_dest", "b")
+        .await
+        .unwrap()
+        .is_some());
+
+    assert!(adapter
+        .import_collection("import_dest", "not json\n".as_bytes())
+        .await
+        .is_err());
+
+    // Batches stored before a bad line stay stored, and the error says how many
+    let one_per_batch = MockQdrantAdapter::with_config(QdrantConfig {
+        max_batch_size: 1,
+        ..QdrantConfig::default()
+    });
+    let config = CollectionConfig::new("partial_import", 2, DistanceMetric::Cosine).unwrap();
+    one_per_batch.create_collection(config).await.unwrap();
+    let partial = format!("{}not json\n", String::from_utf8(buffer).unwrap());
+    let message = one_per_batch
+        .import_collection("partial_import", partial.as_bytes())
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("committing 2 vectors"), "{message}");
+    assert!(one_per_batch
+        .get_vector("partial_import", "b")
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[cfg(feature = "import")]
+#[tokio::test]
+async fn test_import_csv() {
+    use tyl_qdrant_adapter::CsvImportOptions;
+
+    let adapter = MockQdrantAdapter::new();
+    let config = CollectionConfig::new("csv_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let csv = "id,title,e0,e1\n1,First,0.5,0.25\n2,Second,1.0,0.0\n";
+    let imported = adapter
+        .import_csv(
+            "csv_test",
+            csv.as_bytes(),
+            &CsvImportOptions::new("id", ["e0", "e1"]).with_metadata_columns(["title"]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(imported, 2);
+
+    let first = adapter.get_vector("csv_test", "1").await.unwrap().unwrap();
+    assert_eq!(first.embedding, vec![0.5, 0.25]);
+    assert_eq!(first.metadata["title"], serde_json::json!("First"));
+
+    // Embedding columns must match the collection dimension
+    assert!(adapter
+        .import_csv(
+            "csv_test",
+            csv.as_bytes(),
+            &CsvImportOptions::new("id", ["e0"])
+        )
+        .await
+        .is_err());
+    assert!(adapter
+        .import_csv(
+            "csv_test",
+            csv.as_bytes(),
+            &CsvImportOptions::new("missing", ["e0", "e1"])
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_payload_schema_rejects_invalid_vectors() {
+    let adapter = MockQdrantAdapter::new();
+    let config = CollectionConfig::new("schema_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    adapter.set_payload_schema(
+        "schema_test",
+        std::collections::HashMap::from([("price".to_string(), ExpectedJsonType::Number)]),
+    );
+
+    let mut priced = Vector::new("a".to_string(), vec![1.0, 0.0]);
+    priced.add_metadata("price", serde_json::json!(100.0));
+    adapter.store_vector("schema_test", priced).await.unwrap();
+
+    let mut wrong_type = Vector::new("b".to_string(), vec![0.0, 1.0]);
+    wrong_type.add_metadata("price", serde_json::json!("cheap"));
+    assert!(adapter
+        .store_vector("schema_test", wrong_type)
+        .await
+        .is_err());
+
+    let missing = Vector::new("c".to_string(), vec![0.0, 1.0]);
+    let results = adapter
+        .store_vectors_batch("schema_test", vec![missing])
+        .await
+        .unwrap();
+    assert!(results[0].is_err());
+
+    assert!(adapter
+        .get_vector("schema_test", "b")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(adapter
+        .get_vector("schema_test", "c")
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_search_similar_with_progress_reports_pages() {
+    let adapter = MockQdrantAdapter::new();
+    let config = CollectionConfig::new("progress_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let vectors: Vec<Vector> = (0..250)
+        .map(|i| Vector::new(i.to_string(), vec![1.0, i as f32 / 250.0]))
+        .collect();
+    for chunk in vectors.chunks(100) {
+        adapter
+            .store_vectors_batch("progress_test", chunk.to_vec())
+            .await
+            .unwrap();
+    }
+
+    let mut progress = Vec::new();
+    let results = adapter
+        .search_similar_with_progress(
+            "progress_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(250),
+            |count| progress.push(count),
+        )
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 250);
+    assert_eq!(progress, vec![100, 200, 250]);
+
+    let mut progress = Vec::new();
+    adapter
+        .search_similar_with_progress(
+            "progress_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(3),
+            |count| progress.push(count),
+        )
+        .await
+        .unwrap();
+    assert_eq!(progress, vec![3]);
+
+    // Fewer matches than the limit: the short page ends the search
+    let mut progress = Vec::new();
+    let results = adapter
+        .search_similar_with_progress(
+            "progress_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(400),
+            |count| progress.push(count),
+        )
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 250);
+    assert_eq!(progress, vec![100, 200, 250]);
+
+    let mut progress = Vec::new();
+    let results = adapter
+        .search_similar_with_progress(
+            "progress_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(0),
+            |count| progress.push(count),
+        )
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+    assert_eq!(progress, vec![0]);
+}
+
+#[tokio::test]
+async fn test_create_collection_if_not_exists() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = || CollectionConfig::new("idempotent_test", 2, DistanceMetric::Cosine).unwrap();
+    assert!(adapter
+        .create_collection_if_not_exists(config())
+        .await
+        .unwrap());
+    assert!(!adapter
+        .create_collection_if_not_exists(config())
+        .await
+        .unwrap());
+
+    // A plain create still reports the conflict
+    assert!(adapter.create_collection(config()).await.is_err());
+}
+
+#[tokio::test]
+async fn test_get_vectors_with_versions() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("versions_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    adapter
+        .store_vector(
+            "versions_test",
+            Vector::new("a".to_string(), vec![1.0, 0.0]),
+        )
+        .await
+        .unwrap();
+    adapter
+        .store_vector(
+            "versions_test",
+            Vector::new("b".to_string(), vec![0.0, 1.0]),
+        )
+        .await
+        .unwrap();
+
+    let ids = || vec!["a".to_string(), "missing".to_string(), "b".to_string()];
+    let before = adapter
+        .get_vectors_with_versions("versions_test", ids())
+        .await
+        .unwrap();
+    assert_eq!(before.len(), 3);
+    assert!(before[1].is_none());
+    let (vector_a, version_a) = before[0].clone().unwrap();
+    let (_, version_b) = before[2].clone().unwrap();
+    assert_eq!(vector_a.id, "a");
+
+    // Re-storing a point bumps its version; untouched points keep theirs
+    adapter
+        .store_vector(
+            "versions_test",
+            Vector::new("a".to_string(), vec![0.5, 0.5]),
+        )
+        .await
+        .unwrap();
+    let after = adapter
+        .get_vectors_with_versions("versions_test", ids())
+        .await
+        .unwrap();
+    assert!(after[0].as_ref().unwrap().1 > version_a);
+    assert_eq!(after[2].as_ref().unwrap().1, version_b);
+}
+
+#[tokio::test]
+async fn test_multivector_collection() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("colbert_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter
+        .create_collection_with_options(
+            config,
+            QdrantCollectionOptions::new().with_multivector(MultiVectorComparator::MaxSim),
+        )
+        .await
+        .unwrap();
+
+    let rows = vec![vec![0.1, 0.2], vec![0.3, 0.4], vec![0.5, 0.6]];
+    let mut metadata = std::collections::HashMap::new();
+    metadata.insert("title".to_string(), serde_json::json!("late interaction"));
+    adapter
+        .store_multivector("colbert_test", "doc", rows, metadata)
+        .await
+        .unwrap();
+
+    let stored = adapter
+        .get_vector("colbert_test", "doc")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.embedding, vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6]);
+    assert_eq!(
+        stored.metadata.get("title"),
+        Some(&serde_json::json!("late interaction"))
+    );
+
+    // Rows must match the collection dimension
+    assert!(adapter
+        .store_multivector(
+            "colbert_test",
+            "bad",
+            vec![vec![0.1, 0.2, 0.3]],
+            std::collections::HashMap::new()
+        )
+        .await
+        .is_err());
+
+    // Plain collections reject multivectors
+    let plain = CollectionConfig::new("plain_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(plain).await.unwrap();
+    assert!(adapter
+        .store_multivector(
+            "plain_test",
+            "doc",
+            vec![vec![0.1, 0.2]],
+            std::collections::HashMap::new()
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_health_monitor_observes_transitions() {
+    let adapter = Arc::new(MockQdrantAdapter::new());
+    let transitions = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let monitor = HealthMonitor::start(adapter.clone(), Duration::from_millis(10), {
+        let transitions = Arc::clone(&transitions);
+        move |healthy| transitions.lock().unwrap().push(healthy)
+    })
+    .unwrap();
+
+    let wait_for = |expected: Vec<bool>| {
+        let transitions = Arc::clone(&transitions);
+        async move {
+            for _ in 0..100 {
+                if *transitions.lock().unwrap() == expected {
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            panic!(
+                "expected transitions {expected:?}, got {:?}",
+                transitions.lock().unwrap()
+            );
+        }
+    };
+
+    wait_for(vec![true]).await;
+    let handle = monitor.handle();
+    assert!(handle.is_healthy());
+    let healthy_at = handle.status().last_healthy_at.unwrap();
+
+    adapter.set_healthy(false);
+    wait_for(vec![true, false]).await;
+    let status = handle.status();
+    assert!(!status.healthy);
+    assert!(status.last_healthy_at.unwrap() >= healthy_at);
+    assert!(status.last_checked_at.unwrap() > status.last_healthy_at.unwrap());
+
+    // Stopped monitors keep the last status but observe no further transitions
+    monitor.stop();
+    adapter.set_healthy(true);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(*transitions.lock().unwrap(), vec![true, false]);
+    assert!(!handle.is_healthy());
+}
+
+#[test]
+fn test_health_monitor_rejects_zero_interval() {
+    let adapter = Arc::new(MockQdrantAdapter::new());
+    assert!(HealthMonitor::start(adapter, Duration::ZERO, |_| {}).is_err());
+}
+
+#[tokio::test]
+async fn test_store_vector_detailed() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("detailed_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let outcome = adapter
+        .store_vector_detailed(
+            "detailed_test",
+            Vector::new("doc-1".to_string(), vec![1.0, 0.0]),
+        )
+        .await
+        .unwrap();
+    assert_eq!(outcome.id, "doc-1");
+    assert_eq!(outcome.collection, "detailed_test");
+    assert!(outcome.duration < Duration::from_secs(5));
+    assert!(adapter
+        .get_vector("detailed_test", "doc-1")
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_threshold_is_a_max_distance_for_euclidean() {
+    let adapter = MockQdrantAdapter::new();
+    for (name, metric) in [
+        ("cosine_threshold", DistanceMetric::Cosine),
+        ("euclidean_threshold", DistanceMetric::Euclidean),
+    ] {
+        let config = CollectionConfig::new(name, 2, metric).unwrap();
+        adapter.create_collection(config).await.unwrap();
+        adapter
+            .store_vector(name, Vector::new("doc".to_string(), vec![1.0, 0.0]))
+            .await
+            .unwrap();
+    }
+
+    // The mock scores every hit 0.9: a similarity for cosine, a distance for Euclidean
+    let search = |collection: &'static str, threshold: f32| {
+        let adapter = adapter.clone();
+        async move {
+            adapter
+                .search_detailed(
+                    collection,
+                    vec![1.0, 0.0],
+                    SearchParams::with_limit(5).with_threshold(threshold),
+                )
+                .await
+                .unwrap()
+        }
+    };
+
+    let cosine = search("cosine_threshold", 0.5).await;
+    assert!(cosine.higher_is_better);
+    assert_eq!(cosine.returned_count, 1);
+    assert_eq!(search("cosine_threshold", 0.95).await.returned_count, 0);
+
+    let euclidean = search("euclidean_threshold", 0.5).await;
+    assert!(!euclidean.higher_is_better);
+    assert_eq!(euclidean.returned_count, 0);
+    assert_eq!(search("euclidean_threshold", 0.95).await.returned_count, 1);
+}
+
+#[tokio::test]
+async fn test_search_within_ids() {
+    let adapter = MockQdrantAdapter::seeded(
+        "allowlist_test",
+        2,
+        (1..=6)
+            .map(|i| {
+                let mut vector = Vector::new(i.to_string(), vec![i as f32, 1.0]);
+                let team = if i % 2 == 0 { "even" } else { "odd" };
+                vector.add_metadata("team", serde_json::json!(team));
+                vector
+            })
+            .collect(),
+    )
+    .await
+    .unwrap();
+
+    let allowed = vec!["2".to_string(), "3".to_string(), "4".to_string()];
+    let results = adapter
+        .search_within_ids(
+            "allowlist_test",
+            vec![1.0, 1.0],
+            &allowed,
+            SearchParams::with_limit(10),
+        )
+        .await
+        .unwrap();
+    let mut ids: Vec<&str> = results.iter().map(|r| r.vector.id.as_str()).collect();
+    ids.sort();
+    assert_eq!(ids, vec!["2", "3", "4"]);
+
+    // The allowlist is ANDed with the caller's filters
+    let results = adapter
+        .search_within_ids(
+            "allowlist_test",
+            vec![1.0, 1.0],
+            &allowed,
+            SearchParams::with_limit(10).with_filter("team", serde_json::json!("even")),
+        )
+        .await
+        .unwrap();
+    let mut ids: Vec<&str> = results.iter().map(|r| r.vector.id.as_str()).collect();
+    ids.sort();
+    assert_eq!(ids, vec!["2", "4"]);
+
+    let results = adapter
+        .search_within_ids(
+            "allowlist_test",
+            vec![1.0, 1.0],
+            &[],
+            SearchParams::with_limit(10),
+        )
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+}
+
+#[tokio::test]
+async fn test_search_similar_require_relevance() {
+    let adapter = MockQdrantAdapter::seeded(
+        "relevance_test",
+        2,
+        vec![
+            Vector::new("doc-1".to_string(), vec![1.0, 0.0]),
+            Vector::new("doc-2".to_string(), vec![0.0, 1.0]),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // The mock scores every hit 0.9: relevant under a 0.5 floor
+    let results = adapter
+        .search_similar_require_relevance(
+            "relevance_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(5),
+            0.5,
+            true,
+        )
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+
+    // ...and irrelevant under a 0.95 floor
+    let results = adapter
+        .search_similar_require_relevance(
+            "relevance_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(5),
+            0.95,
+            false,
+        )
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+
+    let error = adapter
+        .search_similar_require_relevance(
+            "relevance_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(5),
+            0.95,
+            true,
+        )
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("best score 0.9"), "{error}");
+}
+
+#[tokio::test]
+async fn test_estimate_memory_bytes_scales_with_dimension_and_count() {
+    let adapter = MockQdrantAdapter::new();
+
+    for (collection, dimension, count) in [("small", 4, 10), ("wide", 8, 10), ("large", 4, 20)] {
+        let config = CollectionConfig::new(collection, dimension, DistanceMetric::Cosine).unwrap();
+        adapter.create_collection(config).await.unwrap();
+        let vectors = (0..count)
+            .map(|i| Vector::new(format!("doc-{i}"), vec![1.0; dimension]))
+            .collect();
+        adapter
+            .store_vectors_batch(collection, vectors)
+            .await
+            .unwrap();
+    }
+
+    // 10 points x 4 float32 components, plus 50% overhead
+    let small = adapter.estimate_memory_bytes("small").await.unwrap();
+    assert_eq!(small, 240);
+    assert_eq!(
+        adapter.estimate_memory_bytes("wide").await.unwrap(),
+        2 * small
+    );
+    assert_eq!(
+        adapter.estimate_memory_bytes("large").await.unwrap(),
+        2 * small
+    );
+
+    let config = CollectionConfig::new("empty", 4, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    assert_eq!(adapter.estimate_memory_bytes("empty").await.unwrap(), 0);
+    assert!(adapter.estimate_memory_bytes("missing").await.is_err());
+}
+
+#[tokio::test]
+async fn test_missing_data_contract() {
+    let adapter = MockQdrantAdapter::new();
+    let missing_collection = tyl_qdrant_adapter::vector_errors::collection_not_found("missing");
+
+    // Missing collection: an error for reads and searches
+    let error = adapter.get_vector("missing", "doc-1").await.unwrap_err();
+    assert_eq!(error.to_string(), missing_collection.to_string());
+    let error = adapter
+        .search_similar("missing", vec![1.0, 0.0], SearchParams::with_limit(5))
+        .await
+        .unwrap_err();
+    assert_eq!(error.to_string(), missing_collection.to_string());
+
+    let config = CollectionConfig::new("contract_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    // Missing vector: Ok(None)
+    assert!(adapter
+        .get_vector("contract_test", "doc-1")
+        .await
+        .unwrap()
+        .is_none());
+
+    // Empty but valid search: Ok(vec![])
+    let results = adapter
+        .search_similar("contract_test", vec![1.0, 0.0], SearchParams::with_limit(5))
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+
+    adapter
+        .store_vector(
+            "contract_test",
+            Vector::new("doc-1".to_string(), vec![1.0, 0.0]),
+        )
+        .await
+        .unwrap();
+    let results = adapter
+        .search_similar(
+            "contract_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(5).with_filter("lang", serde_json::json!("de")),
+        )
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+}
+
+#[tokio::test]
+async fn test_collection_checksum_detects_drift() {
+    let adapter = MockQdrantAdapter::new();
+
+    let docs = |ids: &[&str]| -> Vec<Vector> {
+        ids.iter()
+            .map(|id| {
+                let mut vector = Vector::new(id.to_string(), vec![1.0, 0.0]);
+                vector.add_metadata("title", serde_json::json!(format!("Title {id}")));
+                vector.add_metadata("year", serde_json::json!(2024));
+                vector
+            })
+            .collect()
+    };
+    for collection in ["source", "replica"] {
+        let config = CollectionConfig::new(collection, 2, DistanceMetric::Cosine).unwrap();
+        adapter.create_collection(config).await.unwrap();
+    }
+    // Insertion order does not matter
+    adapter
+        .store_vectors_batch("source", docs(&["a", "b", "c"]))
+        .await
+        .unwrap();
+    adapter
+        .store_vectors_batch("replica", docs(&["c", "a", "b"]))
+        .await
+        .unwrap();
+
+    let source = adapter.collection_checksum("source").await.unwrap();
+    assert_eq!(source.len(), 64);
+    assert_eq!(
+        adapter.collection_checksum("replica").await.unwrap(),
+        source
+    );
+
+    // An embedding-only change is caught only when embeddings are included
+    let mut moved = docs(&["b"]).remove(0);
+    moved.embedding = vec![0.0, 1.0];
+    adapter.store_vector("replica", moved).await.unwrap();
+    assert_eq!(
+        adapter.collection_checksum("replica").await.unwrap(),
+        source
+    );
+    assert_ne!(
+        adapter
+            .collection_checksum_with_embeddings("replica")
+            .await
+            .unwrap(),
+        adapter
+            .collection_checksum_with_embeddings("source")
+            .await
+            .unwrap()
+    );
+
+    // A payload change diverges
+    let mut edited = docs(&["c"]).remove(0);
+    edited.add_metadata("year", serde_json::json!(2025));
+    adapter.store_vector("replica", edited).await.unwrap();
+    assert_ne!(
+        adapter.collection_checksum("replica").await.unwrap(),
+        source
+    );
+
+    assert!(adapter.collection_checksum("missing").await.is_err());
+}
+
+#[tokio::test]
+async fn test_store_vectors_concurrent() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("concurrent_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let vectors: Vec<Vector> = (0..1000)
+        .map(|i| Vector::new(format!("doc-{i}"), vec![i as f32, 1.0]))
+        .collect();
+    let results = adapter
+        .store_vectors_concurrent("concurrent_test", vectors, 4)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1000);
+    assert!(results.iter().all(|result| result.is_ok()));
+
+    let stored = adapter
+        .count_matching("concurrent_test", SearchParams::with_limit(10))
+        .await
+        .unwrap();
+    assert_eq!(stored, 1000);
+    for id in ["doc-0", "doc-500", "doc-999"] {
+        assert!(adapter
+            .get_vector("concurrent_test", id)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    assert!(adapter
+        .store_vectors_concurrent("concurrent_test", Vec::new(), 0)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_retry_failed_resubmits_only_failures() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("retry_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let vectors: Vec<Vector> = (0..3)
+        .map(|i| Vector::new(format!("doc-{i}"), vec![i as f32, 1.0]))
+        .collect();
+    adapter.fail_next_store("retry_test", "doc-1");
+
+    let results = adapter
+        .store_vectors_batch("retry_test", vectors.clone())
+        .await
+        .unwrap();
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+    assert!(results[2].is_ok());
+    assert!(adapter
+        .get_vector("retry_test", "doc-1")
+        .await
+        .unwrap()
+        .is_none());
+
+    let retried = adapter
+        .retry_failed("retry_test", &vectors, &results)
+        .await
+        .unwrap();
+    assert_eq!(retried.len(), 3);
+    assert!(retried.iter().all(|result| result.is_ok()));
+    assert!(adapter
+        .get_vector("retry_test", "doc-1")
+        .await
+        .unwrap()
+        .is_some());
+
+    // Results must line up with the vectors they came from
+    assert!(adapter
+        .retry_failed("retry_test", &vectors, &retried[..2])
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_store_embeddings_batch_assigns_uuids() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("anonymous_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let embeddings: Vec<(
+        Vec<f32>,
+        std::collections::HashMap<String, serde_json::Value>,
+    )> = (0..5)
+        .map(|i| {
+            let mut metadata = std::collections::HashMap::new();
+            metadata.insert("position".to_string(), serde_json::json!(i));
+            (vec![i as f32, 1.0], metadata)
+        })
+        .collect();
+    let ids: Vec<String> = adapter
+        .store_embeddings_batch("anonymous_test", embeddings)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(id, result)| {
+            result.unwrap();
+            id
+        })
+        .collect();
+    assert_eq!(ids.len(), 5);
+
+    // IDs come back in input order and resolve to the matching embedding
+    for (i, id) in ids.iter().enumerate() {
+        assert!(uuid::Uuid::parse_str(id).is_ok(), "{id} is not a UUID");
+        let stored = adapter
+            .get_vector("anonymous_test", id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.embedding, vec![i as f32, 1.0]);
+        assert_eq!(stored.metadata["position"], serde_json::json!(i));
+    }
+    let unique: std::collections::HashSet<_> = ids.iter().collect();
+    assert_eq!(unique.len(), 5);
+
+    // A rejected embedding is reported with its ID; the others are stored
+    adapter.set_payload_schema(
+        "anonymous_test",
+        std::collections::HashMap::from([("position".to_string(), ExpectedJsonType::Integer)]),
+    );
+    let embeddings = vec![
+        (
+            vec![1.0, 0.0],
+            std::collections::HashMap::from([("position".to_string(), serde_json::json!(7))]),
+        ),
+        (vec![0.0, 1.0], std::collections::HashMap::new()),
+    ];
+    let results = adapter
+        .store_embeddings_batch("anonymous_test", embeddings)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_err());
+    assert!(adapter
+        .get_vector("anonymous_test", &results[0].0)
+        .await
+        .unwrap()
+        .is_some());
+    assert!(adapter
+        .get_vector("anonymous_test", &results[1].0)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_search_detailed() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("search_detailed_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    for id in ["doc-1", "doc-2"] {
+        adapter
+            .store_vector(
+                "search_detailed_test",
+                Vector::new(id.to_string(), vec![1.0, 0.0]),
+            )
+            .await
+            .unwrap();
+    }
+
+    let outcome = adapter
+        .search_detailed(
+            "search_detailed_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(outcome.returned_count, 2);
+    assert_eq!(outcome.results.len(), 2);
+    assert!(!outcome.threshold_applied);
+    assert!(outcome.elapsed < Duration::from_secs(5));
+
+    // The mock scores every hit 0.9, so this threshold filters everything out
+    let outcome = adapter
+        .search_detailed(
+            "search_detailed_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(10).with_threshold(0.95),
+        )
+        .await
+        .unwrap();
+    assert_eq!(outcome.returned_count, 0);
+    assert!(outcome.results.is_empty());
+    assert!(outcome.threshold_applied);
+}
+
+#[tokio::test]
+async fn test_multi_value_filter() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("any_of_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    for (id, status, lang) in [
+        ("published-en", "published", "en"),
+        ("review-en", "review", "en"),
+        ("draft-en", "draft", "en"),
+        ("published-de", "published", "de"),
+    ] {
+        let mut vector = Vector::new(id.to_string(), vec![1.0, 0.0]);
+        vector.add_metadata("status", serde_json::json!(status));
+        vector.add_metadata("lang", serde_json::json!(lang));
+        adapter.store_vector("any_of_test", vector).await.unwrap();
+    }
+
+    let matching_ids = |params: SearchParams| {
+        let adapter = adapter.clone();
+        async move {
+            let mut ids: Vec<String> = adapter
+                .search_similar("any_of_test", vec![1.0, 0.0], params)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|result| result.vector.id)
+                .collect();
+            ids.sort();
+            ids
+        }
+    };
+
+    let ids = matching_ids(
+        SearchParams::with_limit(10)
+            .with_any_of("status", ["published", "review"])
+            .with_filter("lang", serde_json::json!("en")),
+    )
+    .await;
+    assert_eq!(ids, vec!["published-en", "review-en"]);
+
+    let ids = matching_ids(
+        SearchParams::with_limit(10)
+            .with_filter("status", serde_json::json!({"$in": ["draft", "review"]})),
+    )
+    .await;
+    assert_eq!(ids, vec!["draft-en", "review-en"]);
+}
+
+#[tokio::test]
+async fn test_distinct_values() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("facets_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    for (id, category) in [
+        ("a", "news"),
+        ("b", "sports"),
+        ("c", "news"),
+        ("d", "tech"),
+        ("e", "news"),
+        ("f", "sports"),
+    ] {
+        let mut vector = Vector::new(id.to_string(), vec![1.0, 0.0]);
+        vector.add_metadata("category", serde_json::json!(category));
+        adapter.store_vector("facets_test", vector).await.unwrap();
+    }
+    adapter
+        .store_vector("facets_test", Vector::new("g".to_string(), vec![0.0, 1.0]))
+        .await
+        .unwrap();
+
+    let facets = adapter
+        .distinct_values("facets_test", "category", 10, usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(
+        facets,
+        vec![
+            (serde_json::json!("news"), 3),
+            (serde_json::json!("sports"), 2),
+            (serde_json::json!("tech"), 1),
+        ]
+    );
+
+    let top = adapter
+        .distinct_values("facets_test", "category", 1, usize::MAX)
+        .await
+        .unwrap();
+    assert_eq!(top, vec![(serde_json::json!("news"), 3)]);
+
+    // Only the first points in ID order are counted under a cap
+    let capped = adapter
+        .distinct_values("facets_test", "category", 10, 3)
+        .await
+        .unwrap();
+    assert_eq!(
+        capped,
+        vec![
+            (serde_json::json!("news"), 2),
+            (serde_json::json!("sports"), 1),
+        ]
+    );
+    assert!(adapter
+        .distinct_values("missing", "category", 10, usize::MAX)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_scroll_ordered() {
+    let adapter = MockQdrantAdapter::new();
+
+    let config = CollectionConfig::new("ordered_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    for (id, created_ts) in [("b", 200), ("c", 300), ("a", 100)] {
+        let mut vector = Vector::new(id.to_string(), vec![1.0, 0.0]);
+        vector.add_metadata("created_ts", serde_json::json!(created_ts));
+        adapter.store_vector("ordered_test", vector).await.unwrap();
+    }
+    adapter
+        .store_vector(
+            "ordered_test",
+            Vector::new("untimed".to_string(), vec![0.0, 1.0]),
+        )
+        .await
+        .unwrap();
+
+    let ids = |vectors: Vec<Vector>| vectors.into_iter().map(|v| v.id).collect::<Vec<_>>();
+
+    let oldest_first = adapter
+        .scroll_ordered("ordered_test", "created_ts", true, 10)
+        .await
+        .unwrap();
+    assert_eq!(ids(oldest_first), vec!["a", "b", "c"]);
+
+    let newest = adapter
+        .scroll_ordered("ordered_test", "created_ts", false, 2)
+        .await
+        .unwrap();
+    assert_eq!(ids(newest), vec!["c", "b"]);
+
+    assert!(adapter
+        .scroll_ordered("missing", "created_ts", true, 10)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_seeded_mock() {
+    let vectors = vec![
+        Vector::new("a".to_string(), vec![1.0, 0.0]),
+        Vector::new("b".to_string(), vec![0.0, 1.0]),
+    ];
+    let adapter = MockQdrantAdapter::seeded("seeded_test", 2, vectors)
+        .await
+        .unwrap();
+
+    assert!(adapter
+        .get_vector("seeded_test", "a")
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(
+        adapter
+            .count_matching("seeded_test", SearchParams::with_limit(10))
+            .await
+            .unwrap(),
+        2
+    );
+
+    let mismatched = vec![Vector::new("c".to_string(), vec![1.0, 0.0, 0.0])];
+    assert!(MockQdrantAdapter::seeded("seeded_test", 2, mismatched)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_list_collections_matching() {
+    let adapter = MockQdrantAdapter::new();
+    for name in ["docs_en", "docs_de", "images_en", "old_docs"] {
+        let config = CollectionConfig::new(name, 2, DistanceMetric::Cosine).unwrap();
+        adapter.create_collection(config).await.unwrap();
+    }
+
+    let names = |collections: Vec<CollectionConfig>| {
+        let mut names: Vec<_> = collections.into_iter().map(|c| c.name).collect();
+        names.sort();
+        names
+    };
+
+    let prefixed = adapter.list_collections_matching("docs_").await.unwrap();
+    assert_eq!(names(prefixed), vec!["docs_de", "docs_en"]);
+
+    let english = adapter.list_collections_matching("*_en").await.unwrap();
+    assert_eq!(names(english), vec!["docs_en", "images_en"]);
+
+    let any_docs = adapter.list_collections_matching("*docs*").await.unwrap();
+    assert_eq!(any_docs.len(), 3);
+    assert!(adapter
+        .list_collections_matching("videos_*")
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_get_payload() {
+    let mut vector = Vector::new("doc".to_string(), vec![0.5, 0.5]);
+    vector.add_metadata("title", serde_json::json!("Release notes"));
+    let adapter = MockQdrantAdapter::seeded("payload_test", 2, vec![vector])
+        .await
+        .unwrap();
+
+    let payload = adapter
+        .get_payload("payload_test", "doc")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(payload.len(), 1);
+    assert_eq!(payload["title"], serde_json::json!("Release notes"));
+
+    assert!(adapter
+        .get_payload("payload_test", "missing")
+        .await
+        .unwrap()
+        .is_none());
+    assert!(adapter.get_payload("missing", "doc").await.is_err());
+}
+
+#[tokio::test]
+async fn test_store_vector_merge_metadata() {
+    let mut original = Vector::new("doc".to_string(), vec![1.0, 0.0]);
+    original.add_metadata("title", serde_json::json!("Draft"));
+    original.add_metadata("author", serde_json::json!("sam"));
+    let adapter = MockQdrantAdapter::seeded("merge_test", 2, vec![original])
+        .await
+        .unwrap();
+
+    let mut update = Vector::new("doc".to_string(), vec![0.0, 1.0]);
+    update.add_metadata("title", serde_json::json!("Final"));
+    update.add_metadata("reviewed", serde_json::json!(true));
+    adapter
+        .store_vector_merge_metadata("merge_test", update)
+        .await
+        .unwrap();
+
+    let stored = adapter
+        .get_vector("merge_test", "doc")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.embedding, vec![0.0, 1.0]);
+    assert_eq!(stored.metadata["title"], serde_json::json!("Final"));
+    assert_eq!(stored.metadata["author"], serde_json::json!("sam"));
+    assert_eq!(stored.metadata["reviewed"], serde_json::json!(true));
+
+    let mut fresh = Vector::new("new".to_string(), vec![1.0, 0.0]);
+    fresh.add_metadata("title", serde_json::json!("New"));
+    adapter
+        .store_vector_merge_metadata("merge_test", fresh)
+        .await
+        .unwrap();
+    let stored = adapter
+        .get_vector("merge_test", "new")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.metadata.len(), 1);
+
+    // A partial update passes a schema requiring keys it does not set, but not a wrong type
+    adapter.set_payload_schema(
+        "merge_test",
+        std::collections::HashMap::from([("title".to_string(), ExpectedJsonType::String)]),
+    );
+    let mut partial = Vector::new("doc".to_string(), vec![1.0, 1.0]);
+    partial.add_metadata("reviewed", serde_json::json!(false));
+    adapter
+        .store_vector_merge_metadata("merge_test", partial)
+        .await
+        .unwrap();
+    let mut mistyped = Vector::new("doc".to_string(), vec![1.0, 1.0]);
+    mistyped.add_metadata("title", serde_json::json!(42));
+    assert!(adapter
+        .store_vector_merge_metadata("merge_test", mistyped)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_search_with_defaults() {
+    let config = QdrantConfig {
+        default_search_limit: 2,
+        ..QdrantConfig::default()
+    };
+    let adapter = MockQdrantAdapter::with_config(config);
+    let collection = CollectionConfig::new("defaults_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(collection).await.unwrap();
+    for id in ["a", "b", "c"] {
+        adapter
+            .store_vector("defaults_test", Vector::new(id.to_string(), vec![1.0, 0.0]))
+            .await
+            .unwrap();
+    }
+
+    let results = adapter
+        .search_with_defaults("defaults_test", vec![1.0, 0.0])
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+
+    // The mock scores every match 0.9, so a stricter default threshold filters them all
+    let strict = MockQdrantAdapter::with_config(QdrantConfig {
+        default_search_threshold: Some(0.95),
+        ..QdrantConfig::default()
+    });
+    let collection = CollectionConfig::new("defaults_test", 2, DistanceMetric::Cosine).unwrap();
+    strict.create_collection(collection).await.unwrap();
+    strict
+        .store_vector(
+            "defaults_test",
+            Vector::new("a".to_string(), vec![1.0, 0.0]),
+        )
+        .await
+        .unwrap();
+    assert!(strict
+        .search_with_defaults("defaults_test", vec![1.0, 0.0])
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_store_text_records_model_and_content_type() {
+    let adapter = MockQdrantAdapter::seeded("modality_test", 2, Vec::new())
+        .await
+        .unwrap();
+    adapter
+        .store_text(
+            "modality_test",
+            "snippet",
+            "fn main() {}",
+            ContentType::Code,
+            &FixedDimEmbedder { dim: 2 },
+            std::collections::HashMap::new(),
+        )
+        .await
+        .unwrap();
+    adapter
+        .store_text(
+            "modality_test",
+            "note",
+            "hello",
+            ContentType::Text,
+            &FixedDimEmbedder { dim: 2 },
+            std::collections::HashMap::new(),
+        )
+        .await
+        .unwrap();
+
+    let stored = adapter
+        .get_vector("modality_test", "snippet")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.embedding, vec![12.0, 1.0]);
+    assert_eq!(
+        stored.metadata[CONTENT_TYPE_PAYLOAD_KEY],
+        serde_json::json!("code")
+    );
+    assert_eq!(
+        stored.metadata[EMBEDDING_MODEL_PAYLOAD_KEY],
+        serde_json::json!("fixed-2-model")
+    );
+
+    let code = adapter
+        .search_similar(
+            "modality_test",
+            vec![1.0, 1.0],
+            SearchParams::with_limit(10).with_content_type(&ContentType::Code),
+        )
+        .await
+        .unwrap();
+    assert_eq!(code.len(), 1);
+    assert_eq!(code[0].vector.id, "snippet");
+}
+
+#[tokio::test]
+async fn test_store_text_uses_configured_reserved_prefix() {
+    let adapter = MockQdrantAdapter::with_config(QdrantConfig {
+        reserved_payload_prefix: "_internal_".to_string(),
+        ..QdrantConfig::default()
+    });
+    let config = CollectionConfig::new("prefix_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    adapter
+        .store_text(
+            "prefix_test",
+            "snippet",
+            "fn main() {}",
+            ContentType::Code,
+            &FixedDimEmbedder { dim: 2 },
+            std::collections::HashMap::new(),
+        )
+        .await
+        .unwrap();
+
+    let stored = adapter
+        .get_vector("prefix_test", "snippet")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        stored.metadata["_internal_embedding_model"],
+        serde_json::json!("fixed-2-model")
+    );
+    assert!(!stored.metadata.contains_key(CONTENT_TYPE_PAYLOAD_KEY));
+
+    let code = adapter
+        .search_similar(
+            "prefix_test",
+            vec![1.0, 1.0],
+            SearchParams::with_limit(10)
+                .with_content_type_key(&ContentType::Code, adapter.config()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(code.len(), 1);
+
+    // Keys under the configured prefix are rejected; the default one is ordinary metadata
+    let mut vector = Vector::new("flagged".to_string(), vec![1.0, 0.0]);
+    vector.add_metadata("__tyl_note", serde_json::json!("kept"));
+    adapter.store_vector("prefix_test", vector).await.unwrap();
+    let mut vector = Vector::new("rejected".to_string(), vec![1.0, 0.0]);
+    vector.add_metadata("_internal_flag", serde_json::json!(true));
+    assert!(adapter.store_vector("prefix_test", vector).await.is_err());
+}
+
+#[tokio::test]
+async fn test_search_dedup_by_payload_key() {
+    let chunks = [
+        ("a-1", "a"),
+        ("a-2", "a"),
+        ("a-3", "a"),
+        ("b-1", "b"),
+        ("b-2", "b"),
+    ]
+    .into_iter()
+    .map(|(id, doc_id)| {
+        let mut vector = Vector::new(id.to_string(), vec![1.0, 0.0]);
+        vector.add_metadata("doc_id", serde_json::json!(doc_id));
+        vector
+    })
+    .collect();
+    let adapter = MockQdrantAdapter::seeded("chunks_test", 2, chunks)
+        .await
+        .unwrap();
+
+    let results = adapter
+        .search_similar_with_options(
+            "chunks_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(10),
+            QdrantSearchOptions::new().with_dedup_by("doc_id"),
+        )
+        .await
+        .unwrap();
+
+    let mut doc_ids: Vec<_> = results
+        .iter()
+        .map(|r| r.vector.metadata["doc_id"].clone())
+        .collect();
+    doc_ids.sort_by_key(|value| value.to_string());
+    assert_eq!(
+        doc_ids,
+        vec![serde_json::json!("a"), serde_json::json!("b")]
+    );
+}
+
+#[tokio::test]
+async fn test_search_using_stored_vector() {
+    let adapter = MockQdrantAdapter::seeded(
+        "lookup_docs",
+        2,
+        vec![
+            Vector::new("doc-1".to_string(), vec![1.0, 0.0]),
+            Vector::new("doc-2".to_string(), vec![0.0, 1.0]),
+        ],
+    )
+    .await
+    .unwrap();
+    let queries = CollectionConfig::new("lookup_queries", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(queries).await.unwrap();
+    adapter
+        .store_vector(
+            "lookup_queries",
+            Vector::new("saved-query".to_string(), vec![1.0, 0.0]),
+        )
+        .await
+        .unwrap();
+
+    let results = adapter
+        .search_using_stored_vector(
+            "lookup_docs",
+            "lookup_queries",
+            "saved-query",
+            SearchParams::with_limit(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.vector.id.starts_with("doc-")));
+
+    assert!(adapter
+        .search_using_stored_vector(
+            "lookup_docs",
+            "lookup_queries",
+            "missing",
+            SearchParams::with_limit(10),
+        )
+        .await
+        .is_err());
+    assert!(adapter
+        .search_using_stored_vector(
+            "lookup_docs",
+            "missing",
+            "saved-query",
+            SearchParams::with_limit(10),
+        )
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_recreate_collection() {
+    let adapter = MockQdrantAdapter::seeded(
+        "recreate_test",
+        2,
+        vec![Vector::new("old".to_string(), vec![1.0, 0.0])],
+    )
+    .await
+    .unwrap();
+
+    let config = CollectionConfig::new("recreate_test", 3, DistanceMetric::Euclidean).unwrap();
+    adapter.recreate_collection(config).await.unwrap();
+
+    let info = adapter
+        .get_collection_info("recreate_test")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(info.dimension, 3);
+    assert!(matches!(info.distance_metric, DistanceMetric::Euclidean));
+    assert!(adapter
+        .get_vector("recreate_test", "old")
+        .await
+        .unwrap()
+        .is_none());
+
+    // A missing collection is simply created
+    let fresh = CollectionConfig::new("recreate_fresh", 2, DistanceMetric::Cosine).unwrap();
+    adapter.recreate_collection(fresh).await.unwrap();
+    assert!(adapter
+        .get_collection_info("recreate_fresh")
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_reserved_payload_prefix_is_rejected() {
+    let adapter = MockQdrantAdapter::seeded("reserved_test", 2, Vec::new())
+        .await
+        .unwrap();
+
+    let mut vector = Vector::new("doc".to_string(), vec![1.0, 0.0]);
+    vector.add_metadata("__tyl_owner", serde_json::json!("me"));
+    assert!(adapter.store_vector("reserved_test", vector).await.is_err());
+    assert!(adapter
+        .get_vector("reserved_test", "doc")
+        .await
+        .unwrap()
+        .is_none());
+
+    let mut vector = Vector::new("doc".to_string(), vec![1.0, 0.0]);
+    vector.add_metadata("owner", serde_json::json!("me"));
+    adapter.store_vector("reserved_test", vector).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_collections_with_stats() {
+    let adapter = MockQdrantAdapter::seeded(
+        "stats_a",
+        2,
+        vec![
+            Vector::new("1".to_string(), vec![1.0, 0.0]),
+            Vector::new("2".to_string(), vec![0.0, 1.0]),
+        ],
+    )
+    .await
+    .unwrap();
+    let config = CollectionConfig::new("stats_b", 3, DistanceMetric::DotProduct).unwrap();
+    adapter.create_collection(config).await.unwrap();
+
+    let mut collections = adapter.collections_with_stats().await.unwrap();
+    collections.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+    assert_eq!(collections.len(), 2);
+
+    let (config_a, stats_a) = &collections[0];
+    assert_eq!(config_a.name, "stats_a");
+    assert_eq!(config_a.dimension, 2);
+    assert_eq!(stats_a["vectors_count"], serde_json::json!(2));
+
+    let (config_b, stats_b) = &collections[1];
+    assert_eq!(config_b.name, "stats_b");
+    assert_eq!(config_b.dimension, 3);
+    assert_eq!(stats_b["vectors_count"], serde_json::json!(0));
+}
+
+#[tokio::test]
+async fn test_reconnect_with_api_key() {
+    let config = QdrantConfig {
+        api_key: Some("old-key".to_string()),
+        ..QdrantConfig::default()
+    };
+    let mut adapter = MockQdrantAdapter::with_config(config);
+    let collection = CollectionConfig::new("rotated", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(collection).await.unwrap();
+    adapter
+        .store_vector("rotated", Vector::new("1".to_string(), vec![1.0, 0.0]))
+        .await
+        .unwrap();
+
+    adapter
+        .reconnect_with_api_key(Some("new-key".to_string()))
+        .await
+        .unwrap();
+    assert_eq!(adapter.config().api_key.as_deref(), Some("new-key"));
+    assert!(adapter.get_vector("rotated", "1").await.unwrap().is_some());
+
+    adapter.reconnect_with_api_key(None).await.unwrap();
+    assert_eq!(adapter.config().api_key, None);
+}
+
+#[tokio::test]
+async fn test_strict_filters_reject_typo_operators() {
+    let vector = {
+        let mut vector = Vector::new("1".to_string(), vec![1.0, 0.0]);
+        vector.add_metadata("price", serde_json::json!(50));
+        vector
+    };
+    let typo = || {
+        SearchParams::with_limit(10)
+            .with_filter("price", serde_json::json!({"$gte": 10, "$ltee": 20}))
+    };
+
+    let lenient = MockQdrantAdapter::seeded("strict_test", 2, vec![vector.clone()])
+        .await
+        .unwrap();
+    let results = lenient
+        .search_similar("strict_test", vec![1.0, 0.0], typo())
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+
+    let strict = MockQdrantAdapter::with_config(QdrantConfig {
+        strict_filters: true,
+        ..QdrantConfig::default()
+    });
+    let collection = CollectionConfig::new("strict_test", 2, DistanceMetric::Cosine).unwrap();
+    strict.create_collection(collection).await.unwrap();
+    strict.store_vector("strict_test", vector).await.unwrap();
+    let error = strict
+        .search_similar("strict_test", vec![1.0, 0.0], typo())
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("price.$ltee"), "{error}");
+}
+
+#[tokio::test]
+async fn test_search_similar_then_rerank() {
+    let vectors = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(|id| Vector::new(id.to_string(), vec![1.0, 0.0]))
+        .collect();
+    let adapter = MockQdrantAdapter::seeded("rerank_test", 2, vectors)
+        .await
+        .unwrap();
+
+    let ids = |results: &[VectorSearchResult]| -> Vec<String> {
+        results
+            .iter()
+            .map(|result| result.vector.id.clone())
+            .collect()
+    };
+    let candidates = adapter
+        .search_similar("rerank_test", vec![1.0, 0.0], SearchParams::with_limit(10))
+        .await
+        .unwrap();
+    let mut expected = ids(&candidates);
+    expected.reverse();
+
+    let reranked = adapter
+        .search_similar_then_rerank(
+            "rerank_test",
+            vec![1.0, 0.0],
+            SearchParams::with_limit(10),
+            |candidates| {
+                candidates
+                    .iter()
+                    .rev()
+                    .map(|result| VectorSearchResult::new(result.vector.clone(), result.score))
+                    .collect()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(reranked.len(), 4);
+    assert_eq!(ids(&reranked), expected);
+}
+
+#[tokio::test]
+async fn test_ensure_collection_outcomes() {
+    let adapter = MockQdrantAdapter::new();
+    let config = CollectionConfig::new("declared", 4, DistanceMetric::Cosine).unwrap();
+
+    // Absent: created
+    let outcome = adapter.ensure_collection(config.clone()).await.unwrap();
+    assert_eq!(outcome, EnsureOutcome::Created);
+    assert!(adapter
+        .get_collection_info("declared")
+        .await
+        .unwrap()
+        .is_some());
+
+    // Present with the same config
+    let outcome = adapter.ensure_collection(config).await.unwrap();
+    assert_eq!(outcome, EnsureOutcome::AlreadyMatches);
+
+    // Present with a different config: reported, not changed
+    let different = CollectionConfig::new("declared", 8, DistanceMetric::DotProduct).unwrap();
+    let outcome = adapter.ensure_collection(different).await.unwrap();
+    assert_eq!(
+        outcome,
+        EnsureOutcome::ConfigMismatch {
+            differences: vec![
+                "dimension is 4, expected 8".to_string(),
+                "distance metric is Cosine, expected Dot".to_string(),
+            ],
+        }
+    );
+    let actual = adapter
+        .get_collection_info("declared")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(actual.dimension, 4);
+}
+
+#[tokio::test]
+async fn test_assert_collection_schema() {
+    let adapter = MockQdrantAdapter::new();
+    let deployed = CollectionConfig::new("deployed", 4, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(deployed.clone()).await.unwrap();
+
+    adapter
+        .assert_collection_schema("deployed", &deployed)
+        .await
+        .unwrap();
+
+    let wider = CollectionConfig::new("deployed", 8, DistanceMetric::Cosine).unwrap();
+    let message = adapter
+        .assert_collection_schema("deployed", &wider)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("dimension is 4, expected 8"), "{message}");
+    assert!(!message.contains("distance metric"), "{message}");
+
+    let dot = CollectionConfig::new("deployed", 4, DistanceMetric::DotProduct).unwrap();
+    let message = adapter
+        .assert_collection_schema("deployed", &dot)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        message.contains("distance metric is Cosine, expected Dot"),
+        "{message}"
+    );
+    assert!(!message.contains("dimension"), "{message}");
+
+    // Every mismatch is reported at once
+    let both = CollectionConfig::new("deployed", 8, DistanceMetric::Euclidean).unwrap();
+    let message = adapter
+        .assert_collection_schema("deployed", &both)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("dimension is 4, expected 8"), "{message}");
+    assert!(
+        message.contains("distance metric is Cosine, expected Euclid"),
+        "{message}"
+    );
+
+    assert!(adapter
+        .assert_collection_schema("missing", &deployed)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_assert_collection_schema_compares_hnsw() {
+    let adapter = MockQdrantAdapter::new();
+    let config = CollectionConfig::new("indexed", 4, DistanceMetric::Cosine).unwrap();
+    let deployed = QdrantCollectionOptions::new().with_hnsw(QdrantHnswConfig {
+        m: Some(32),
+        ef_construct: Some(200),
+        ..QdrantHnswConfig::default()
+    });
+    adapter
+        .create_collection_with_options(config.clone(), deployed.clone())
+        .await
+        .unwrap();
+
+    adapter
+        .assert_collection_schema_with_options("indexed", &config, &deployed)
+        .await
+        .unwrap();
+
+    // Only the HNSW settings that are set are compared
+    let only_m = QdrantCollectionOptions::new().with_hnsw(QdrantHnswConfig {
+        m: Some(32),
+        ..QdrantHnswConfig::default()
+    });
+    adapter
+        .assert_collection_schema_with_options("indexed", &config, &only_m)
+        .await
+        .unwrap();
+
+    let expected = QdrantCollectionOptions::new().with_hnsw(QdrantHnswConfig {
+        m: Some(16),
+        on_disk: Some(true),
+        ..QdrantHnswConfig::default()
+    });
+    let message = adapter
+        .assert_collection_schema_with_options("indexed", &config, &expected)
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(message.contains("hnsw m is 32, expected 16"), "{message}");
+    assert!(
+        message.contains("hnsw on_disk is unset, expected true"),
+        "{message}"
+    );
+    assert!(!message.contains("ef_construct"), "{message}");
+}
+
+#[tokio::test]
+async fn test_update_named_vector() {
+    let mut vector = Vector::new("doc".to_string(), vec![1.0, 0.0]);
+    vector.add_metadata("title", serde_json::json!("Intro"));
+    let adapter = MockQdrantAdapter::seeded("named_test", 2, vec![vector])
+        .await
+        .unwrap();
+
+    adapter
+        .update_named_vector("named_test", "doc", "title", vec![0.5, 0.5])
+        .await
+        .unwrap();
+    adapter
+        .update_named_vector("named_test", "doc", "body", vec![0.0, 1.0])
+        .await
+        .unwrap();
+    adapter
+        .update_named_vector("named_test", "doc", "body", vec![0.6, 0.8])
+        .await
+        .unwrap();
+
+    // Only the targeted vector changed; the default vector and payload are untouched
+    assert_eq!(
+        adapter.named_vector("named_test", "doc", "body"),
+        Some(vec![0.6, 0.8])
+    );
+    assert_eq!(
+        adapter.named_vector("named_test", "doc", "title"),
+        Some(vec![0.5, 0.5])
+    );
+    let stored = adapter
+        .get_vector("named_test", "doc")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.embedding, vec![1.0, 0.0]);
+    assert_eq!(stored.metadata["title"], serde_json::json!("Intro"));
+
+    adapter
+        .update_named_vector("named_test", "doc", "", vec![0.0, 1.0])
+        .await
+        .unwrap();
+    assert_eq!(
+        adapter.named_vector("named_test", "doc", ""),
+        Some(vec![0.0, 1.0])
+    );
+    assert_eq!(
+        adapter.named_vector("named_test", "doc", "body"),
+        Some(vec![0.6, 0.8])
+    );
+
+    assert!(adapter
+        .update_named_vector("named_test", "missing", "body", vec![0.0, 1.0])
+        .await
+        .is_err());
+
+    // Named vectors go with their points, so recreated collections and points start clean
+    adapter.delete_collection("named_test").await.unwrap();
+    let config = CollectionConfig::new("named_test", 2, DistanceMetric::Cosine).unwrap();
+    adapter.create_collection(config).await.unwrap();
+    adapter
+        .store_vector("named_test", Vector::new("doc".to_string(), vec![1.0, 0.0]))
+        .await
+        .unwrap();
+    assert_eq!(adapter.named_vector("named_test", "doc", "body"), None);
+
+    adapter
+        .update_named_vector("named_test", "doc", "body", vec![0.6, 0.8])
+        .await
+        .unwrap();
+    adapter.delete_vector("named_test", "doc").await.unwrap();
+    adapter
+        .store_vector("named_test", Vector::new("doc".to_string(), vec![1.0, 0.0]))
+        .await
+        .unwrap();
+    assert_eq!(adapter.named_vector("named_test", "doc", "body"), None);
+}
+
+#[tokio::test]
+async fn test_existing_ids() {
+    let vectors = ["a", "b", "c"]
+        .into_iter()
+        .map(|id| Vector::new(id.to_string(), vec![1.0, 0.0]))
+        .collect();
+    let adapter = MockQdrantAdapter::seeded("existing_test", 2, vectors)
+        .await
+        .unwrap();
+
+    let candidates = ["a", "x", "c", "y", "a"].map(String::from).to_vec();
+    let existing = adapter
+        .existing_ids("existing_test", candidates)
+        .await
+        .unwrap();
+    assert_eq!(
+        existing,
+        std::collections::HashSet::from(["a".to_string(), "c".to_string()])
+    );
+
+    assert!(adapter
+        .existing_ids("existing_test", Vec::new())
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(adapter
+        .existing_ids("missing", vec!["a".to_string()])
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_cluster_info() {
+    let adapter = MockQdrantAdapter::seeded(
+        "cluster_test",
+        3,
+        vec![Vector::new("a".to_string(), vec![1.0, 0.0, 0.0])],
+    )
+    .await
+    .unwrap();
+    let info = adapter.cluster_info().await.unwrap();
+
+    // A single node runs without distributed mode
+    assert!(!info.enabled);
+    assert!(info.peers.is_empty());
+    assert_eq!(info.leader, None);
+    assert_eq!(info.disk_usage_bytes, Some(12));
+}
+
+#[tokio::test]
+async fn test_create_collection_for_service() {
+    let adapter = MockQdrantAdapter::new();
+    adapter
+        .create_collection_for_service(
+            "minilm",
+            &FixedDimEmbedder { dim: 384 },
+            DistanceMetric::Cosine,
+        )
+        .await
+        .unwrap();
+
+    let config = adapter
+        .get_collection_info("minilm")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(config.dimension, 384);
+    assert!(adapter
+        .validate_embedding_compatibility("minilm", &FixedDimEmbedder { dim: 384 })
+        .await
+        .is_ok());
+}
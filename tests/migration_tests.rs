@@ -9,7 +9,9 @@
 #[cfg(feature = "schema-migration")]
 mod migration_tests {
     use semver::Version;
-    use tyl_qdrant_adapter::{migration::*, CollectionConfig, DistanceMetric, MockQdrantAdapter};
+    use tyl_qdrant_adapter::{
+        migration::*, CollectionConfig, DistanceMetric, MockQdrantAdapter, VectorCollectionManager,
+    };
 
     #[tokio::test]
     async fn test_migration_manager_initialization() {
@@ -251,6 +253,69 @@ mod migration_tests {
         assert!(history[0].version < history[1].version);
     }
 
+    #[tokio::test]
+    async fn test_resume_migration_after_partial_failure() {
+        let adapter = MockQdrantAdapter::new();
+        let manager = SchemaMigrationManager::new(adapter.clone());
+
+        manager.initialize().await.unwrap();
+
+        // A leftover collection makes the second change fail
+        adapter
+            .create_collection(
+                CollectionConfig::new("reports", 128, DistanceMetric::Cosine).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let version = Version::new(1, 0, 0);
+        let migration = MigrationBuilder::new(version.clone(), "Two collections".to_string())
+            .author("Test".to_string())
+            .create_collection(
+                CollectionConfig::new("documents", 128, DistanceMetric::Cosine).unwrap(),
+            )
+            .create_collection(
+                CollectionConfig::new("reports", 128, DistanceMetric::Cosine).unwrap(),
+            )
+            .build();
+
+        assert!(manager.apply_migration(migration.clone()).await.is_err());
+        assert!(adapter
+            .get_collection_info("documents")
+            .await
+            .unwrap()
+            .is_some());
+        // The partial migration is not part of the history yet
+        assert!(manager.get_migration_history().await.unwrap().is_empty());
+
+        // Applying it again would restart it from scratch, losing its progress
+        let error = manager.apply_migration(migration).await.unwrap_err();
+        assert!(error.to_string().contains("resume_migration"), "{error}");
+
+        // Once the cause is fixed, only the failed change is applied again
+        adapter.delete_collection("reports").await.unwrap();
+        let result = manager.resume_migration(version.clone()).await.unwrap();
+        assert_eq!(result.version, version);
+        assert_eq!(result.applied_changes.len(), 1);
+        assert!(matches!(
+            &result.applied_changes[0],
+            ChangeResult::CollectionCreated(name) if name == "reports"
+        ));
+
+        let history = manager.get_migration_history().await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].version, version);
+
+        // Resuming a completed migration applies nothing
+        let result = manager.resume_migration(version).await.unwrap();
+        assert!(result.applied_changes.is_empty());
+
+        assert!(manager
+            .resume_migration(Version::new(9, 0, 0))
+            .await
+            .is_err());
+    }
+
     #[test]
     fn test_pact_interaction_serialization() {
         let interaction = PactInteraction {